@@ -2,8 +2,22 @@
 
 const { program } = require("commander");
 const chalk = require("chalk");
-const { create } = require("../lib/commands/create");
+const { create, newOwnable } = require("../lib/commands/create");
+const { init } = require("../lib/commands/init");
 const { build, clean } = require("../lib/commands/build");
+const { pinMetadata } = require("../lib/commands/pinMetadata");
+const { convertMetadata } = require("../lib/commands/convertMetadata");
+const { lint } = require("../lib/commands/lint");
+const { audit } = require("../lib/commands/audit");
+const { reset } = require("../lib/commands/reset");
+const { exportTemplate } = require("../lib/commands/exportTemplate");
+const { validate } = require("../lib/commands/validate");
+const { packageOwnable } = require("../lib/commands/package");
+const { verify } = require("../lib/commands/verify");
+const { schema } = require("../lib/commands/schema");
+const { simulate, simulateQuery } = require("../lib/commands/simulate");
+const { codegenTs } = require("../lib/commands/codegenTs");
+const { msg } = require("../lib/commands/msg");
 
 program
   .name("ownables-cli")
@@ -23,11 +37,124 @@ program
   });
 
 program
-  .command("build")
+  .command("new [name]")
+  .description("Create a new Ownable for an ownable type without prompts")
+  .option("--type <type>", "Ownable type, e.g. music or image")
+  .option(
+    "--manifest <file>",
+    "Read name, description, ownable_type and extra placeholder values from an ownable.toml"
+  )
+  .option("--description <description>", "Description of the Ownable")
+  .option("--crate-version <version>", "Version (x.y.z)", "1.0.0")
+  .option("--authors <authors>", "Authors (comma-separated)")
+  .option("--keywords <keywords>", "Keywords (comma-separated)")
+  .option("--image <path>", "Embed an image (max 256 KB) in the metadata as base64 image_data")
+  .option("--dir <dir>", "Target directory (defaults to ./<name>)")
+  .option("--force", "Write into the target directory even if it exists")
+  .option("--dry-run", "Show which files would be created or modified without writing them")
+  .option("--template-version <version>", "Pin the template version to scaffold from")
+  .option("--template-path <dir>", "Scaffold from a local directory of templates")
+  .option("--template-repo <git-url>", "Scaffold from templates in a git repository")
+  .option(
+    "--template <name>",
+    "Template to use from --template-path or --template-repo (defaults to the built-in name for --type)"
+  )
+  .action(async (name, options) => {
+    try {
+      await newOwnable(name, options);
+    } catch (error) {
+      console.error(chalk.red("Error:"), error.message);
+      process.exit(1);
+    }
+  });
+
+program
+  .command("init <name>")
+  .description("Bootstrap a complete Ownable workspace and check that it compiles")
+  .option("--type <type>", "Ownable type, e.g. music or image", "image")
+  .option("--description <description>", "Description of the Ownable")
+  .option("--dir <dir>", "Target directory (defaults to ./<name>)")
+  .option("--force", "Write into the target directory even if it exists")
+  .option("--no-check", "Skip running cargo check on the generated workspace")
+  .action(async (name, options) => {
+    try {
+      await init(name, options);
+    } catch (error) {
+      console.error(chalk.red("Error:"), error.message);
+      process.exit(1);
+    }
+  });
+
+program
+  .command("reset <dir>")
+  .description("Regenerate an Ownable's sources from its recorded template values")
+  .action(async (dir) => {
+    try {
+      await reset(dir);
+    } catch (error) {
+      console.error(chalk.red("Error:"), error.message);
+      process.exit(1);
+    }
+  });
+
+program
+  .command("export-template <template> <output>")
+  .description("Export a built-in template for use with cargo-generate")
+  .action(async (template, output) => {
+    try {
+      await exportTemplate(template, output);
+    } catch (error) {
+      console.error(chalk.red("Error:"), error.message);
+      process.exit(1);
+    }
+  });
+
+program
+  .command("build [dir]")
   .description("Build the Ownable project")
-  .action(async () => {
+  .option(
+    "--target-dir <path>",
+    "Cargo target directory (defaults to the project's target directory)"
+  )
+  .option("--no-optimize", "Skip wasm-opt optimization of the module")
+  .option(
+    "--reproducible",
+    "Build byte-identical wasm from a locked Cargo.lock and report its sha256"
+  )
+  .action(async (dir, options) => {
     try {
-      await build();
+      await build({ ...options, dir });
+    } catch (error) {
+      console.error(chalk.red("Error:"), error.message);
+      process.exit(1);
+    }
+  });
+
+program
+  .command("package [dir]")
+  .description("Bundle a built Ownable and print its package CID")
+  .option("--output <path>", "Archive path (defaults to <dir>/<name>-package.zip)")
+  .option("--api <url>", "IPFS HTTP API address used to verify the CID")
+  .option("--no-verify", "Skip checking the CID against an IPFS node, e.g. offline")
+  .option("--include-schema", "Generate the message schema and bundle it as schema.json")
+  .action(async (dir, options) => {
+    try {
+      await packageOwnable(dir, options);
+    } catch (error) {
+      console.error(chalk.red("Error:"), error.message);
+      process.exit(1);
+    }
+  });
+
+program
+  .command("verify")
+  .description("Check a local Ownable package matches a deployed package CID")
+  .requiredOption("--package <path>", "Directory of the packaged Ownable")
+  .requiredOption("--expected-cid <cid>", "CID the deployed Ownable was packaged with")
+  .action(async (options) => {
+    try {
+      const { matches, placeholders } = await verify(options);
+      if (!matches || placeholders.length > 0) process.exit(1);
     } catch (error) {
       console.error(chalk.red("Error:"), error.message);
       process.exit(1);
@@ -46,4 +173,143 @@ program
     }
   });
 
+program
+  .command("pin-metadata <dir>")
+  .description(
+    "Pin a directory of metadata JSON files to IPFS under a single directory CID"
+  )
+  .option("--api <url>", "IPFS HTTP API address")
+  .action(async (dir, options) => {
+    try {
+      await pinMetadata(dir, options);
+    } catch (error) {
+      console.error(chalk.red("Error:"), error.message);
+      process.exit(1);
+    }
+  });
+
+program
+  .command("convert-metadata <input> <output>")
+  .description("Convert metadata between ERC-721 and ownable formats")
+  .requiredOption("--from <format>", "Input format (erc721 or ownable)")
+  .requiredOption("--to <format>", "Output format (erc721 or ownable)")
+  .action(async (input, output, options) => {
+    try {
+      await convertMetadata(input, output, options);
+    } catch (error) {
+      console.error(chalk.red("Error:"), error.message);
+      process.exit(1);
+    }
+  });
+
+program
+  .command("lint [dir]")
+  .description("Flag handlers that ignore the sender or block environment")
+  .action(async (dir) => {
+    try {
+      const findings = await lint(dir);
+      if (findings.length > 0) process.exit(1);
+    } catch (error) {
+      console.error(chalk.red("Error:"), error.message);
+      process.exit(1);
+    }
+  });
+
+program
+  .command("audit [dir]")
+  .description("Flag unsafe patterns in the contract; fails on high severity findings")
+  .option(
+    "--allow <rule>",
+    "Suppress a rule; repeat for several",
+    (rule, rules) => rules.concat(rule),
+    []
+  )
+  .action(async (dir, options) => {
+    try {
+      await audit(dir, options);
+    } catch (error) {
+      console.error(chalk.red("Error:"), error.message);
+      process.exit(1);
+    }
+  });
+
+program
+  .command("validate <dir>")
+  .description("Check a generated Ownable for unsubstituted template placeholders")
+  .action(async (dir) => {
+    try {
+      const found = await validate(dir);
+      if (found.length > 0) process.exit(1);
+    } catch (error) {
+      console.error(chalk.red("Error:"), error.message);
+      process.exit(1);
+    }
+  });
+
+program
+  .command("schema [dir]")
+  .description("Generate JSON schema files for the Ownable's messages")
+  .action(async (dir) => {
+    try {
+      await schema(dir);
+    } catch (error) {
+      console.error(chalk.red("Error:"), error.message);
+      process.exit(1);
+    }
+  });
+
+program
+  .command("codegen-ts [dir]")
+  .description("Generate TypeScript bindings for the Ownable's messages")
+  .requiredOption("--out <file>", "TypeScript file to write")
+  .action(async (dir, options) => {
+    try {
+      await codegenTs(dir, options);
+    } catch (error) {
+      console.error(chalk.red("Error:"), error.message);
+      process.exit(1);
+    }
+  });
+
+program
+  .command("msg <variant> [fields...]")
+  .description("Print the JSON of an execute message, e.g. msg transfer --recipient <address>")
+  .option("--dir <dir>", "Ownable project whose schema defines the messages", ".")
+  .allowUnknownOption()
+  .action(async (variant, fields, options) => {
+    try {
+      await msg(variant, fields, options);
+    } catch (error) {
+      console.error(chalk.red("Error:"), error.message);
+      process.exit(1);
+    }
+  });
+
+program
+  .command("simulate [dir]")
+  .description("Instantiate, transfer and lock the Ownable in a local cw-multi-test app")
+  .action(async (dir) => {
+    try {
+      await simulate(dir);
+    } catch (error) {
+      console.error(chalk.red("Error:"), error.message);
+      process.exit(1);
+    }
+  });
+
+program
+  .command("simulate-query [dir]")
+  .description("Print the Ownable's query responses from a local cw-multi-test app")
+  .option("--msg <json>", "Query or array of queries (defaults to info, metadata and widget state)")
+  .option("--golden <file>", "Write the output on the first run, then fail when it differs")
+  .option("--update-golden", "Rewrite the golden file with the current output")
+  .action(async (dir, options) => {
+    try {
+      await simulateQuery(dir, options);
+    } catch (error) {
+      console.error(chalk.red("Error:"), error.message);
+      process.exit(1);
+    }
+  });
+
 program.parse();
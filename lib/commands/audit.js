@@ -0,0 +1,142 @@
+const chalk = require("chalk");
+const fs = require("fs-extra");
+const path = require("path");
+const { listRustFiles, findFunctions, lineOf } = require("../utils/rustSource");
+
+// Entry points the wallet calls with untrusted messages
+const ENTRY_POINTS = ["execute", "register_external_event"];
+// Writes that change who owns the ownable or what it is
+const STATE_WRITE = /\.(save|update|remove)\(|\.owner\s*=/;
+// Ways a handler checks the sender, including acting on the sender's own
+// balance
+const SENDER_CHECK = /\bauthorize\(|ContractError::Unauthorized|verify_\w+\(|\binfo\.sender\b/;
+// Ways a handler checks the lock
+const LOCK_CHECK = /\bensure_unlocked\(|\bLOCKED\.(load|may_load|update)\(/;
+// `format!` building a JSON object or field by hand
+const FORMATTED_JSON = /format!\(\s*r?#*"\s*(\{\{|[^"]*\\"\w+\\"\s*:)/g;
+
+const SEVERITIES = ["high", "medium"];
+
+/**
+ * Handlers `execute` dispatches messages to, e.g. `try_transfer`
+ */
+function executeHandlers(functions) {
+  const execute = functions.find((fn) => fn.name === "execute");
+  if (!execute) return new Set();
+  return new Set([...execute.body.matchAll(/\b(try_\w+)\s*\(/g)].map((match) => match[1]));
+}
+
+function bodyLine(fn, index) {
+  return fn.bodyLine + lineOf(fn.body, index) - 1;
+}
+
+const RULES = {
+  "unwrap-in-handler": {
+    severity: "high",
+    check: (fn, context) => {
+      if (!ENTRY_POINTS.includes(fn.name) && !context.handlers.has(fn.name)) return [];
+      return [...fn.body.matchAll(/\.unwrap\(\)/g)].map((match) => ({
+        line: bodyLine(fn, match.index),
+        message:
+          `\`${fn.name}\` calls \`.unwrap()\`; a bad message panics instead of returning an error`,
+        fix: "propagate the error with `?` or map it to a `ContractError`",
+      }));
+    },
+  },
+  "missing-sender-check": {
+    severity: "high",
+    check: (fn, context) => {
+      if (!context.handlers.has(fn.name) || !STATE_WRITE.test(fn.body)) return [];
+      if (SENDER_CHECK.test(fn.body)) return [];
+      return [{
+        line: fn.line,
+        message: `\`${fn.name}\` writes state without checking the sender`,
+        fix: "call `authorize` or compare the sender against the owner first",
+      }];
+    },
+  },
+  "missing-lock-check": {
+    severity: "medium",
+    check: (fn, context) => {
+      if (!context.handlers.has(fn.name) || !/\.owner\s*=/.test(fn.body)) return [];
+      if (LOCK_CHECK.test(fn.body)) return [];
+      return [{
+        line: fn.line,
+        message: `\`${fn.name}\` changes the owner without checking the lock`,
+        fix: "call `ensure_unlocked` before changing ownership",
+      }];
+    },
+  },
+  "formatted-json": {
+    severity: "medium",
+    check: (fn) =>
+      [...fn.body.matchAll(FORMATTED_JSON)].map((match) => ({
+        line: bodyLine(fn, match.index),
+        message: `\`${fn.name}\` builds JSON with \`format!\`; values are not escaped`,
+        fix: "serialize a struct with `to_json_binary` or `serde_json` instead",
+      })),
+  },
+};
+
+/**
+ * Audit a single Rust source, returning findings with line and severity
+ */
+function auditSource(source, file, allow = []) {
+  const functions = findFunctions(source);
+  const context = { handlers: executeHandlers(functions) };
+  const findings = [];
+  for (const [rule, { severity, check }] of Object.entries(RULES)) {
+    if (allow.includes(rule)) continue;
+    for (const fn of functions) {
+      for (const finding of check(fn, context)) {
+        findings.push({ file, rule, severity, ...finding });
+      }
+    }
+  }
+  return findings.sort(
+    (a, b) => SEVERITIES.indexOf(a.severity) - SEVERITIES.indexOf(b.severity) || a.line - b.line
+  );
+}
+
+async function audit(dir = ".", options = {}) {
+  const allow = options.allow || [];
+  const unknown = allow.filter((rule) => !RULES[rule]);
+  if (unknown.length > 0) {
+    throw new Error(
+      `Unknown rule ${unknown.join(", ")}. Rules: ${Object.keys(RULES).join(", ")}`
+    );
+  }
+
+  const projectDir = path.resolve(dir);
+  const files = await listRustFiles(path.join(projectDir, "src"));
+  if (files.length === 0) {
+    throw new Error(`No Rust sources found in ${path.join(projectDir, "src")}`);
+  }
+
+  const findings = [];
+  for (const file of files) {
+    const source = await fs.readFile(file, "utf8");
+    findings.push(...auditSource(source, path.relative(projectDir, file), allow));
+  }
+
+  const color = { high: chalk.red, medium: chalk.yellow };
+  for (const finding of findings) {
+    console.log(
+      `${chalk.yellow(`${finding.file}:${finding.line}`)} ${color[finding.severity](
+        finding.severity
+      )} ${finding.message} ${chalk.gray(`[${finding.rule}]`)}`
+    );
+    console.log(`  ${chalk.cyan("fix:")} ${finding.fix}`);
+  }
+
+  const high = findings.filter((finding) => finding.severity === "high").length;
+  if (high > 0) {
+    throw new Error(`${high} high severity finding${high === 1 ? "" : "s"}`);
+  }
+  if (findings.length === 0) {
+    console.log(chalk.green("✓ No audit findings"));
+  }
+  return findings;
+}
+
+module.exports = { audit, auditSource, RULES };
@@ -9,11 +9,13 @@ const toml = require("@iarna/toml");
 const sharp = require("sharp");
 const ora = require("ora");
 const { EventChain, LTO, Event } = require("@ltonetwork/lto");
+const crypto = require("crypto");
 const { execAsync } = require("../utils/execAsync");
 const {
   getOwnableType,
   handleStaticOwnable,
   handleMusicOwnable,
+  handleDocumentOwnable,
 } = require("../utils/ownableTypes");
 
 
@@ -155,6 +157,26 @@ async function checkPrerequisites() {
   }
 }
 
+/**
+ * The crate version becomes the cw2 contract version recorded on-chain,
+ * so warn when it was never set
+ */
+async function checkCrateVersion(cargoPath) {
+  const source = await fs.readFile(cargoPath, "utf8");
+  // an unrendered `version = PLACEHOLDER1_VERSION` isn't valid TOML
+  const placeholder = source.match(/^version\s*=\s*"?(PLACEHOLDER\w*)/m);
+  const version = placeholder
+    ? placeholder[1]
+    : (toml.parse(source).package || {}).version;
+  if (!version || version === "0.0.0" || version.includes("PLACEHOLDER")) {
+    console.warn(
+      chalk.yellow(
+        `Crate version '${version || ""}' in Cargo.toml is not set. It is recorded on-chain as the contract version; update it before building.`
+      )
+    );
+  }
+}
+
 async function checkProjectStructure() {
   const cwd = process.cwd();
 
@@ -165,6 +187,8 @@ async function checkProjectStructure() {
     );
   }
 
+  await checkCrateVersion(path.join(cwd, "Cargo.toml"));
+
   // Check if src directory exists
   if (!fs.existsSync(path.join(cwd, "src"))) {
     throw new Error(
@@ -197,8 +221,64 @@ async function checkProjectStructure() {
   }
 }
 
-async function buildWasm(projectPath, spinner) {
+/**
+ * Flags that keep machine-specific paths out of the module, so the same
+ * source and toolchain produce byte-identical wasm
+ */
+async function reproducibleBuildEnv(projectPath, targetDir) {
+  if (!(await fs.pathExists(path.join(projectPath, "Cargo.lock")))) {
+    throw new Error(
+      "Reproducible builds need a Cargo.lock. Run `cargo generate-lockfile` and commit it."
+    );
+  }
+  const hasToolchain = await Promise.all(
+    ["rust-toolchain.toml", "rust-toolchain"].map((file) =>
+      fs.pathExists(path.join(projectPath, file))
+    )
+  );
+  if (!hasToolchain.includes(true)) {
+    console.warn(
+      chalk.yellow(
+        "\nNo rust-toolchain.toml found. Pin the toolchain so others can reproduce this build."
+      )
+    );
+  }
+
+  const cargoHome = process.env.CARGO_HOME || path.join(os.homedir(), ".cargo");
+  return {
+    rustflags: [
+      `--remap-path-prefix=${projectPath}=/ownable`,
+      `--remap-path-prefix=${targetDir}=/target`,
+      `--remap-path-prefix=${cargoHome}=/cargo`,
+      "-C codegen-units=1",
+    ].join(" "),
+    cargoArgs: "--locked",
+    bindgenArgs: "--remove-name-section --remove-producers-section",
+  };
+}
+
+/**
+ * wasm-opt rejects a module using features it wasn't told to enable, so
+ * enable the ones RUSTFLAGS compiles with: atomics, bulk memory and mutable
+ * globals
+ */
+function wasmOptArgs(wasmPath) {
+  return [
+    "-Os",
+    "--enable-threads",
+    "--enable-bulk-memory",
+    "--enable-mutable-globals",
+    `"${wasmPath}"`,
+    "-o",
+    `"${wasmPath}"`,
+  ];
+}
+
+async function buildWasm(projectPath, spinner, options = {}) {
   const buildDir = path.join(projectPath, "build");
+  const targetDir = options.targetDir
+    ? path.resolve(options.targetDir)
+    : path.join(projectPath, "target");
 
   try {
     await fs.ensureDir(buildDir);
@@ -212,16 +292,21 @@ async function buildWasm(projectPath, spinner) {
     const wasmPath = path.join(buildDir, `${projectName}_bg.wasm`);
     const jsPath = path.join(buildDir, `${projectName}.js`);
 
+    const reproducible = options.reproducible
+      ? await reproducibleBuildEnv(projectPath, targetDir)
+      : { rustflags: "", cargoArgs: "", bindgenArgs: "" };
+
     // Configure build environment
     process.env.RUSTFLAGS =
-      "-C target-feature=+atomics,+bulk-memory,+mutable-globals";
-    process.env.CARGO_TARGET_DIR = path.join(projectPath, "target");
+      `-C target-feature=+atomics,+bulk-memory,+mutable-globals ${reproducible.rustflags}`.trim();
+    process.env.CARGO_TARGET_DIR = targetDir;
+    if (options.reproducible) process.env.SOURCE_DATE_EPOCH = "0";
 
     // Build WASM module
     spinner.text = "Building WebAssembly module...";
     try {
       const { stdout: wasmStdout, stderr: wasmStderr } = await execAsync(
-        "cargo build --target wasm32-unknown-unknown --release",
+        `cargo build --target wasm32-unknown-unknown --release ${reproducible.cargoArgs}`.trim(),
         { cwd: projectPath }
       );
       if (wasmStderr) console.error(chalk.yellow(wasmStderr));
@@ -233,7 +318,12 @@ async function buildWasm(projectPath, spinner) {
     spinner.text = "Generating JavaScript bindings...";
     try {
       const { stdout: bindgenStdout, stderr: bindgenStderr } = await execAsync(
-        `wasm-bindgen target/wasm32-unknown-unknown/release/${projectName}.wasm --out-dir ${buildDir} --target web`,
+        `wasm-bindgen "${path.join(
+          targetDir,
+          "wasm32-unknown-unknown",
+          "release",
+          `${projectName}.wasm`
+        )}" --out-dir "${buildDir}" --target web ${reproducible.bindgenArgs}`.trim(),
         { cwd: projectPath }
       );
       if (bindgenStderr) console.error(chalk.yellow(bindgenStderr));
@@ -241,6 +331,26 @@ async function buildWasm(projectPath, spinner) {
       throw new Error(`WASM bindgen failed: ${error.message}`);
     }
 
+    // Shrink the module with wasm-opt when available
+    if (options.optimize !== false) {
+      if (shell.which("wasm-opt")) {
+        spinner.text = "Optimizing WebAssembly module...";
+        try {
+          await execAsync(`wasm-opt ${wasmOptArgs(wasmPath).join(" ")}`, {
+            cwd: projectPath,
+          });
+        } catch (error) {
+          throw new Error(`wasm-opt failed: ${error.message}`);
+        }
+      } else {
+        console.warn(
+          chalk.yellow(
+            "\nwasm-opt not found, skipping optimization. Install binaryen to produce smaller packages."
+          )
+        );
+      }
+    }
+
     // Check if schema directory exists in build directory
     const schemaDir = path.join(buildDir, "schema");
     const schemaExists = await fs.pathExists(schemaDir);
@@ -309,9 +419,14 @@ async function buildWasm(projectPath, spinner) {
     }
 
     spinner.text = "Build process completed";
+    const sha256 = crypto
+      .createHash("sha256")
+      .update(await fs.readFile(wasmPath))
+      .digest("hex");
     return {
       wasmPath,
       jsPath,
+      sha256,
     };
   } catch (error) {
     throw new Error(`Failed to build WebAssembly: ${error.message}`);
@@ -412,7 +527,12 @@ async function createPackage(
     );
 
     // Handle assets based on ownable type
-    if (ownableType === "static-ownable") {
+    if (
+      ownableType === "static-ownable" ||
+      ownableType === "subscription-ownable" ||
+      ownableType === "consumable-ownable" ||
+      ownableType === "artwork-ownable"
+    ) {
       const contentInfo = await handleStaticOwnable(
         projectPath,
         outputPath,
@@ -432,6 +552,25 @@ async function createPackage(
         path.join(outputPath, "index.html"),
         updatedHtml
       );
+    } else if (ownableType === "document-ownable") {
+      const contentInfo = await handleDocumentOwnable(
+        projectPath,
+        outputPath,
+        metadata,
+        spinner
+      );
+      // Update index.html with correct document and cover references
+      const indexHtml = await fs.promises.readFile(
+        path.join(projectPath, "assets", "index.html"),
+        "utf8"
+      );
+      const updatedHtml = indexHtml
+        .replace(/PLACEHOLDER2_DOC/g, `documents/${contentInfo.documentFile}`)
+        .replace(/PLACEHOLDER2_IMG/g, `images/${contentInfo.imageFile}`);
+      await fs.promises.writeFile(
+        path.join(outputPath, "index.html"),
+        updatedHtml
+      );
     } else if (ownableType === "music-ownable") {
       const contentInfo = await handleMusicOwnable(
         projectPath,
@@ -463,6 +602,12 @@ async function createPackage(
       );
     }
 
+    // Copy the widget state bridge alongside index.html
+    const widgetJs = path.join(projectPath, "assets", "widget.js");
+    if (await fs.pathExists(widgetJs)) {
+      await fs.promises.copyFile(widgetJs, path.join(outputPath, "widget.js"));
+    }
+
     // Create ZIP file
     const zip = new JSZip();
     const addDirToZip = async (dirPath, zipPath = "") => {
@@ -500,7 +645,7 @@ async function createPackage(
   }
 }
 
-async function build() {
+async function build(options = {}) {
   const spinner = ora({
     text: "Starting build process...",
     color: "cyan",
@@ -510,7 +655,7 @@ async function build() {
     },
   }).start();
 
-  const totalSteps = 6; // Total number of major steps
+  const totalSteps = 5; // Total number of major steps
   let currentStep = 0;
 
   const updateProgress = (step, message) => {
@@ -520,11 +665,23 @@ async function build() {
   };
 
   try {
+    if (options.dir) {
+      // --target-dir is relative to where the command was run
+      if (options.targetDir) {
+        options = { ...options, targetDir: path.resolve(options.targetDir) };
+      }
+      process.chdir(path.resolve(options.dir));
+    }
+
     updateProgress(1, "Checking environment...");
     await Promise.all([checkPrerequisites(), checkProjectStructure()]);
 
     updateProgress(2, "Building WebAssembly...");
-    const { wasmPath, jsPath } = await buildWasm(process.cwd(), spinner);
+    const { wasmPath, jsPath, sha256 } = await buildWasm(
+      process.cwd(),
+      spinner,
+      options
+    );
 
     updateProgress(3, "Preparing package...");
     const metadata = await getMetadataFromCargo();
@@ -550,12 +707,12 @@ async function build() {
     const eventChain = new EventChain(account);
 
     updateProgress(5, "Creating package...");
-    const tmpDir = await fs.promises.mkdtemp(
-      path.join(os.tmpdir(), "ownable-")
-    );
+    // the rendered package stays in build/package for `ownables package`
+    const packageDir = path.join(process.cwd(), "build", "package");
+    await fs.emptyDir(packageDir);
     const zipPath = await createPackage(
       process.cwd(),
-      tmpDir,
+      packageDir,
       wasmPath,
       jsPath,
       metadata,
@@ -564,11 +721,13 @@ async function build() {
       spinner
     );
 
-    // Cleanup
-    updateProgress(6, "Cleaning up...");
-    await fs.remove(tmpDir);
-
     spinner.succeed("Build completed successfully! 🎉");
+    const { size } = await fs.stat(wasmPath);
+    console.log(`\nWASM module: ${(size / 1024).toFixed(1)} KB`);
+    if (options.reproducible) {
+      const { stdout: rustc } = await execAsync("rustc --version");
+      console.log(`WASM sha256: ${sha256} (${rustc.trim()})`);
+    }
     console.log(`\nPackage created at: ${zipPath}`);
   } catch (error) {
     spinner.fail("Build failed");
@@ -613,5 +772,9 @@ async function clean(projectPath) {
 
 module.exports = {
   build,
+  buildWasm,
   clean,
+  checkCrateVersion,
+  reproducibleBuildEnv,
+  wasmOptArgs,
 };
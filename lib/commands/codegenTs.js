@@ -0,0 +1,195 @@
+const chalk = require("chalk");
+const fs = require("fs-extra");
+const path = require("path");
+const { schema } = require("./schema");
+
+const IDENTIFIER = /^[A-Za-z_$][A-Za-z0-9_$]*$/;
+
+function pascalCase(name) {
+  return name
+    .split(/[_\s-]+/)
+    .filter(Boolean)
+    .map((part) => part[0].toUpperCase() + part.slice(1))
+    .join("");
+}
+
+function refName(ref) {
+  return ref.replace(/^#\/definitions\//, "");
+}
+
+function docComment(description, indent) {
+  if (!description) return "";
+  const lines = description.trim().split("\n");
+  if (lines.length === 1) return `${indent}/** ${lines[0]} */\n`;
+  const body = lines.map((line) => `${indent} * ${line}`.trimEnd()).join("\n");
+  return `${indent}/**\n${body}\n${indent} */\n`;
+}
+
+function union(types) {
+  const unique = [...new Set(types)];
+  return unique.length === 0 ? "never" : unique.join(" | ");
+}
+
+function propertyName(name) {
+  return IDENTIFIER.test(name) ? name : JSON.stringify(name);
+}
+
+function objectBody(node, indent) {
+  const required = new Set(node.required || []);
+  const fields = Object.entries(node.properties || {}).map(
+    ([name, field]) => {
+      const optional = required.has(name) ? "" : "?";
+      return (
+        docComment(field.description, `${indent}  `) +
+        `${indent}  ${propertyName(name)}${optional}: ${tsType(field, `${indent}  `)};`
+      );
+    }
+  );
+  return fields.length === 0 ? "{}" : `{\n${fields.join("\n")}\n${indent}}`;
+}
+
+/**
+ * TypeScript type for a JSON schema node, inlining anonymous objects
+ */
+function tsType(node, indent = "") {
+  if (node === true || node === undefined) return "unknown";
+  if (node.$ref) return refName(node.$ref);
+  if (node.enum) return union(node.enum.map((value) => JSON.stringify(value)));
+  if (node.const !== undefined) return JSON.stringify(node.const);
+  for (const key of ["anyOf", "oneOf"]) {
+    if (node[key]) return union(node[key].map((member) => tsType(member, indent)));
+  }
+  if (node.allOf) {
+    return node.allOf.map((member) => tsType(member, indent)).join(" & ");
+  }
+  if (Array.isArray(node.type)) {
+    return union(node.type.map((type) => tsType({ ...node, type }, indent)));
+  }
+
+  switch (node.type) {
+    case "string":
+      return "string";
+    case "integer":
+    case "number":
+      return "number";
+    case "boolean":
+      return "boolean";
+    case "null":
+      return "null";
+    case "array":
+      if (Array.isArray(node.items)) {
+        return `[${node.items.map((item) => tsType(item, indent)).join(", ")}]`;
+      }
+      return `${wrapArrayItem(tsType(node.items, indent))}[]`;
+    case "object":
+      if (node.properties) return objectBody(node, indent);
+      if (node.additionalProperties && node.additionalProperties !== true) {
+        return `Record<string, ${tsType(node.additionalProperties, indent)}>`;
+      }
+      // empty struct variants like `Lock {}`
+      return "Record<string, never>";
+    default:
+      return "unknown";
+  }
+}
+
+function wrapArrayItem(type) {
+  return /[|&]/.test(type) ? `(${type})` : type;
+}
+
+/**
+ * Serde's externally tagged enums come out as `oneOf` members that are
+ * either a plain string or an object with a single required key
+ */
+function enumVariants(node) {
+  const members = node.oneOf || node.anyOf;
+  if (!members) return null;
+  const variants = [];
+  for (const member of members) {
+    if (member.type === "string" && member.enum) {
+      member.enum.forEach((tag) => variants.push({ tag, unit: true, member }));
+    } else if (
+      member.type === "object" &&
+      member.required &&
+      member.required.length === 1 &&
+      Object.keys(member.properties || {}).length === 1
+    ) {
+      const tag = member.required[0];
+      variants.push({ tag, payload: member.properties[tag], member });
+    } else {
+      return null;
+    }
+  }
+  return variants;
+}
+
+function declare(name, node) {
+  const doc = docComment(node.description, "");
+  const variants = enumVariants(node);
+
+  if (variants && variants.some((variant) => !variant.unit)) {
+    // `ExecuteMsg.Transfer` holds the payload of `{ transfer: { .. } }`
+    const members = variants.map((variant) =>
+      variant.unit
+        ? JSON.stringify(variant.tag)
+        : `{ ${propertyName(variant.tag)}: ${name}.${pascalCase(variant.tag)} }`
+    );
+    const payloads = variants
+      .filter((variant) => !variant.unit)
+      .map(
+        (variant) =>
+          docComment(variant.member.description, "  ") +
+          `  export type ${pascalCase(variant.tag)} = ${tsType(variant.payload, "  ")};`
+      );
+    return (
+      `${doc}export type ${name} =\n${members.map((member) => `  | ${member}`).join("\n")};\n\n` +
+      `export namespace ${name} {\n${payloads.join("\n")}\n}\n`
+    );
+  }
+  if (node.type === "object" && node.properties) {
+    return `${doc}export interface ${name} ${objectBody(node, "")}\n`;
+  }
+  return `${doc}export type ${name} = ${tsType(node)};\n`;
+}
+
+/**
+ * Emit TypeScript declarations for a set of JSON schemas as written by
+ * `cargo schema`. Definitions shared between schemas are declared once.
+ */
+function schemaToTypeScript(schemas) {
+  const declarations = new Map();
+  for (const root of schemas) {
+    if (!root.title) continue;
+    const name = pascalCase(root.title);
+    if (!declarations.has(name)) declarations.set(name, declare(name, root));
+    for (const [defName, node] of Object.entries(root.definitions || {})) {
+      if (!declarations.has(defName)) declarations.set(defName, declare(defName, node));
+    }
+  }
+
+  return [
+    "// Generated by `ownables codegen-ts` from the contract's JSON schema. Do not edit.",
+    "",
+    ...[...declarations.values()].map((declaration) => declaration.trimEnd() + "\n"),
+  ].join("\n");
+}
+
+/**
+ * Generate the crate's JSON schema and write TypeScript bindings for it
+ */
+async function codegenTs(dir = ".", options = {}) {
+  if (!options.out) {
+    throw new Error("Missing output file: pass --out <file>");
+  }
+
+  const files = await schema(dir);
+  const schemas = await Promise.all(files.map((file) => fs.readJson(file)));
+  const output = path.resolve(options.out);
+  await fs.ensureDir(path.dirname(output));
+  await fs.writeFile(output, schemaToTypeScript(schemas));
+
+  console.log(chalk.green(`✓ TypeScript bindings written to ${output}`));
+  return output;
+}
+
+module.exports = { codegenTs, schemaToTypeScript };
@@ -0,0 +1,139 @@
+const chalk = require("chalk");
+const fs = require("fs-extra");
+const path = require("path");
+const { METADATA_FIELDS, validateMetadata } = require("../utils/metadata");
+
+const FORMATS = ["erc721", "ownable"];
+
+// ERC-721 (OpenSea) metadata keys that have an ownable Metadata counterpart
+const ERC721_FIELDS = {
+  name: "name",
+  description: "description",
+  image: "image",
+  image_data: "image_data",
+  animation_url: "animation_url",
+  external_url: "external_url",
+  background_color: "background_color",
+  youtube_url: "youtube_url",
+};
+
+// ERC-721 display types whose values are numbers; ownable traits store
+// every value as a string
+const NUMERIC_DISPLAY_TYPES = ["number", "boost_number", "boost_percentage", "date"];
+
+/**
+ * ERC-721 `attributes` as the ownable's `{ trait_type, value, display_type }`
+ * traits, which are passed to instantiate alongside the Metadata
+ */
+function erc721ToTraits(attributes, warn) {
+  if (!Array.isArray(attributes)) {
+    warn("'attributes' is not a list and was dropped");
+    return [];
+  }
+  const traits = [];
+  attributes.forEach((attribute, index) => {
+    const { trait_type, value, display_type } = attribute || {};
+    if (typeof trait_type !== "string" || !["string", "number", "boolean"].includes(typeof value)) {
+      warn(`attribute ${index} has no trait_type and scalar value and was dropped`);
+      return;
+    }
+    const trait = { trait_type, value: String(value) };
+    if (typeof display_type === "string") trait.display_type = display_type;
+    traits.push(trait);
+  });
+  return traits;
+}
+
+function traitsToErc721(traits) {
+  return traits.map(({ trait_type, value, display_type }) => {
+    const numeric = NUMERIC_DISPLAY_TYPES.includes(display_type) && value.trim() !== "" && !isNaN(value);
+    const attribute = { trait_type, value: numeric ? Number(value) : value };
+    if (display_type) attribute.display_type = display_type;
+    return attribute;
+  });
+}
+
+function erc721ToOwnable(input, warn) {
+  const metadata = {};
+  let attributes;
+  for (const [key, value] of Object.entries(input)) {
+    const field = ERC721_FIELDS[key];
+    if (key === "attributes") {
+      attributes = erc721ToTraits(value, warn);
+    } else if (!field) {
+      warn(`'${key}' has no ownable Metadata field and was dropped`);
+    } else if (value !== null && typeof value !== "string") {
+      warn(`'${key}' is not a string and was dropped`);
+    } else {
+      metadata[field] = value;
+    }
+  }
+  validateMetadata(metadata);
+  return attributes ? { ...metadata, attributes } : metadata;
+}
+
+function ownableToErc721(input, warn) {
+  const { attributes, ...fields } = input;
+  validateMetadata(fields);
+  const reverse = Object.fromEntries(
+    Object.entries(ERC721_FIELDS).map(([key, field]) => [field, key])
+  );
+  const metadata = {};
+  for (const field of METADATA_FIELDS) {
+    if (input[field] === undefined || input[field] === null) continue;
+    if (!reverse[field]) {
+      warn(`'${field}' has no ERC-721 field and was dropped`);
+      continue;
+    }
+    metadata[reverse[field]] = input[field];
+  }
+  if (Array.isArray(attributes)) {
+    metadata.attributes = traitsToErc721(attributes);
+  } else if (attributes !== undefined) {
+    warn("'attributes' is not a list and was dropped");
+  }
+  return metadata;
+}
+
+function convert(input, from, to, warn = () => {}) {
+  if (!FORMATS.includes(from) || !FORMATS.includes(to)) {
+    throw new Error(`Supported formats: ${FORMATS.join(", ")}`);
+  }
+  if (input === null || typeof input !== "object" || Array.isArray(input)) {
+    throw new Error("Metadata must be a JSON object");
+  }
+  if (from === to) {
+    return input;
+  }
+  return from === "erc721"
+    ? erc721ToOwnable(input, warn)
+    : ownableToErc721(input, warn);
+}
+
+async function convertMetadata(input, output, options) {
+  const inputPath = path.resolve(input);
+  if (!(await fs.pathExists(inputPath))) {
+    throw new Error(`Metadata file not found: ${inputPath}`);
+  }
+
+  let metadata;
+  try {
+    metadata = JSON.parse(await fs.readFile(inputPath, "utf8"));
+  } catch (error) {
+    throw new Error(`${inputPath}: ${error.message}`);
+  }
+
+  const converted = convert(metadata, options.from, options.to, (message) =>
+    console.warn(chalk.yellow(`Warning: ${message}`))
+  );
+
+  await fs.writeFile(
+    path.resolve(output),
+    JSON.stringify(converted, null, 2) + "\n"
+  );
+  console.log(
+    chalk.green(`✓ Converted ${options.from} metadata to ${options.to}: ${output}`)
+  );
+}
+
+module.exports = { convert, convertMetadata };
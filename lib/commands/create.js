@@ -2,6 +2,26 @@ const chalk = require("chalk");
 const fs = require("fs-extra");
 const path = require("path");
 const inquirer = require("inquirer");
+const os = require("os");
+const shell = require("shelljs");
+const { execAsync } = require("../utils/execAsync");
+const {
+  TEMPLATES,
+  getTemplate,
+  registerTemplate,
+  resolveTemplate,
+  resolveTemplateVersion,
+  templateDirs,
+  templateFeatures,
+  unregisterTemplate,
+} = require("../utils/templates");
+const { fromCargoGenerate } = require("../utils/cargoGenerate");
+const { readOwnableToml } = require("../utils/ownableToml");
+const { openTemplateSource, findTemplateDir } = require("../utils/templateSource");
+const {
+  findPlaceholders,
+  formatPlaceholders,
+} = require("../utils/placeholders");
 
 async function getTemplateType() {
   return inquirer.prompt([
@@ -9,16 +29,10 @@ async function getTemplateType() {
       type: "list",
       name: "template",
       message: "What type of Ownable would you like to create?",
-      choices: [
-        {
-          name: "Static Ownable - A simple static image or content display",
-          value: "static-ownable",
-        },
-        {
-          name: "Music Ownable - Audio with cover art and backdrop image",
-          value: "music-ownable",
-        },
-      ],
+      choices: TEMPLATES.map((template) => ({
+        name: template.description,
+        value: template.name,
+      })),
     },
   ]);
 }
@@ -84,64 +98,156 @@ async function getMetadata() {
   return result;
 }
 
-async function replacePlaceholders(filePath, metadata) {
+// JSON strings are valid TOML basic strings
+const tomlString = (value) => JSON.stringify(String(value));
+
+// Rust has no `\b`, `\f` or `\uXXXX` escapes; use `\u{..}` for those
+const RUST_ESCAPES = { b: "\\u{8}", f: "\\u{c}" };
+
+function rustString(value) {
+  return JSON.stringify(String(value)).replace(
+    /\\(?:u([0-9a-f]{4})|(.))/g,
+    (escape, hex, char) => (hex ? `\\u{${hex}}` : RUST_ESCAPES[char] || escape)
+  );
+}
+
+async function replacePlaceholders(filePath, metadata, template) {
+  const ownableType = getTemplate(template);
   let content = await fs.readFile(filePath, "utf8");
 
+  // Extra ownable.toml values go first, so `PLACEHOLDER4_NAME` can't clobber
+  // a longer token such as `PLACEHOLDER4_NAME_SUFFIX`
+  for (const [key, value] of Object.entries(metadata.extra || {})) {
+    content = content.replace(
+      new RegExp(`PLACEHOLDER4_${key.toUpperCase()}\\b`, "g"),
+      typeof value === "string" ? rustString(value) : JSON.stringify(value)
+    );
+  }
+
   // Replace all placeholders with their corresponding values
   content = content
     // Cargo.toml placeholders
-    .replace(/PLACEHOLDER1_NAME/g, `"${metadata.name}"`)
-    .replace(/PLACEHOLDER1_DESCRIPTION/g, `"${metadata.description}"`)
-    .replace(/PLACEHOLDER1_VERSION/g, `"${metadata.version}"`)
-    .replace(/PLACEHOLDER1_AUTHORS/g, `"${metadata.authors}"`)
+    .replace(/PLACEHOLDER1_NAME/g, tomlString(metadata.name))
+    .replace(/PLACEHOLDER1_DESCRIPTION/g, tomlString(metadata.description))
+    .replace(/PLACEHOLDER1_VERSION/g, tomlString(metadata.version))
+    .replace(/PLACEHOLDER1_AUTHORS/g, tomlString(metadata.authors))
     .replace(
       /PLACEHOLDER1_KEYWORDS/g,
-      (metadata.keywords || []).map((k) => `"${k.trim()}"`).join(", ")
+      (metadata.keywords || []).map((k) => tomlString(k.trim())).join(", ")
     )
+    .replace(/PLACEHOLDER1_FEATURES/g, templateFeatures(ownableType))
 
     // Contract placeholders
-    .replace(/PLACEHOLDER4_CONTRACT_NAME/g, `"${metadata.name}"`)
-    .replace(/PLACEHOLDER4_TYPE/g, `"music"`)
-    .replace(/PLACEHOLDER4_DESCRIPTION/g, `"${metadata.description}"`)
-    .replace(/PLACEHOLDER4_NAME/g, `"${metadata.name}"`)
+    .replace(/PLACEHOLDER4_CONTRACT_NAME/g, rustString(metadata.name))
+    .replace(
+      /PLACEHOLDER4_OWNABLE_TYPE/g,
+      `OwnableType::${ownableType.variant}`
+    )
+    .replace(/PLACEHOLDER4_TYPE/g, rustString(ownableType.type))
+    .replace(/PLACEHOLDER4_DESCRIPTION/g, rustString(metadata.description))
+    .replace(/PLACEHOLDER4_NAME/g, rustString(metadata.name))
+    .replace(
+      /PLACEHOLDER4_IMAGE_DATA/g,
+      metadata.imageData ? `Some(${rustString(metadata.imageData)}.to_string())` : "None"
+    )
 
-    // Schema placeholders
-    .replace(/PLACEHOLDER3_MSG/g, metadata.name)
-    .replace(/PLACEHOLDER3_STATE/g, metadata.name)
+    // Schema placeholders, the crate name as a Rust path
+    .replace(/PLACEHOLDER3_MSG/g, metadata.name.replace(/-/g, "_"))
+    .replace(/PLACEHOLDER3_STATE/g, metadata.name.replace(/-/g, "_"))
+    .replace(/PLACEHOLDER3_CONTRACT/g, metadata.name.replace(/-/g, "_"))
 
-    // HTML placeholders
+    // HTML and README placeholders; asset paths such as PLACEHOLDER2_COVER
+    // are filled in by `build`
     .replace(/PLACEHOLDER2_TITLE/g, metadata.name)
     .replace(/PLACEHOLDER2_DESCRIPTION/g, metadata.description)
-    .replace(/PLACEHOLDER2_COVER/g, "PLACEHOLDER2_COVER")
-    .replace(/PLACEHOLDER2_BACKGROUND/g, "PLACEHOLDER2_BACKGROUND")
-    .replace(/PLACEHOLDER2_AUDIO/g, "PLACEHOLDER2_AUDIO");
+    .replace(/PLACEHOLDER2_TYPE/g, ownableType.type);
+
+  // Templates written for cargo-generate use `{{project-name}}`-style variables
+  content = fromCargoGenerate(content, metadata);
 
   await fs.writeFile(filePath, content);
 }
 
-async function create() {
-  console.log(chalk.blue("Creating new Ownable template..."));
+// Files that receive placeholder substitution, relative to the project
+const TEMPLATE_FILES = [
+  "Cargo.toml",
+  "src/contract.rs",
+  "src/msg.rs",
+  "src/state.rs",
+  "src/error.rs",
+  "src/lib.rs",
+  "examples/schema.rs",
+  "examples/simulate.rs",
+  "examples/simulate_query.rs",
+  "assets/index.html",
+  "assets/widget.js",
+  "README.md",
+];
 
-  // Get template type from user
-  const { template } = await getTemplateType();
-  console.log(chalk.green("✓ Template type selected"));
+// Source of the generated project's README.md
+const README_TEMPLATE = "template.md.tmpl";
 
-  // Get metadata from user
-  const metadata = await getMetadata();
-  console.log(chalk.green("✓ Metadata collected"));
+// Records how a project was generated so it can be regenerated by `reset`
+const MANIFEST_PATH = path.join(".ownables", "manifest.json");
 
-  // Create project directory
-  const projectDir = path.join(process.cwd(), metadata.name);
-  if (fs.existsSync(projectDir)) {
-    throw new Error(`Directory ${metadata.name} already exists`);
+async function installReadme(projectDir) {
+  const source = path.join(projectDir, README_TEMPLATE);
+  if (fs.existsSync(source)) {
+    await fs.move(source, path.join(projectDir, "README.md"), {
+      overwrite: true,
+    });
+  }
+}
+
+async function renderTemplateFiles(projectDir, metadata, template, filter) {
+  for (const relativePath of TEMPLATE_FILES.filter(filter || (() => true))) {
+    const file = path.join(projectDir, relativePath);
+    if (fs.existsSync(file)) {
+      await replacePlaceholders(file, metadata, template);
+    } else {
+      console.warn(
+        chalk.yellow(
+          `Warning: File ${file} not found, skipping placeholder replacement`
+        )
+      );
+    }
+  }
+}
+
+async function writeManifest(projectDir, template, templateVersion, metadata, templateSource) {
+  const manifestPath = path.join(projectDir, MANIFEST_PATH);
+  await fs.ensureDir(path.dirname(manifestPath));
+  await fs.writeFile(
+    manifestPath,
+    JSON.stringify({ template, templateVersion, templateSource, metadata }, null, 2) + "\n"
+  );
+}
+
+async function readManifest(projectDir) {
+  const manifestPath = path.join(projectDir, MANIFEST_PATH);
+  if (!fs.existsSync(manifestPath)) {
+    throw new Error(
+      `No ${MANIFEST_PATH} found in ${projectDir}. Was it created with ownables-cli?`
+    );
+  }
+  return JSON.parse(await fs.readFile(manifestPath, "utf8"));
+}
+
+async function scaffold(template, metadata, projectDir, options = {}) {
+  const templateVersion = resolveTemplateVersion(options.templateVersion);
+  if (fs.existsSync(projectDir) && !options.force) {
+    throw new Error(`Directory ${projectDir} already exists`);
   }
 
   // Copy template
-  const templateDir = path.join(__dirname, "../../templates", template);
-  await fs.copy(templateDir, projectDir);
+  for (const dir of templateDirs(getTemplate(template))) {
+    await fs.copy(dir, projectDir);
+  }
 
-  // Create type.txt to identify the ownable type
-  await fs.writeFile(path.join(projectDir, "type.txt"), template);
+  // Create type.txt to identify the ownable type; custom templates are
+  // packaged like the built-in template for their type
+  const packageAs = getTemplate(template).packageAs || template;
+  await fs.writeFile(path.join(projectDir, "type.txt"), packageAs);
 
   // Create assets directory if it doesn't exist
   const assetsDir = path.join(projectDir, "assets");
@@ -150,35 +256,106 @@ async function create() {
   const imagesDir = path.join(assetsDir, "images");
   await fs.ensureDir(imagesDir);
 
-  if (template === "music-ownable") {
+  if (packageAs === "music-ownable") {
     const audioDir = path.join(assetsDir, "audio");
     await fs.ensureDir(audioDir);
   }
+  if (packageAs === "document-ownable") {
+    await fs.ensureDir(path.join(assetsDir, "documents"));
+  }
+
+  await installReadme(projectDir);
 
   // Replace placeholders in all relevant files
-  const filesToUpdate = [
-    path.join(projectDir, "Cargo.toml"),
-    path.join(projectDir, "src", "contract.rs"),
-    path.join(projectDir, "src", "msg.rs"),
-    path.join(projectDir, "src", "state.rs"),
-    path.join(projectDir, "src", "error.rs"),
-    path.join(projectDir, "src", "lib.rs"),
-    path.join(projectDir, "examples", "schema.rs"),
-    path.join(assetsDir, "index.html"),
-  ];
-
-  for (const file of filesToUpdate) {
-    if (fs.existsSync(file)) {
-      await replacePlaceholders(file, metadata);
+  await renderTemplateFiles(projectDir, metadata, template);
+  await writeManifest(projectDir, template, templateVersion, metadata, options.templateSource);
+
+  await assertNoPlaceholders(projectDir);
+}
+
+async function assertNoPlaceholders(projectDir) {
+  const leftovers = await findPlaceholders(projectDir);
+  if (leftovers.length > 0) {
+    throw new Error(
+      `Unsubstituted placeholders in ${projectDir}:\n${formatPlaceholders(
+        leftovers
+      )}`
+    );
+  }
+}
+
+async function listFiles(dir, base = dir) {
+  const files = [];
+  for (const entry of await fs.readdir(dir, { withFileTypes: true })) {
+    const fullPath = path.join(dir, entry.name);
+    if (entry.isDirectory()) {
+      files.push(...(await listFiles(fullPath, base)));
     } else {
-      console.warn(
-        chalk.yellow(
-          `Warning: File ${file} not found, skipping placeholder replacement`
-        )
-      );
+      files.push(path.relative(base, fullPath));
     }
   }
+  return files.sort();
+}
+
+async function unifiedDiff(from, to, label) {
+  if (!shell.which("diff")) return null;
+  try {
+    await execAsync(
+      `diff -u --label "a/${label}" --label "b/${label}" "${from}" "${to}"`
+    );
+    return "";
+  } catch (error) {
+    // diff exits with 1 when the files differ
+    return error.code === 1 ? error.stdout : null;
+  }
+}
+
+/**
+ * Scaffold into a temporary directory and report how `projectDir` would
+ * change, without writing to it
+ */
+async function dryRunScaffold(template, metadata, projectDir, options = {}) {
+  const tmpDir = await fs.mkdtemp(path.join(os.tmpdir(), "ownable-dry-run-"));
+  try {
+    const previewDir = path.join(tmpDir, path.basename(projectDir));
+    await scaffold(template, metadata, previewDir, {
+      templateVersion: options.templateVersion,
+      templateSource: options.templateSource,
+    });
+
+    const changes = [];
+    for (const file of await listFiles(previewDir)) {
+      const target = path.join(projectDir, file);
+      if (!fs.existsSync(target)) {
+        changes.push({ file, status: "created" });
+        continue;
+      }
+      const [generated, existing] = await Promise.all([
+        fs.readFile(path.join(previewDir, file)),
+        fs.readFile(target),
+      ]);
+      if (generated.equals(existing)) {
+        changes.push({ file, status: "unchanged" });
+        continue;
+      }
+      const diff = file.endsWith(".rs")
+        ? await unifiedDiff(target, path.join(previewDir, file), file)
+        : null;
+      changes.push({ file, status: "modified", diff });
+    }
 
+    const colors = { created: chalk.green, modified: chalk.yellow, unchanged: chalk.gray };
+    for (const { file, status, diff } of changes) {
+      console.log(`${colors[status](status.padEnd(9))} ${file}`);
+      if (diff) console.log(diff);
+    }
+    return changes;
+  } finally {
+    await fs.remove(tmpDir);
+  }
+}
+
+function printNextSteps(template) {
   console.log(chalk.green("\nOwnable template created successfully! 🎉"));
   console.log(chalk.blue("\nNext steps:"));
 
@@ -201,10 +378,181 @@ async function create() {
     console.log("   - Maximum dimensions: 4096x4096 pixels");
     console.log("   - Maximum size: 50MB per image");
     console.log("\n3. Run 'ownables-cli build' to build your Ownable");
+  } else if (template === "document-ownable") {
+    console.log("1. Add your PDF to the assets/documents directory");
+    console.log("   - Name it after the project, e.g. '<name>.pdf'");
+    console.log("   - Maximum size: 50MB");
+    console.log("2. Add a cover image to the assets/images directory");
+    console.log("3. Run 'ownables-cli build' to build your Ownable");
   } else {
     console.log("1. Add your image file to the assets/images directory");
     console.log("2. Run 'ownables-cli build' to build your Ownable");
   }
 }
 
-module.exports = { create };
+async function create() {
+  console.log(chalk.blue("Creating new Ownable template..."));
+
+  // Get template type from user
+  const { template } = await getTemplateType();
+  console.log(chalk.green("✓ Template type selected"));
+
+  // Get metadata from user
+  const metadata = await getMetadata();
+  console.log(chalk.green("✓ Metadata collected"));
+
+  // Create project directory
+  const projectDir = path.join(process.cwd(), metadata.name);
+  await scaffold(template, metadata, projectDir);
+
+  printNextSteps(template);
+}
+
+// Embedded images end up in contract state, so keep them small
+const MAX_IMAGE_SIZE = 256 * 1024;
+
+/**
+ * Read an image to embed as the metadata's `image_data`, base64-encoded
+ */
+async function readImageData(file) {
+  if (!(await fs.pathExists(file))) {
+    throw new Error(`Image ${file} not found`);
+  }
+  const { size } = await fs.stat(file);
+  if (size > MAX_IMAGE_SIZE) {
+    throw new Error(
+      `Image ${file} is ${Math.ceil(size / 1024)} KB; embedded images are limited to ` +
+        `${MAX_IMAGE_SIZE / 1024} KB. Use a smaller image or reference it by URL instead.`
+    );
+  }
+  return (await fs.readFile(file)).toString("base64");
+}
+
+/**
+ * Non-interactive variant of `create`, picking the template by ownable type
+ */
+async function newOwnable(name, options) {
+  // flags win over ownable.toml values
+  const manifest = options.manifest
+    ? await readOwnableToml(path.resolve(options.manifest))
+    : { extra: {} };
+  name = name || manifest.name;
+  const type = options.type || manifest.ownableType;
+  if (!name) {
+    throw new Error("Missing name: pass it as an argument or set `name` in ownable.toml");
+  }
+  if (!type) {
+    throw new Error("Missing ownable type: pass --type or set `ownable_type` in ownable.toml");
+  }
+
+  if (!/^[a-z0-9-]+$/.test(name)) {
+    throw new Error(
+      "Name can only contain lowercase letters, numbers, and hyphens"
+    );
+  }
+  if (!/^\d+\.\d+\.\d+$/.test(options.crateVersion)) {
+    throw new Error("Version must be in format x.y.z");
+  }
+
+  const metadata = {
+    name,
+    description: options.description || manifest.description || name,
+    version: options.crateVersion,
+    authors: options.authors || os.userInfo().username,
+    keywords: (options.keywords || type)
+      .split(",")
+      .map((k) => k.trim()),
+    extra: manifest.extra,
+    imageData: options.image ? await readImageData(path.resolve(options.image)) : undefined,
+  };
+
+  const source =
+    options.templatePath || options.templateRepo
+      ? await openTemplateSource(options)
+      : null;
+  let template;
+  try {
+    template = source
+      ? await registerCustomTemplate(source, type, options.template)
+      : resolveTemplate(type).name;
+    await generate(name, template, metadata, {
+      ...options,
+      templateSource: source && templateSource(options, type),
+    });
+  } finally {
+    if (source) {
+      // the template's directory goes away with its source
+      if (template) unregisterTemplate(template);
+      await source.cleanup();
+    }
+  }
+}
+
+/**
+ * Where a custom template came from, recorded in the manifest so `reset`
+ * can find it again
+ */
+function templateSource(options, type) {
+  return options.templatePath
+    ? { templatePath: path.resolve(options.templatePath), type }
+    : { templateRepo: options.templateRepo, type };
+}
+
+/**
+ * Register the template `name` from a custom source. It defaults to the
+ * built-in template's name for `type` and compiles to the same variant.
+ */
+async function registerCustomTemplate(source, type, name) {
+  const builtin = TEMPLATES.find((t) => t.type === type);
+  if (!name && !builtin) {
+    throw new Error(`No built-in template for ownable type '${type}'. Pass --template <name>.`);
+  }
+  const templateName = name || builtin.name;
+  return registerTemplate({
+    name: templateName,
+    type,
+    variant: builtin ? builtin.variant : type.charAt(0).toUpperCase() + type.slice(1),
+    description: `Custom template from ${source.label}`,
+    dir: await findTemplateDir(source, templateName),
+    packageAs: builtin && builtin.name,
+  }).name;
+}
+
+async function generate(name, template, metadata, options) {
+  const projectDir = path.resolve(options.dir || name);
+  if (options.dryRun) {
+    await dryRunScaffold(template, metadata, projectDir, {
+      templateVersion: options.templateVersion,
+      templateSource: options.templateSource,
+    });
+    return;
+  }
+  if (fs.existsSync(projectDir) && !options.force) {
+    throw new Error(
+      `Directory ${projectDir} already exists. Use --force to overwrite it.`
+    );
+  }
+  await scaffold(template, metadata, projectDir, {
+    force: options.force,
+    templateVersion: options.templateVersion,
+    templateSource: options.templateSource,
+  });
+  console.log(chalk.green(`✓ Created ${name} from ${template}`));
+
+  printNextSteps(template);
+}
+
+module.exports = {
+  create,
+  newOwnable,
+  replacePlaceholders,
+  scaffold,
+  dryRunScaffold,
+  renderTemplateFiles,
+  installReadme,
+  TEMPLATE_FILES,
+  readManifest,
+  registerCustomTemplate,
+  readImageData,
+  MAX_IMAGE_SIZE,
+};
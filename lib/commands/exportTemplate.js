@@ -0,0 +1,60 @@
+const chalk = require("chalk");
+const fs = require("fs-extra");
+const path = require("path");
+const {
+  getTemplate,
+  templateDirs,
+  templateFeatures,
+} = require("../utils/templates");
+const {
+  CARGO_GENERATE_TOML,
+  toCargoGenerate,
+} = require("../utils/cargoGenerate");
+const { TEMPLATE_FILES, installReadme } = require("./create");
+
+/**
+ * Write a built-in template as a cargo-generate template, so it can also be
+ * scaffolded with `cargo generate --path <output>`
+ */
+async function exportTemplate(name, output) {
+  const template = getTemplate(name);
+  const outputDir = path.resolve(output);
+  if (fs.existsSync(outputDir)) {
+    throw new Error(`Directory ${outputDir} already exists`);
+  }
+
+  for (const dir of templateDirs(template)) {
+    await fs.copy(dir, outputDir, {
+      filter: (src) => path.basename(src) !== ".DS_Store",
+    });
+  }
+
+  await installReadme(outputDir);
+
+  // The ownable type is fixed per template rather than prompted for, and
+  // cargo-generate has no way to embed an image
+  const fixed = {
+    PLACEHOLDER4_OWNABLE_TYPE: `OwnableType::${template.variant}`,
+    PLACEHOLDER4_TYPE: `"${template.type}"`,
+    PLACEHOLDER4_IMAGE_DATA: "None",
+    PLACEHOLDER1_FEATURES: templateFeatures(template),
+    PLACEHOLDER2_TYPE: template.type,
+  };
+  for (const relativePath of TEMPLATE_FILES) {
+    const file = path.join(outputDir, relativePath);
+    if (!fs.existsSync(file)) continue;
+    const content = await fs.readFile(file, "utf8");
+    await fs.writeFile(file, toCargoGenerate(content, fixed));
+  }
+
+  await fs.writeFile(
+    path.join(outputDir, "cargo-generate.toml"),
+    CARGO_GENERATE_TOML
+  );
+
+  console.log(
+    chalk.green(`✓ Exported ${template.name} as a cargo-generate template to ${outputDir}`)
+  );
+}
+
+module.exports = { exportTemplate };
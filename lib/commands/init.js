@@ -0,0 +1,70 @@
+const chalk = require("chalk");
+const shell = require("shelljs");
+const fs = require("fs-extra");
+const path = require("path");
+const ora = require("ora");
+const { execAsync } = require("../utils/execAsync");
+const { newOwnable } = require("./create");
+
+// The wallet build, and the entry point wiring it leaves out by default
+const CHECK_COMMANDS = [
+  "cargo check --quiet",
+  "cargo check --quiet --features entry-points",
+];
+
+// Everything a working ownable needs; `lib.rs` wires the contract to the
+// wallet through wasm-bindgen, and the `entry-points` feature adds cosmwasm
+// entry points for running it on a CosmWasm chain
+const WORKSPACE_FILES = [
+  "Cargo.toml",
+  "src/lib.rs",
+  "src/contract.rs",
+  "src/msg.rs",
+  "src/state.rs",
+  "src/error.rs",
+  "assets/index.html",
+];
+
+/**
+ * Scaffold a complete ownable workspace with defaults and check that it
+ * compiles, so a new user starts from a known-good project
+ */
+async function init(name, options = {}) {
+  const type = options.type || "image";
+  await newOwnable(name, {
+    type,
+    description: options.description,
+    crateVersion: "1.0.0",
+    dir: options.dir,
+    force: options.force,
+  });
+
+  const projectDir = path.resolve(options.dir || name);
+  const missing = [];
+  for (const file of WORKSPACE_FILES) {
+    if (!(await fs.pathExists(path.join(projectDir, file)))) missing.push(file);
+  }
+  if (missing.length > 0) {
+    throw new Error(`Generated workspace is incomplete, missing: ${missing.join(", ")}`);
+  }
+
+  if (options.check === false) return projectDir;
+  if (!shell.which("cargo")) {
+    console.log(chalk.yellow("Cargo is not installed; skipping cargo check"));
+    return projectDir;
+  }
+
+  const spinner = ora("Checking the workspace compiles...").start();
+  try {
+    for (const command of CHECK_COMMANDS) {
+      await execAsync(command, { cwd: projectDir });
+    }
+  } catch (error) {
+    spinner.fail("cargo check failed");
+    throw new Error((error.stderr || error.message).trim());
+  }
+  spinner.succeed("Workspace compiles");
+  return projectDir;
+}
+
+module.exports = { init, CHECK_COMMANDS };
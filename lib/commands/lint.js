@@ -0,0 +1,69 @@
+const chalk = require("chalk");
+const fs = require("fs-extra");
+const path = require("path");
+const { listRustFiles, findFunctions } = require("../utils/rustSource");
+
+// Operations that should not happen without looking at who sent the message
+const AUTH_SENSITIVE = /\.(save|update|remove)\(|\.owner\s*=/;
+// Operations that usually need the block time or height
+const TIME_SENSITIVE = /\b(Timestamp|block|height|minted_at|expires|until)\b/;
+
+const RULES = {
+  "ignored-info": {
+    test: (fn) => fn.params.includes("_info") && AUTH_SENSITIVE.test(fn.body),
+    message: (fn) =>
+      `\`${fn.name}\` ignores \`_info\` but writes state; the sender is never checked`,
+    fix: "rename `_info` to `info` and verify `info.sender` before writing",
+  },
+  "ignored-env": {
+    test: (fn) => fn.params.includes("_env") && TIME_SENSITIVE.test(fn.body),
+    message: (fn) =>
+      `\`${fn.name}\` ignores \`_env\` but works with time or height values`,
+    fix: "rename `_env` to `env` and use `env.block` instead of caller input",
+  },
+};
+
+/**
+ * Lint a single Rust source, returning findings with line numbers
+ */
+function lintSource(source, file) {
+  const findings = [];
+  for (const fn of findFunctions(source)) {
+    for (const [rule, { test, message, fix }] of Object.entries(RULES)) {
+      if (test(fn)) {
+        findings.push({ file, line: fn.line, rule, message: message(fn), fix });
+      }
+    }
+  }
+  return findings;
+}
+
+async function lint(dir = ".") {
+  const projectDir = path.resolve(dir);
+  const files = await listRustFiles(path.join(projectDir, "src"));
+  if (files.length === 0) {
+    throw new Error(`No Rust sources found in ${path.join(projectDir, "src")}`);
+  }
+
+  const findings = [];
+  for (const file of files) {
+    const source = await fs.readFile(file, "utf8");
+    findings.push(...lintSource(source, path.relative(projectDir, file)));
+  }
+
+  for (const finding of findings) {
+    console.log(
+      `${chalk.yellow(`${finding.file}:${finding.line}`)} ${finding.message} ${chalk.gray(
+        `[${finding.rule}]`
+      )}`
+    );
+    console.log(`  ${chalk.cyan("fix:")} ${finding.fix}`);
+  }
+
+  if (findings.length === 0) {
+    console.log(chalk.green("✓ No lint findings"));
+  }
+  return findings;
+}
+
+module.exports = { lint, lintSource, RULES };
@@ -0,0 +1,141 @@
+const fs = require("fs-extra");
+const path = require("path");
+
+// Written by `ownables schema` from the contract's `ExecuteMsg`
+const EXECUTE_SCHEMA = path.join("schema", "execute_msg.json");
+
+function snakeCase(name) {
+  return name.replace(/-/g, "_");
+}
+
+function kebabCase(name) {
+  return name.replace(/_/g, "-");
+}
+
+function resolve(node, definitions) {
+  while (node && node.$ref) {
+    node = definitions[node.$ref.replace(/^#\/definitions\//, "")];
+  }
+  return node || {};
+}
+
+/**
+ * Variants of serde's externally tagged `ExecuteMsg`, by snake_case tag
+ */
+function executeVariants(schema) {
+  const variants = new Map();
+  for (const member of schema.oneOf || schema.anyOf || []) {
+    if (member.type === "string" && member.enum) {
+      member.enum.forEach((tag) => variants.set(tag, null));
+    } else if (member.required && member.required.length === 1) {
+      const tag = member.required[0];
+      variants.set(tag, resolve(member.properties[tag], schema.definitions || {}));
+    }
+  }
+  return variants;
+}
+
+function usage(tag, payload) {
+  const required = new Set((payload && payload.required) || []);
+  const fields = Object.keys((payload && payload.properties) || {}).map((name) => {
+    const flag = `--${kebabCase(name)} <${name}>`;
+    return required.has(name) ? flag : `[${flag}]`;
+  });
+  return `Usage: ownables msg ${kebabCase(tag)} ${fields.join(" ")}`.trimEnd();
+}
+
+// Nullable fields are `anyOf: [T, null]` or `type: [T, "null"]`
+function valueType(node, definitions) {
+  node = resolve(node, definitions);
+  const members = node.anyOf || node.oneOf;
+  if (members) {
+    const present = members.map((member) => resolve(member, definitions))
+      .filter((member) => member.type !== "null");
+    return present.length === 1 ? valueType(present[0], definitions) : "json";
+  }
+  const types = [].concat(node.type || []).filter((type) => type !== "null");
+  return types.length === 1 ? types[0] : "json";
+}
+
+function parseValue(raw, type, flag) {
+  switch (type) {
+    case "string":
+      return raw;
+    case "integer":
+    case "number": {
+      const value = Number(raw);
+      if (raw === "" || Number.isNaN(value)) {
+        throw new Error(`${flag} must be a number, got '${raw}'`);
+      }
+      return value;
+    }
+    case "boolean":
+      if (raw !== "true" && raw !== "false") {
+        throw new Error(`${flag} must be true or false, got '${raw}'`);
+      }
+      return raw === "true";
+    default:
+      try {
+        return JSON.parse(raw);
+      } catch (error) {
+        throw new Error(`${flag} must be JSON: ${error.message}`);
+      }
+  }
+}
+
+/**
+ * Build the `ExecuteMsg` JSON for `variant` from `--field value` arguments,
+ * typed and checked against the contract's execute schema
+ */
+function buildMessage(schema, variant, args = []) {
+  const variants = executeVariants(schema);
+  const tag = snakeCase(variant);
+  if (!variants.has(tag)) {
+    const known = [...variants.keys()].map(kebabCase).join(", ");
+    throw new Error(`Unknown message '${variant}'. Known messages: ${known}`);
+  }
+
+  const payload = variants.get(tag);
+  if (payload === null) {
+    if (args.length > 0) throw new Error(`${usage(tag, null)}\n'${tag}' takes no fields`);
+    return JSON.stringify(tag);
+  }
+
+  const definitions = schema.definitions || {};
+  const properties = payload.properties || {};
+  const fields = {};
+  for (let i = 0; i < args.length; i += 2) {
+    const flag = args[i];
+    const name = flag.startsWith("--") ? snakeCase(flag.slice(2)) : null;
+    if (!name || !Object.prototype.hasOwnProperty.call(properties, name)) {
+      throw new Error(`${usage(tag, payload)}\nUnknown field '${flag}'`);
+    }
+    if (args[i + 1] === undefined) {
+      throw new Error(`${usage(tag, payload)}\nMissing value for ${flag}`);
+    }
+    fields[name] = parseValue(args[i + 1], valueType(properties[name], definitions), flag);
+  }
+
+  const missing = (payload.required || []).filter((name) => fields[name] === undefined);
+  if (missing.length > 0) {
+    const flags = missing.map((name) => `--${kebabCase(name)}`).join(", ");
+    throw new Error(`${usage(tag, payload)}\nMissing required ${flags}`);
+  }
+
+  return JSON.stringify({ [tag]: fields });
+}
+
+/**
+ * Print a ready-to-broadcast execute message for the ownable in `dir`
+ */
+async function msg(variant, args, options = {}) {
+  const schemaFile = path.join(path.resolve(options.dir || "."), EXECUTE_SCHEMA);
+  if (!(await fs.pathExists(schemaFile))) {
+    throw new Error(`No execute schema found at ${schemaFile}. Run 'ownables schema' first.`);
+  }
+  const message = buildMessage(await fs.readJson(schemaFile), variant, args);
+  console.log(message);
+  return message;
+}
+
+module.exports = { msg, buildMessage };
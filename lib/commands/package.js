@@ -0,0 +1,157 @@
+const chalk = require("chalk");
+const fs = require("fs-extra");
+const path = require("path");
+const JSZip = require("jszip");
+const { computeCid } = require("../utils/cid");
+const { hashFile, DEFAULT_IPFS_API } = require("../utils/ipfs");
+const { schema } = require("./schema");
+
+// Bundled message schema, recorded as `schema` in the package.json
+const SCHEMA_FILE = "schema.json";
+
+// Fixed entry timestamp so identical inputs give a byte-identical archive.
+// Zip stores local time, so build it from local components.
+const ZIP_DATE = new Date(1980, 0, 1);
+
+async function listFiles(dir, prefix = "") {
+  const files = [];
+  const entries = await fs.readdir(dir, { withFileTypes: true });
+  for (const entry of entries) {
+    const fullPath = path.join(dir, entry.name);
+    const relativePath = prefix ? `${prefix}/${entry.name}` : entry.name;
+    if (entry.name === ".DS_Store") continue;
+    if (entry.isDirectory()) {
+      files.push(...(await listFiles(fullPath, relativePath)));
+    } else {
+      files.push({ path: relativePath, source: fullPath });
+    }
+  }
+  return files;
+}
+
+/**
+ * Collect the package contents from a previous `build`: the wasm module and
+ * bindings, and the rendered package in build/package with its widget
+ * assets, metadata and chain.json
+ */
+async function collectPackageFiles(projectDir) {
+  const buildDir = path.join(projectDir, "build");
+  const built = (await fs.pathExists(buildDir)) ? await fs.readdir(buildDir) : [];
+  const wasmFile = built.find((file) => file.endsWith("_bg.wasm"));
+  if (!wasmFile) {
+    throw new Error(
+      `No built WebAssembly module in ${buildDir}. Run 'ownables-cli build' first.`
+    );
+  }
+  const jsFile = wasmFile.replace(/_bg\.wasm$/, ".js");
+  if (!built.includes(jsFile)) {
+    throw new Error(`No JavaScript bindings ${jsFile} in ${buildDir}`);
+  }
+
+  // the assets in the project still hold the template placeholders; build
+  // renders them into build/package
+  const packageDir = path.join(buildDir, "package");
+  if (!(await fs.pathExists(path.join(packageDir, "chain.json")))) {
+    throw new Error(
+      `No rendered package with a chain.json in ${packageDir}. Run 'ownables-cli build' first.`
+    );
+  }
+
+  return [
+    { path: "ownable_bg.wasm", source: path.join(buildDir, wasmFile) },
+    { path: "ownable.js", source: path.join(buildDir, jsFile) },
+    ...(await listFiles(packageDir)),
+  ];
+}
+
+/**
+ * Generate the message schema and add it to the files as a single
+ * schema.json, keyed by schema name, referenced from the package.json
+ */
+async function withSchema(projectDir, files) {
+  const bundled = {};
+  for (const file of await schema(projectDir)) {
+    bundled[path.basename(file, ".json")] = JSON.parse(await fs.readFile(file, "utf8"));
+  }
+
+  return [
+    ...files.map((file) => {
+      if (file.path !== "package.json") return file;
+      return {
+        path: file.path,
+        content: async () => {
+          const manifest = JSON.parse(await fs.readFile(file.source, "utf8"));
+          return JSON.stringify({ ...manifest, schema: SCHEMA_FILE }, null, 2);
+        },
+      };
+    }),
+    { path: SCHEMA_FILE, content: async () => JSON.stringify(bundled, null, 2) },
+  ];
+}
+
+/**
+ * Zip the files deterministically: sorted entries, fixed timestamps and
+ * permissions, fixed compression
+ */
+async function createArchive(files) {
+  const zip = new JSZip();
+  const sorted = [...files].sort((a, b) => (a.path < b.path ? -1 : 1));
+  for (const file of sorted) {
+    const content = file.content ? await file.content() : await fs.readFile(file.source);
+    zip.file(file.path, content, {
+      date: ZIP_DATE,
+      createFolders: false,
+      unixPermissions: 0o644,
+    });
+  }
+  return zip.generateAsync({
+    type: "nodebuffer",
+    compression: "DEFLATE",
+    compressionOptions: { level: 9 },
+    platform: "UNIX",
+  });
+}
+
+/**
+ * Check the IPFS node would give the archive the CID computed locally, so
+ * the InstantiateMsg doesn't carry a CID the network won't reproduce
+ */
+async function verifyCid(archive, cid, apiUrl) {
+  let nodeCid;
+  try {
+    nodeCid = await hashFile(archive, { apiUrl });
+  } catch (error) {
+    throw new Error(`${error.message}. Use --no-verify to package offline.`);
+  }
+  if (nodeCid !== cid) {
+    throw new Error(
+      `Package CID ${cid} does not match ${nodeCid} computed by the IPFS node at ${apiUrl}`
+    );
+  }
+}
+
+/**
+ * Bundle a built ownable and compute the CID recorded as its package
+ */
+async function packageOwnable(dir = ".", options = {}) {
+  const projectDir = path.resolve(dir);
+  let files = await collectPackageFiles(projectDir);
+  if (options.includeSchema) files = await withSchema(projectDir, files);
+  const archive = await createArchive(files);
+  const cid = computeCid(archive);
+  if (options.verify !== false) {
+    await verifyCid(archive, cid, options.api || DEFAULT_IPFS_API);
+  }
+
+  const output = path.resolve(
+    options.output || path.join(projectDir, `${path.basename(projectDir)}-package.zip`)
+  );
+  await fs.writeFile(output, archive);
+
+  console.log(chalk.green(`✓ Packaged ${files.length} files`));
+  console.log(`Archive: ${output}`);
+  console.log(`CID: ${cid}`);
+  return { output, cid };
+}
+
+module.exports = { packageOwnable, collectPackageFiles, createArchive };
@@ -0,0 +1,49 @@
+const chalk = require("chalk");
+const fs = require("fs-extra");
+const path = require("path");
+const ora = require("ora");
+const { readMetadataFile } = require("../utils/metadata");
+const { addDirectory, DEFAULT_IPFS_API } = require("../utils/ipfs");
+
+async function pinMetadata(dir, options = {}) {
+  const metadataDir = path.resolve(dir);
+  if (!(await fs.pathExists(metadataDir))) {
+    throw new Error(`Directory not found: ${metadataDir}`);
+  }
+
+  const fileNames = (await fs.readdir(metadataDir))
+    .filter((file) => file.toLowerCase().endsWith(".json"))
+    .sort();
+  if (fileNames.length === 0) {
+    throw new Error(`No metadata JSON files found in ${metadataDir}`);
+  }
+
+  // Validate everything before anything is uploaded
+  const files = [];
+  for (const fileName of fileNames) {
+    const filePath = path.join(metadataDir, fileName);
+    await readMetadataFile(filePath);
+    files.push({ path: fileName, content: await fs.readFile(filePath) });
+  }
+  console.log(chalk.green(`✓ ${files.length} metadata files validated`));
+
+  const apiUrl = options.api || DEFAULT_IPFS_API;
+  const spinner = ora(`Pinning metadata to ${apiUrl}...`).start();
+  let result;
+  try {
+    result = await addDirectory(files, { apiUrl });
+  } catch (error) {
+    spinner.fail("Pinning failed");
+    throw error;
+  }
+  spinner.succeed("Metadata pinned");
+
+  console.log(`\nRoot CID: ${chalk.cyan(result.root)}`);
+  result.files.forEach((file) =>
+    console.log(`  ipfs://${result.root}/${file.path}`)
+  );
+
+  return result;
+}
+
+module.exports = { pinMetadata };
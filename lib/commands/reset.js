@@ -0,0 +1,73 @@
+const chalk = require("chalk");
+const fs = require("fs-extra");
+const path = require("path");
+const {
+  TEMPLATES,
+  getTemplate,
+  resolveTemplateVersion,
+  templateDirs,
+  unregisterTemplate,
+} = require("../utils/templates");
+const { openTemplateSource } = require("../utils/templateSource");
+const {
+  readManifest,
+  registerCustomTemplate,
+  renderTemplateFiles,
+  installReadme,
+} = require("./create");
+
+/**
+ * Regenerate a project's sources from its template and recorded values,
+ * leaving everything under assets/ untouched. A custom template is read
+ * again from the path or repository recorded at scaffold time.
+ */
+async function reset(dir) {
+  const projectDir = path.resolve(dir);
+  const { template, templateVersion, templateSource, metadata } = await readManifest(projectDir);
+  // regenerate only from the template version the project was created with
+  resolveTemplateVersion(templateVersion);
+
+  if (!templateSource) {
+    if (!TEMPLATES.some((t) => t.name === template)) {
+      throw new Error(
+        `Template '${template}' is not built in and the manifest doesn't record its source. Re-create the project with --template-path or --template-repo.`
+      );
+    }
+    await regenerate(projectDir, template, metadata);
+    return;
+  }
+
+  const source = await openTemplateSource(templateSource);
+  try {
+    await registerCustomTemplate(source, templateSource.type, template);
+    await regenerate(projectDir, template, metadata);
+  } finally {
+    unregisterTemplate(template);
+    await source.cleanup();
+  }
+}
+
+async function regenerate(projectDir, template, metadata) {
+  for (const sourceDir of templateDirs(getTemplate(template))) {
+    const entries = await fs.readdir(sourceDir);
+    for (const entry of entries.filter((name) => name !== "assets")) {
+      await fs.copy(path.join(sourceDir, entry), path.join(projectDir, entry), {
+        overwrite: true,
+      });
+    }
+  }
+
+  await installReadme(projectDir);
+  await renderTemplateFiles(
+    projectDir,
+    metadata,
+    template,
+    (file) => !file.startsWith("assets/")
+  );
+
+  console.log(
+    chalk.green(`✓ Regenerated ${metadata.name} from ${template}, assets preserved`)
+  );
+}
+
+module.exports = { reset };
@@ -0,0 +1,61 @@
+const chalk = require("chalk");
+const shell = require("shelljs");
+const fs = require("fs-extra");
+const path = require("path");
+const ora = require("ora");
+const { execAsync } = require("../utils/execAsync");
+
+// Entrypoint every template declares as `cargo schema` in .cargo/config
+const SCHEMA_EXAMPLE = path.join("examples", "schema.rs");
+
+/**
+ * Run the crate's schema example and return the generated files
+ */
+async function schema(dir = ".") {
+  const projectDir = path.resolve(dir);
+  if (!(await fs.pathExists(path.join(projectDir, "Cargo.toml")))) {
+    throw new Error(`No Cargo.toml found in ${projectDir}`);
+  }
+  if (!(await fs.pathExists(path.join(projectDir, SCHEMA_EXAMPLE)))) {
+    throw new Error(
+      `No schema entrypoint found: add ${SCHEMA_EXAMPLE} exporting the message schemas`
+    );
+  }
+  if (!shell.which("cargo")) {
+    throw new Error("Cargo is not installed. Please install Rust and Cargo first.");
+  }
+
+  const spinner = ora("Generating schema files...").start();
+  try {
+    const { stderr } = await execAsync("cargo run --example schema", {
+      cwd: projectDir,
+    });
+    if (stderr) console.error(chalk.yellow(stderr));
+  } catch (error) {
+    spinner.fail("Schema generation failed");
+    throw error;
+  }
+
+  const schemaDir = path.join(projectDir, "schema");
+  const files = (await fs.pathExists(schemaDir))
+    ? (await fs.readdir(schemaDir)).filter((file) => file.endsWith(".json")).sort()
+    : [];
+  if (files.length === 0) {
+    spinner.fail("Schema generation failed");
+    throw new Error(`No schema files were written to ${schemaDir}`);
+  }
+  for (const file of files) {
+    try {
+      JSON.parse(await fs.readFile(path.join(schemaDir, file), "utf8"));
+    } catch (error) {
+      spinner.fail("Schema generation failed");
+      throw new Error(`Invalid schema file ${file}: ${error.message}`);
+    }
+  }
+
+  spinner.succeed(`Generated ${files.length} schema files`);
+  files.forEach((file) => console.log(`  ${path.join("schema", file)}`));
+  return files.map((file) => path.join(schemaDir, file));
+}
+
+module.exports = { schema };
@@ -0,0 +1,120 @@
+const chalk = require("chalk");
+const shell = require("shelljs");
+const fs = require("fs-extra");
+const path = require("path");
+const ora = require("ora");
+const { execAsync } = require("../utils/execAsync");
+
+// Entrypoints every template declares as `cargo simulate` and
+// `cargo simulate-query`
+const SIMULATE_EXAMPLE = path.join("examples", "simulate.rs");
+const SIMULATE_QUERY_EXAMPLE = path.join("examples", "simulate_query.rs");
+
+async function checkSimulation(projectDir, example) {
+  if (!(await fs.pathExists(path.join(projectDir, "Cargo.toml")))) {
+    throw new Error(`No Cargo.toml found in ${projectDir}`);
+  }
+  if (!(await fs.pathExists(path.join(projectDir, example)))) {
+    throw new Error(
+      `No simulation found: add ${example}, or reset the project to the latest template`
+    );
+  }
+  if (!shell.which("cargo")) {
+    throw new Error("Cargo is not installed. Please install Rust and Cargo first.");
+  }
+}
+
+/**
+ * Run the crate's simulation example, which instantiates, transfers and
+ * locks the ownable in a cw-multi-test app, and print its report
+ */
+async function simulate(dir = ".") {
+  const projectDir = path.resolve(dir);
+  await checkSimulation(projectDir, SIMULATE_EXAMPLE);
+
+  const spinner = ora("Simulating instantiate, transfer and lock...").start();
+  let stdout;
+  try {
+    ({ stdout } = await execAsync("cargo run --quiet --example simulate", {
+      cwd: projectDir,
+    }));
+  } catch (error) {
+    spinner.fail("Simulation failed");
+    if (error.stdout) console.log(error.stdout.trim());
+    throw new Error((error.stderr || error.message).trim());
+  }
+
+  spinner.succeed("Simulation completed");
+  console.log(stdout.trim());
+  console.log(chalk.green("✓ Transfer and lock succeeded"));
+  return stdout;
+}
+
+/**
+ * First line where `actual` differs from `expected`, for the golden file
+ * mismatch message
+ */
+function firstDifference(expected, actual) {
+  const expectedLines = expected.split("\n");
+  const actualLines = actual.split("\n");
+  const line = expectedLines.findIndex((text, index) => text !== actualLines[index]);
+  const index = line === -1 ? expectedLines.length : line;
+  return `line ${index + 1}:\n- ${expectedLines[index] || ""}\n+ ${actualLines[index] || ""}`;
+}
+
+/**
+ * Run queries against a freshly instantiated ownable in a cw-multi-test app
+ * and print the JSON responses. With `golden`, the output is written to the
+ * file on the first run and compared against it afterwards.
+ */
+async function simulateQuery(dir = ".", options = {}) {
+  const projectDir = path.resolve(dir);
+  await checkSimulation(projectDir, SIMULATE_QUERY_EXAMPLE);
+
+  const env = { ...process.env };
+  if (options.msg) {
+    let queries;
+    try {
+      queries = [].concat(JSON.parse(options.msg));
+    } catch (error) {
+      throw new Error(`Invalid query JSON: ${error.message}`);
+    }
+    env.SIMULATE_QUERIES = JSON.stringify(queries);
+  }
+
+  const spinner = ora("Simulating queries...").start();
+  let stdout;
+  try {
+    ({ stdout } = await execAsync("cargo run --quiet --example simulate_query", {
+      cwd: projectDir,
+      env,
+    }));
+  } catch (error) {
+    spinner.fail("Simulation failed");
+    throw new Error((error.stderr || error.message).trim());
+  }
+  spinner.succeed("Simulation completed");
+  const output = JSON.stringify(JSON.parse(stdout), null, 2) + "\n";
+
+  if (options.golden) {
+    const golden = path.resolve(options.golden);
+    if (options.updateGolden || !(await fs.pathExists(golden))) {
+      await fs.outputFile(golden, output);
+      console.log(chalk.green(`✓ Wrote golden file ${golden}`));
+      return output;
+    }
+    const expected = await fs.readFile(golden, "utf8");
+    if (expected !== output) {
+      throw new Error(
+        `Output differs from golden file ${golden} at ${firstDifference(expected, output)}\nRun with --update-golden to accept it.`
+      );
+    }
+    console.log(chalk.green(`✓ Output matches golden file ${golden}`));
+    return output;
+  }
+
+  console.log(output.trim());
+  return output;
+}
+
+module.exports = { simulate, simulateQuery };
@@ -0,0 +1,24 @@
+const chalk = require("chalk");
+const path = require("path");
+const {
+  findPlaceholders,
+  formatPlaceholders,
+} = require("../utils/placeholders");
+
+/**
+ * Report template placeholders left in a generated project
+ */
+async function validate(dir) {
+  const projectDir = path.resolve(dir);
+  const found = await findPlaceholders(projectDir);
+
+  if (found.length === 0) {
+    console.log(chalk.green("✓ No unsubstituted placeholders"));
+  } else {
+    console.log(chalk.red(`Unsubstituted placeholders in ${projectDir}:`));
+    console.log(formatPlaceholders(found));
+  }
+  return found;
+}
+
+module.exports = { validate };
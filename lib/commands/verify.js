@@ -0,0 +1,54 @@
+const chalk = require("chalk");
+const fs = require("fs-extra");
+const path = require("path");
+const { collectPackageFiles, createArchive } = require("./package");
+const { computeCid } = require("../utils/cid");
+const {
+  findPlaceholders,
+  findPackagePlaceholders,
+  formatPlaceholders,
+} = require("../utils/placeholders");
+
+/**
+ * Rebuild the package archive for a local ownable and check its CID against
+ * the one recorded on chain, and that its sources were fully rendered
+ */
+async function verify(options = {}) {
+  if (!options.package) {
+    throw new Error("Missing package: pass --package <path>");
+  }
+  if (!options.expectedCid) {
+    throw new Error("Missing expected CID: pass --expected-cid <cid>");
+  }
+
+  const projectDir = path.resolve(options.package);
+  if (!(await fs.pathExists(projectDir))) {
+    throw new Error(`Package ${projectDir} not found`);
+  }
+
+  const files = await collectPackageFiles(projectDir);
+  const cid = computeCid(await createArchive(files));
+  const matches = cid === options.expectedCid;
+  const placeholders = [
+    ...(await findPlaceholders(projectDir)),
+    ...(await findPackagePlaceholders(files)),
+  ];
+
+  if (matches) {
+    console.log(chalk.green(`✓ CID matches: ${cid}`));
+  } else {
+    console.log(chalk.red("✗ CID mismatch"));
+    console.log(`Expected: ${options.expectedCid}`);
+    console.log(`Actual:   ${cid}`);
+  }
+  if (placeholders.length === 0) {
+    console.log(chalk.green("✓ No unsubstituted placeholders"));
+  } else {
+    console.log(chalk.red(`Unsubstituted placeholders in ${projectDir}:`));
+    console.log(formatPlaceholders(placeholders));
+  }
+
+  return { cid, matches, placeholders };
+}
+
+module.exports = { verify };
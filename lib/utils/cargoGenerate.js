@@ -0,0 +1,76 @@
+// cargo-generate variables and the PLACEHOLDER tokens they stand in for.
+// `project-name`, `crate_name` and `authors` are built into cargo-generate;
+// the rest are declared in the exported cargo-generate.toml.
+const VARIABLES = [
+  { token: /PLACEHOLDER1_NAME/g, variable: '"{{project-name}}"' },
+  { token: /PLACEHOLDER1_DESCRIPTION/g, variable: '"{{description}}"' },
+  { token: /PLACEHOLDER1_VERSION/g, variable: '"{{version}}"' },
+  { token: /PLACEHOLDER1_AUTHORS/g, variable: '"{{authors}}"' },
+  { token: /PLACEHOLDER1_KEYWORDS/g, variable: '"{{keywords}}"' },
+  { token: /PLACEHOLDER4_CONTRACT_NAME/g, variable: '"{{project-name}}"' },
+  { token: /PLACEHOLDER4_DESCRIPTION/g, variable: '"{{description}}"' },
+  { token: /PLACEHOLDER4_NAME/g, variable: '"{{project-name}}"' },
+  { token: /PLACEHOLDER3_MSG/g, variable: "{{crate_name}}" },
+  { token: /PLACEHOLDER3_STATE/g, variable: "{{crate_name}}" },
+  { token: /PLACEHOLDER3_CONTRACT/g, variable: "{{crate_name}}" },
+  { token: /PLACEHOLDER2_TITLE/g, variable: "{{project-name}}" },
+  { token: /PLACEHOLDER2_DESCRIPTION/g, variable: "{{description}}" },
+];
+
+const CARGO_GENERATE_TOML = `[template]
+cargo_generate_version = ">=0.10.0"
+
+[placeholders.description]
+type = "string"
+prompt = "Describe your Ownable"
+
+[placeholders.version]
+type = "string"
+prompt = "Version (e.g., 1.0.0)"
+default = "1.0.0"
+regex = "^\\\\d+\\\\.\\\\d+\\\\.\\\\d+$"
+
+[placeholders.keywords]
+type = "string"
+prompt = "Keyword"
+`;
+
+/**
+ * Rewrite PLACEHOLDER tokens as cargo-generate variables. Tokens fixed by
+ * the template itself (e.g. the ownable type) are resolved to `fixed`.
+ */
+function toCargoGenerate(content, fixed = {}) {
+  let result = content;
+  for (const { token, variable } of VARIABLES) {
+    result = result.replace(token, variable);
+  }
+  for (const [token, value] of Object.entries(fixed)) {
+    result = result.split(token).join(value);
+  }
+  return result;
+}
+
+/**
+ * Substitute cargo-generate variables, e.g. `{{project-name}}`, with
+ * scaffold metadata
+ */
+function fromCargoGenerate(content, metadata) {
+  const values = {
+    "project-name": metadata.name,
+    crate_name: metadata.name.replace(/-/g, "_"),
+    description: metadata.description,
+    version: metadata.version,
+    authors: metadata.authors,
+    keywords: (metadata.keywords || []).map((k) => k.trim()).join(", "),
+  };
+
+  return content.replace(/\{\{\s*([\w-]+)\s*\}\}/g, (match, name) =>
+    name in values ? values[name] : match
+  );
+}
+
+module.exports = {
+  CARGO_GENERATE_TOML,
+  toCargoGenerate,
+  fromCargoGenerate,
+};
@@ -0,0 +1,114 @@
+const crypto = require("crypto");
+
+const CID_VERSION = 0x01;
+const RAW_CODEC = 0x55;
+const DAG_PB_CODEC = 0x70;
+const SHA2_256 = 0x12;
+
+// `ipfs add --cid-version=1` defaults: fixed-size chunker, raw leaves and a
+// balanced DAG of up to 174 links per node
+const CHUNK_SIZE = 256 * 1024;
+const MAX_LINKS = 174;
+const UNIXFS_FILE = 2;
+
+const BASE32_ALPHABET = "abcdefghijklmnopqrstuvwxyz234567";
+
+// RFC 4648 base32, lowercase and unpadded as used by multibase `b`
+function base32(bytes) {
+  let output = "";
+  let buffer = 0;
+  let bits = 0;
+  for (const byte of bytes) {
+    buffer = (buffer << 8) | byte;
+    bits += 8;
+    while (bits >= 5) {
+      output += BASE32_ALPHABET[(buffer >>> (bits - 5)) & 31];
+      bits -= 5;
+    }
+  }
+  if (bits > 0) {
+    output += BASE32_ALPHABET[(buffer << (5 - bits)) & 31];
+  }
+  return output;
+}
+
+function varint(value) {
+  const bytes = [];
+  while (value >= 0x80) {
+    bytes.push((value % 0x80) | 0x80);
+    value = Math.floor(value / 0x80);
+  }
+  bytes.push(value);
+  return Buffer.from(bytes);
+}
+
+// protobuf field key, length-delimited or varint
+function field(number, value) {
+  if (Buffer.isBuffer(value)) {
+    return Buffer.concat([varint((number << 3) | 2), varint(value.length), value]);
+  }
+  return Buffer.concat([varint(number << 3), varint(value)]);
+}
+
+function cidBytes(codec, block) {
+  const digest = crypto.createHash("sha256").update(block).digest();
+  return Buffer.concat([
+    Buffer.from([CID_VERSION, codec, SHA2_256, digest.length]),
+    digest,
+  ]);
+}
+
+/**
+ * dag-pb node over `children`, with UnixFS file data recording the content
+ * size under each child. Links are encoded before data, as in go-ipfs.
+ */
+function fileNode(children) {
+  const contentSize = children.reduce((sum, child) => sum + child.contentSize, 0);
+  const unixfs = Buffer.concat([
+    field(1, UNIXFS_FILE),
+    field(3, contentSize),
+    ...children.map((child) => field(4, child.contentSize)),
+  ]);
+  const block = Buffer.concat([
+    ...children.map((child) =>
+      field(2, Buffer.concat([
+        field(1, child.cid),
+        field(2, Buffer.alloc(0)),
+        field(3, child.treeSize),
+      ]))
+    ),
+    field(1, unixfs),
+  ]);
+  return {
+    cid: cidBytes(DAG_PB_CODEC, block),
+    contentSize,
+    treeSize: block.length + children.reduce((sum, child) => sum + child.treeSize, 0),
+  };
+}
+
+/**
+ * CIDv1 of `content` as `ipfs add --cid-version=1` imports it: a single raw
+ * block when it fits in one chunk, otherwise a balanced UnixFS DAG of raw
+ * leaves. Base32, sha2-256.
+ */
+function computeCid(content) {
+  let nodes = [];
+  for (let offset = 0; offset < content.length || nodes.length === 0; offset += CHUNK_SIZE) {
+    const chunk = content.subarray(offset, offset + CHUNK_SIZE);
+    nodes.push({
+      cid: cidBytes(RAW_CODEC, chunk),
+      contentSize: chunk.length,
+      treeSize: chunk.length,
+    });
+  }
+  while (nodes.length > 1) {
+    const parents = [];
+    for (let index = 0; index < nodes.length; index += MAX_LINKS) {
+      parents.push(fileNode(nodes.slice(index, index + MAX_LINKS)));
+    }
+    nodes = parents;
+  }
+  return `b${base32(nodes[0].cid)}`;
+}
+
+module.exports = { computeCid, CHUNK_SIZE, MAX_LINKS };
@@ -0,0 +1,131 @@
+const http = require("http");
+const https = require("https");
+const crypto = require("crypto");
+
+const DEFAULT_IPFS_API = process.env.IPFS_API_URL || "http://127.0.0.1:5001";
+
+/**
+ * Build a multipart/form-data body for the IPFS `add` endpoint
+ */
+function buildMultipart(files) {
+  const boundary = `----ownables-${crypto.randomBytes(12).toString("hex")}`;
+  const parts = [];
+
+  for (const file of files) {
+    parts.push(
+      Buffer.from(
+        `--${boundary}\r\n` +
+          `Content-Disposition: form-data; name="file"; filename="${encodeURIComponent(
+            file.path
+          )}"\r\n` +
+          "Content-Type: application/octet-stream\r\n\r\n"
+      ),
+      Buffer.isBuffer(file.content) ? file.content : Buffer.from(file.content),
+      Buffer.from("\r\n")
+    );
+  }
+  parts.push(Buffer.from(`--${boundary}--\r\n`));
+
+  return { boundary, body: Buffer.concat(parts) };
+}
+
+/**
+ * POST to an IPFS HTTP API endpoint and collect the response body
+ */
+function request(apiUrl, endpoint, query, headers, body) {
+  const url = new URL(`/api/v0/${endpoint}`, apiUrl);
+  Object.entries(query).forEach(([key, value]) =>
+    url.searchParams.set(key, String(value))
+  );
+  const client = url.protocol === "https:" ? https : http;
+
+  return new Promise((resolve, reject) => {
+    const req = client.request(
+      url,
+      { method: "POST", headers: { ...headers, "Content-Length": body.length } },
+      (res) => {
+        const chunks = [];
+        res.on("data", (chunk) => chunks.push(chunk));
+        res.on("end", () => {
+          const text = Buffer.concat(chunks).toString("utf8");
+          if (res.statusCode !== 200) {
+            reject(
+              new Error(`IPFS ${endpoint} failed (${res.statusCode}): ${text}`)
+            );
+          } else {
+            resolve(text);
+          }
+        });
+      }
+    );
+    req.on("error", (error) =>
+      reject(new Error(`Unable to reach IPFS node at ${apiUrl}: ${error.message}`))
+    );
+    req.end(body);
+  });
+}
+
+/**
+ * Add files to IPFS wrapped in a single directory
+ *
+ * Returns the root directory CID and the CID of each file.
+ */
+async function addDirectory(files, { apiUrl = DEFAULT_IPFS_API, pin = true } = {}) {
+  const { boundary, body } = buildMultipart(files);
+  const text = await request(
+    apiUrl,
+    "add",
+    { "wrap-with-directory": true, "cid-version": 1, pin },
+    { "Content-Type": `multipart/form-data; boundary=${boundary}` },
+    body
+  );
+
+  // The add endpoint streams one JSON object per line; the wrapping
+  // directory is reported last with an empty name.
+  const entries = text
+    .split("\n")
+    .filter((line) => line.trim() !== "")
+    .map((line) => JSON.parse(line));
+  const root = entries.find((entry) => entry.Name === "");
+  if (!root) {
+    throw new Error("IPFS node did not return a directory CID");
+  }
+
+  return {
+    root: root.Hash,
+    files: entries
+      .filter((entry) => entry.Name !== "")
+      .map((entry) => ({ path: entry.Name, cid: entry.Hash })),
+  };
+}
+
+/**
+ * CID the node would give `content` on `ipfs add --cid-version=1`, without
+ * storing or pinning it
+ */
+async function hashFile(content, { apiUrl = DEFAULT_IPFS_API } = {}) {
+  const { boundary, body } = buildMultipart([{ path: "package.zip", content }]);
+  const text = await request(
+    apiUrl,
+    "add",
+    { "only-hash": true, "cid-version": 1, pin: false },
+    { "Content-Type": `multipart/form-data; boundary=${boundary}` },
+    body
+  );
+
+  const entry = text
+    .split("\n")
+    .filter((line) => line.trim() !== "")
+    .map((line) => JSON.parse(line))
+    .pop();
+  if (!entry || !entry.Hash) {
+    throw new Error("IPFS node did not return a CID");
+  }
+  return entry.Hash;
+}
+
+module.exports = {
+  DEFAULT_IPFS_API,
+  addDirectory,
+  hashFile,
+};
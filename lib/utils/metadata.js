@@ -0,0 +1,60 @@
+const fs = require("fs-extra");
+
+// Fields of the ownable `Metadata` struct (ownable-std), all optional strings
+const METADATA_FIELDS = [
+  "image",
+  "image_data",
+  "external_url",
+  "description",
+  "name",
+  "background_color",
+  "animation_url",
+  "youtube_url",
+];
+
+/**
+ * Validate an object against the ownable Metadata schema
+ */
+function validateMetadata(metadata, source = "metadata") {
+  if (
+    metadata === null ||
+    typeof metadata !== "object" ||
+    Array.isArray(metadata)
+  ) {
+    throw new Error(`${source}: metadata must be a JSON object`);
+  }
+
+  for (const [key, value] of Object.entries(metadata)) {
+    if (!METADATA_FIELDS.includes(key)) {
+      throw new Error(`${source}: unknown metadata field '${key}'`);
+    }
+    if (value !== null && typeof value !== "string") {
+      throw new Error(`${source}: field '${key}' must be a string or null`);
+    }
+  }
+
+  return metadata;
+}
+
+/**
+ * Read and validate a metadata JSON file
+ */
+async function readMetadataFile(filePath) {
+  let metadata;
+  try {
+    metadata = JSON.parse(await fs.readFile(filePath, "utf8"));
+  } catch (error) {
+    if (error.code === "ENOENT") {
+      throw new Error(`Metadata file not found: ${filePath}`);
+    }
+    throw new Error(`${filePath}: ${error.message}`);
+  }
+
+  return validateMetadata(metadata, filePath);
+}
+
+module.exports = {
+  METADATA_FIELDS,
+  validateMetadata,
+  readMetadataFile,
+};
@@ -0,0 +1,45 @@
+const fs = require("fs-extra");
+const toml = require("@iarna/toml");
+
+// Keys with a fixed meaning; every other key fills a `PLACEHOLDER4_<KEY>` token
+const KNOWN_KEYS = ["name", "description", "ownable_type"];
+const EXTRA_KEY = /^[a-z][a-z0-9_]*$/;
+
+/**
+ * Read scaffold values from an ownable.toml. `network` and any other extra
+ * keys are returned in `extra`.
+ */
+async function readOwnableToml(file) {
+  if (!(await fs.pathExists(file))) {
+    throw new Error(`Manifest ${file} not found`);
+  }
+  let data;
+  try {
+    data = toml.parse(await fs.readFile(file, "utf8"));
+  } catch (error) {
+    throw new Error(`Invalid manifest ${file}: ${error.message}`);
+  }
+
+  const extra = {};
+  for (const [key, value] of Object.entries(data)) {
+    if (!["string", "number", "boolean"].includes(typeof value)) {
+      throw new Error(`Manifest key \`${key}\` must be a string, number or boolean`);
+    }
+    if (KNOWN_KEYS.includes(key)) continue;
+    if (!EXTRA_KEY.test(key)) {
+      throw new Error(
+        `Manifest key \`${key}\` must be lowercase letters, numbers and underscores`
+      );
+    }
+    extra[key] = String(value);
+  }
+
+  return {
+    name: data.name,
+    description: data.description,
+    ownableType: data.ownable_type,
+    extra,
+  };
+}
+
+module.exports = { readOwnableToml };
@@ -9,6 +9,8 @@ const MIN_IMAGE_DIMENSIONS = { width: 300, height: 300 };
 const MAX_IMAGE_DIMENSIONS = { width: 4096, height: 4096 };
 const ALLOWED_AUDIO_FORMATS = [".mp3", ".wav", ".ogg"];
 const ALLOWED_IMAGE_FORMATS = [".jpg", ".jpeg", ".png", ".webp"];
+const MAX_DOCUMENT_SIZE = 50 * 1024 * 1024; // 50MB
+const ALLOWED_DOCUMENT_FORMATS = [".pdf"];
 
 /**
  * Validate image dimensions and format
@@ -295,6 +297,47 @@ async function handleMusicOwnable(projectPath, outputDir, metadata, spinner) {
   }
 }
 
+/**
+ * Copy the project's document; its cover image is handled like a static
+ * ownable's image
+ */
+async function handleDocumentOwnable(projectPath, outputDir, metadata, spinner) {
+  const projectName = metadata.name.toLowerCase().replace(/\s+/g, "-");
+  const documentsDir = path.join(projectPath, "assets/documents");
+  const documents = (await fs.pathExists(documentsDir))
+    ? await fs.readdir(documentsDir)
+    : [];
+  const documentFile = documents.find(
+    (file) =>
+      file.startsWith(projectName) &&
+      ALLOWED_DOCUMENT_FORMATS.includes(path.extname(file).toLowerCase())
+  );
+  if (!documentFile) {
+    throw new Error(
+      `No document found for project ${projectName} in assets/documents. ` +
+        `Supported formats: ${ALLOWED_DOCUMENT_FORMATS.join(", ")}`
+    );
+  }
+
+  const sourcePath = path.join(documentsDir, documentFile);
+  if (spinner) spinner.text = "Validating document...";
+  const { size } = await fs.stat(sourcePath);
+  if (size > MAX_DOCUMENT_SIZE) {
+    throw new Error(
+      `Document file too large. Maximum size: ${MAX_DOCUMENT_SIZE / (1024 * 1024)}MB`
+    );
+  }
+
+  const cover = await handleStaticOwnable(projectPath, outputDir, metadata, spinner);
+  await fs.copy(sourcePath, path.join(outputDir, "documents", documentFile));
+
+  return {
+    imageFile: cover.imageFile,
+    documentFile,
+    type: "document",
+  };
+}
+
 async function getOwnableType(projectPath) {
   const typePath = path.join(projectPath, "type.txt");
   if (!fs.existsSync(typePath)) {
@@ -306,5 +349,6 @@ async function getOwnableType(projectPath) {
 module.exports = {
   handleStaticOwnable,
   handleMusicOwnable,
+  handleDocumentOwnable,
   getOwnableType,
 };
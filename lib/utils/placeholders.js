@@ -0,0 +1,61 @@
+const fs = require("fs-extra");
+const path = require("path");
+const { listRustFiles, lineOf } = require("./rustSource");
+
+// Template tokens, e.g. PLACEHOLDER4_DESCRIPTION
+const PLACEHOLDER_PATTERN = /PLACEHOLDER\d*_[A-Z_]+/g;
+
+/**
+ * Find template tokens left in a generated project's Rust sources and
+ * Cargo.toml. Asset placeholders are filled in at build time and skipped.
+ */
+async function findPlaceholders(projectDir) {
+  const files = await listRustFiles(projectDir);
+  const cargoToml = path.join(projectDir, "Cargo.toml");
+  if (await fs.pathExists(cargoToml)) files.unshift(cargoToml);
+
+  const found = [];
+  for (const file of files) {
+    const source = await fs.readFile(file, "utf8");
+    for (const match of source.matchAll(PLACEHOLDER_PATTERN)) {
+      found.push({
+        file: path.relative(projectDir, file),
+        line: lineOf(source, match.index),
+        token: match[0],
+      });
+    }
+  }
+  return found;
+}
+
+// Packaged files `build` renders from templates
+const RENDERED_EXTENSIONS = [".html", ".js", ".json", ".css", ".svg"];
+
+/**
+ * Find template tokens left in the rendered files of a package, given as
+ * `{ path, source }` entries
+ */
+async function findPackagePlaceholders(files) {
+  const found = [];
+  for (const file of files) {
+    if (!RENDERED_EXTENSIONS.includes(path.extname(file.path))) continue;
+    const source = await fs.readFile(file.source, "utf8");
+    for (const match of source.matchAll(PLACEHOLDER_PATTERN)) {
+      found.push({ file: file.path, line: lineOf(source, match.index), token: match[0] });
+    }
+  }
+  return found;
+}
+
+function formatPlaceholders(found) {
+  return found
+    .map(({ file, line, token }) => `  ${file}:${line} ${token}`)
+    .join("\n");
+}
+
+module.exports = {
+  PLACEHOLDER_PATTERN,
+  findPlaceholders,
+  findPackagePlaceholders,
+  formatPlaceholders,
+};
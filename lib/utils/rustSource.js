@@ -0,0 +1,70 @@
+const fs = require("fs-extra");
+const path = require("path");
+
+/**
+ * Recursively list the `.rs` files below a directory
+ */
+async function listRustFiles(dir) {
+  const files = [];
+  if (!(await fs.pathExists(dir))) return files;
+
+  const entries = await fs.readdir(dir, { withFileTypes: true });
+  for (const entry of entries.sort((a, b) => a.name.localeCompare(b.name))) {
+    const fullPath = path.join(dir, entry.name);
+    if (entry.isDirectory()) {
+      if (entry.name === "target") continue;
+      files.push(...(await listRustFiles(fullPath)));
+    } else if (entry.name.endsWith(".rs")) {
+      files.push(fullPath);
+    }
+  }
+  return files;
+}
+
+function lineOf(source, index) {
+  return source.slice(0, index).split("\n").length;
+}
+
+/**
+ * Find function definitions with their parameters and body
+ *
+ * This is a lightweight scanner for template sources, not a Rust parser:
+ * braces inside string literals or comments can confuse it.
+ */
+function findFunctions(source) {
+  const functions = [];
+  const fnPattern = /\bfn\s+(\w+)\s*(?:<[^>]*>)?\s*\(([^)]*)\)[^{;]*\{/g;
+  let match;
+
+  while ((match = fnPattern.exec(source)) !== null) {
+    const bodyStart = match.index + match[0].length;
+    let depth = 1;
+    let i = bodyStart;
+    for (; i < source.length && depth > 0; i++) {
+      if (source[i] === "{") depth++;
+      else if (source[i] === "}") depth--;
+    }
+
+    const params = match[2]
+      .split(",")
+      .map((param) => param.trim())
+      .filter(Boolean)
+      .map((param) => param.split(":")[0].replace(/^mut\s+/, "").trim());
+
+    functions.push({
+      name: match[1],
+      params,
+      body: source.slice(bodyStart, i - 1),
+      line: lineOf(source, match.index),
+      bodyLine: lineOf(source, bodyStart),
+    });
+  }
+
+  return functions;
+}
+
+module.exports = {
+  listRustFiles,
+  findFunctions,
+  lineOf,
+};
@@ -0,0 +1,59 @@
+const fs = require("fs-extra");
+const os = require("os");
+const path = require("path");
+const shell = require("shelljs");
+const { execAsync } = require("./execAsync");
+
+const AUTH_FAILURE =
+  /Authentication failed|could not read Username|Permission denied|terminal prompts disabled|returned error: 40[13]/;
+
+/**
+ * Open a custom template source: a local directory, or a shallow clone of a
+ * git repository. Call `cleanup` once the template has been copied.
+ */
+async function openTemplateSource({ templatePath, templateRepo }) {
+  if (templatePath) {
+    const root = path.resolve(templatePath);
+    if (!(await fs.pathExists(root))) {
+      throw new Error(`Template path ${root} does not exist`);
+    }
+    return { root, label: root, cleanup: async () => {} };
+  }
+
+  if (!shell.which("git")) {
+    throw new Error("git is not installed. Please install git to use --template-repo.");
+  }
+  const root = await fs.mkdtemp(path.join(os.tmpdir(), "ownable-template-"));
+  try {
+    // never block on a credential prompt
+    await execAsync(`git clone --depth 1 --quiet "${templateRepo}" "${root}"`, {
+      env: { ...process.env, GIT_TERMINAL_PROMPT: "0" },
+    });
+  } catch (error) {
+    await fs.remove(root);
+    const stderr = (error.stderr || "").trim();
+    if (AUTH_FAILURE.test(stderr)) {
+      throw new Error(
+        `Authentication failed for ${templateRepo}. Check your git credentials or SSH key.`
+      );
+    }
+    throw new Error(`Failed to clone ${templateRepo}: ${stderr || error.message}`);
+  }
+  return { root, label: templateRepo, cleanup: () => fs.remove(root) };
+}
+
+/**
+ * Find a template by name, either under `templates/` or at the top of the
+ * source
+ */
+async function findTemplateDir(source, name) {
+  const candidates = [path.join(source.root, "templates", name), path.join(source.root, name)];
+  for (const dir of candidates) {
+    if (await fs.pathExists(path.join(dir, "Cargo.toml"))) return dir;
+  }
+  throw new Error(
+    `Template '${name}' not found in ${source.label}. Expected templates/${name}/Cargo.toml or ${name}/Cargo.toml.`
+  );
+}
+
+module.exports = { openTemplateSource, findTemplateDir };
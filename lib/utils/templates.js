@@ -0,0 +1,143 @@
+const path = require("path");
+
+const TEMPLATES_DIR = path.join(__dirname, "../../templates");
+
+// The contract crate shared by every built-in template; a template's own
+// directory only adds its assets and README
+const COMMON_DIR = path.join(TEMPLATES_DIR, "common");
+
+// Templates are vendored with the CLI and versioned with it
+const TEMPLATE_VERSION = require("../../package.json").version;
+
+// Built-in templates and the OwnableType variant each one compiles to.
+// `feature` is the cargo feature enabling the type's own messages and state.
+const TEMPLATES = [
+  {
+    name: "static-ownable",
+    type: "image",
+    variant: "Image",
+    description: "Static Ownable - A simple static image or content display",
+  },
+  {
+    name: "music-ownable",
+    type: "music",
+    variant: "Music",
+    description: "Music Ownable - Audio with cover art and backdrop image",
+  },
+  {
+    name: "subscription-ownable",
+    type: "subscription",
+    variant: "Subscription",
+    feature: "subscription",
+    description: "Subscription Ownable - A membership image that expires unless renewed",
+  },
+  {
+    name: "consumable-ownable",
+    type: "consumable",
+    variant: "Consumable",
+    feature: "consumable",
+    description: "Consumable Ownable - An image with a limited number of uses, like a ticket",
+  },
+  {
+    name: "artwork-ownable",
+    type: "artwork",
+    variant: "Artwork",
+    feature: "artwork",
+    description: "Artwork Ownable - An artwork image with medium, year and print edition",
+  },
+  {
+    name: "document-ownable",
+    type: "document",
+    variant: "Document",
+    description: "Document Ownable - A PDF document with a cover image",
+  },
+];
+
+// Templates scaffolded from --template-path or --template-repo; they take
+// precedence over a built-in template of the same name
+const CUSTOM_TEMPLATES = [];
+
+function registerTemplate(template) {
+  CUSTOM_TEMPLATES.unshift(template);
+  return template;
+}
+
+// Drop a custom template once its source is cleaned up
+function unregisterTemplate(name) {
+  const index = CUSTOM_TEMPLATES.findIndex((t) => t.name === name);
+  if (index !== -1) CUSTOM_TEMPLATES.splice(index, 1);
+}
+
+function getTemplate(name) {
+  const template = [...CUSTOM_TEMPLATES, ...TEMPLATES].find((t) => t.name === name);
+  if (!template) {
+    throw new Error(
+      `Unknown template '${name}'. Available templates: ${TEMPLATES.map(
+        (t) => t.name
+      ).join(", ")}`
+    );
+  }
+  return template;
+}
+
+/**
+ * Find the template for an ownable type, e.g. `music` -> `music-ownable`
+ */
+function resolveTemplate(type) {
+  const matches = TEMPLATES.filter((t) => t.type === type);
+  if (matches.length === 0) {
+    throw new Error(
+      `No template for ownable type '${type}'. Available types: ${[
+        ...new Set(TEMPLATES.map((t) => t.type)),
+      ].join(", ")}`
+    );
+  }
+  if (matches.length > 1) {
+    throw new Error(
+      `Multiple templates claim ownable type '${type}': ${matches
+        .map((t) => t.name)
+        .join(", ")}`
+    );
+  }
+  return matches[0];
+}
+
+/**
+ * Check that a pinned template version is the one vendored with this CLI
+ */
+function resolveTemplateVersion(version) {
+  if (version && version !== TEMPLATE_VERSION) {
+    throw new Error(
+      `Template version ${version} is not available. This CLI ships templates at version ${TEMPLATE_VERSION}.`
+    );
+  }
+  return TEMPLATE_VERSION;
+}
+
+/**
+ * Directories a template is made of, copied in order: the shared crate then
+ * the template's own files. A custom template is a single, complete crate.
+ */
+function templateDirs(template) {
+  if (template.dir) return [template.dir];
+  return [COMMON_DIR, path.join(TEMPLATES_DIR, template.name)];
+}
+
+/**
+ * Cargo features to enable by default, as the items of a TOML array
+ */
+function templateFeatures(template) {
+  return template.feature ? JSON.stringify(template.feature) : "";
+}
+
+module.exports = {
+  TEMPLATES,
+  getTemplate,
+  registerTemplate,
+  resolveTemplate,
+  resolveTemplateVersion,
+  templateDirs,
+  templateFeatures,
+  unregisterTemplate,
+  TEMPLATE_VERSION,
+};
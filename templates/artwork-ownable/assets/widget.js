@@ -0,0 +1,30 @@
+// Widget state bridge for PLACEHOLDER2_TITLE (PLACEHOLDER2_TYPE ownable)
+//
+// The wallet hosting this widget answers `get_widget_state` queries and
+// posts the result back into the iframe.
+(function () {
+  var OWNABLE_NAME = "PLACEHOLDER2_TITLE";
+  var OWNABLE_TYPE = "PLACEHOLDER2_TYPE";
+
+  function applyWidgetState(state) {
+    document.title = OWNABLE_NAME;
+    document.body.dataset.ownableType = OWNABLE_TYPE;
+    if (state && state.locked) {
+      document.body.classList.add("locked");
+    } else {
+      document.body.classList.remove("locked");
+    }
+  }
+
+  window.addEventListener("message", function (event) {
+    var data = event.data || {};
+    if (data.type === "widget_state") {
+      applyWidgetState(data.state);
+    }
+  });
+
+  window.parent.postMessage(
+    { type: "query", msg: { get_widget_state: {} } },
+    "*"
+  );
+})();
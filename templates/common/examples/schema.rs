@@ -0,0 +1,51 @@
+use std::env::current_dir;
+use std::fs::create_dir_all;
+
+use cosmwasm_schema::{export_schema, remove_schemas, schema_for};
+use ownable_std::{ExternalEventMsg, InfoResponse, Metadata, NFT};
+
+use PLACEHOLDER3_MSG::msg::{
+    InstantiateMsg, ExecuteMsg, QueryMsg, MigrateMsg, NotifyMsg,
+    CapabilitiesResponse, CreatorResponse, Eip155Response, LockProofResponse,
+    NetworkAddressResponse, OwnerResponse, PackageCidResponse, ProvenanceProofResponse,
+    WidgetStateResponse,
+};
+use PLACEHOLDER3_MSG::pagination::Page;
+use PLACEHOLDER3_STATE::state::{Config, EventRecord, LockInfo, OwnershipRecord, Trait};
+#[cfg(feature = "artwork")]
+use PLACEHOLDER3_STATE::state::ArtworkInfo;
+
+fn main() {
+    let mut out_dir = current_dir().unwrap();
+    out_dir.push("schema");
+    create_dir_all(&out_dir).unwrap();
+    remove_schemas(&out_dir).unwrap();
+
+    export_schema(&schema_for!(InstantiateMsg), &out_dir);
+    export_schema(&schema_for!(ExecuteMsg), &out_dir);
+    export_schema(&schema_for!(QueryMsg), &out_dir);
+    export_schema(&schema_for!(MigrateMsg), &out_dir);
+    export_schema(&schema_for!(NotifyMsg), &out_dir);
+    export_schema(&schema_for!(ExternalEventMsg), &out_dir);
+    export_schema(&schema_for!(InfoResponse), &out_dir);
+    export_schema(&schema_for!(Metadata), &out_dir);
+    export_schema(&schema_for!(Config), &out_dir);
+    export_schema(&schema_for!(CapabilitiesResponse), &out_dir);
+    export_schema(&schema_for!(CreatorResponse), &out_dir);
+    export_schema(&schema_for!(OwnerResponse), &out_dir);
+    export_schema(&schema_for!(LockProofResponse), &out_dir);
+    export_schema(&schema_for!(ProvenanceProofResponse), &out_dir);
+    export_schema(&schema_for!(PackageCidResponse), &out_dir);
+    export_schema(&schema_for!(Eip155Response), &out_dir);
+    export_schema(&schema_for!(NetworkAddressResponse), &out_dir);
+    export_schema(&schema_for!(WidgetStateResponse), &out_dir);
+    export_schema(&schema_for!(LockInfo), &out_dir);
+    export_schema(&schema_for!(OwnershipRecord), &out_dir);
+    export_schema(&schema_for!(Trait), &out_dir);
+    export_schema(&schema_for!(Page<NFT>), &out_dir);
+    export_schema(&schema_for!(Page<EventRecord>), &out_dir);
+    export_schema(&schema_for!(Page<OwnershipRecord>), &out_dir);
+    export_schema(&schema_for!(Page<Trait>), &out_dir);
+    #[cfg(feature = "artwork")]
+    export_schema(&schema_for!(ArtworkInfo), &out_dir);
+}
@@ -0,0 +1,73 @@
+//! Smoke test for the generated ownable: instantiate it in a cw-multi-test
+//! app, transfer and lock it, and print the results. Run with `cargo simulate`.
+
+use cw_multi_test::{AppBuilder, AppResponse, ContractWrapper, Executor};
+use ownable_std::address_lto;
+use serde_json::{json, Value};
+
+use PLACEHOLDER3_CONTRACT::contract::{execute, instantiate, query};
+use PLACEHOLDER3_CONTRACT::testing::{instantiate_msg, public_key, LtoApi, NETWORK_ID};
+use PLACEHOLDER3_MSG::msg::{ExecuteMsg, QueryMsg};
+
+fn fail(step: &str, error: impl std::fmt::Debug) -> ! {
+    eprintln!("{} failed: {:?}", step, error);
+    std::process::exit(1);
+}
+
+fn print_response(step: &str, response: &AppResponse) {
+    println!("{}:", step);
+    for event in response.events.iter().filter(|event| event.ty == "wasm") {
+        for attribute in &event.attributes {
+            println!("  {} = {}", attribute.key, attribute.value);
+        }
+    }
+}
+
+fn main() {
+    let mut app = AppBuilder::new()
+        .with_api(LtoApi::default())
+        .build(|_, _, _| {});
+    let code_id = app.store_code(Box::new(ContractWrapper::new(execute, instantiate, query)));
+
+    let owner = public_key(1);
+    let recipient = public_key(2);
+    let recipient_address = address_lto(NETWORK_ID as char, recipient.to_string())
+        .unwrap_or_else(|error| fail("derive recipient address", error));
+
+    let msg = instantiate_msg(json!({}));
+    let contract = app
+        .instantiate_contract(code_id, owner.clone(), &msg, &[], "ownable", None)
+        .unwrap_or_else(|error| fail("instantiate", error));
+    println!("instantiate:\n  contract = {}", contract);
+
+    let transfer = ExecuteMsg::Transfer {
+        recipient: recipient_address.to_string(),
+        memo: None,
+        sale_price: None,
+    };
+    let response = app
+        .execute_contract(owner, contract.clone(), &transfer, &[])
+        .unwrap_or_else(|error| fail("transfer", error));
+    print_response("transfer", &response);
+
+    // the recipient owns the ownable now, so it locks it
+    let response = app
+        .execute_contract(recipient, contract.clone(), &ExecuteMsg::Lock {}, &[])
+        .unwrap_or_else(|error| fail("lock", error));
+    print_response("lock", &response);
+
+    let info: Value = app
+        .wrap()
+        .query_wasm_smart(contract.clone(), &QueryMsg::GetInfo {})
+        .unwrap_or_else(|error| fail("get_info", error));
+    println!("get_info:\n  {}", info);
+
+    let locked: bool = app
+        .wrap()
+        .query_wasm_smart(contract, &QueryMsg::IsLocked {})
+        .unwrap_or_else(|error| fail("is_locked", error));
+    println!("is_locked:\n  {}", locked);
+    if !locked {
+        fail("lock", "is_locked is still false");
+    }
+}
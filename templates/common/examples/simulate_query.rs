@@ -0,0 +1,45 @@
+//! Query a freshly instantiated ownable in a cw-multi-test app and print the
+//! responses as JSON, keyed by query. `SIMULATE_QUERIES` holds a JSON array
+//! of queries; without it the info, metadata and widget state are queried.
+//! Run with `cargo simulate-query`.
+
+use cw_multi_test::{AppBuilder, ContractWrapper, Executor};
+use serde_json::{json, Map, Value};
+
+use PLACEHOLDER3_CONTRACT::contract::{execute, instantiate, query};
+use PLACEHOLDER3_CONTRACT::testing::{instantiate_msg, public_key, LtoApi};
+use PLACEHOLDER3_MSG::msg::QueryMsg;
+
+fn fail(step: &str, error: impl std::fmt::Debug) -> ! {
+    eprintln!("{} failed: {:?}", step, error);
+    std::process::exit(1);
+}
+
+fn main() {
+    let queries: Vec<Value> = match std::env::var("SIMULATE_QUERIES") {
+        Ok(queries) => serde_json::from_str(&queries).unwrap_or_else(|error| fail("parse queries", error)),
+        Err(_) => vec![json!({ "get_info": {} }), json!({ "get_metadata": {} }), json!({ "get_widget_state": {} })],
+    };
+
+    let mut app = AppBuilder::new()
+        .with_api(LtoApi::default())
+        .build(|_, _, _| {});
+    let code_id = app.store_code(Box::new(ContractWrapper::new(execute, instantiate, query)));
+    let contract = app
+        .instantiate_contract(code_id, public_key(1), &instantiate_msg(json!({})), &[], "ownable", None)
+        .unwrap_or_else(|error| fail("instantiate", error));
+
+    let mut responses = Map::new();
+    for value in queries {
+        let name = value.as_object()
+            .and_then(|query| query.keys().next().cloned())
+            .unwrap_or_else(|| fail("parse queries", format!("{} is not a query", value)));
+        let msg: QueryMsg = serde_json::from_value(value).unwrap_or_else(|error| fail(&name, error));
+        let response: Value = app
+            .wrap()
+            .query_wasm_smart(contract.clone(), &msg)
+            .unwrap_or_else(|error| fail(&name, error));
+        responses.insert(name, response);
+    }
+    println!("{}", serde_json::to_string_pretty(&responses).unwrap());
+}
@@ -0,0 +1,54 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::ContractError;
+
+/// A CAIP-2 chain id of the form `namespace:reference`, e.g. `eip155:1`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Caip2 {
+    pub namespace: String,
+    pub reference: String,
+}
+
+impl FromStr for Caip2 {
+    type Err = ContractError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ContractError::MatchChainIdError { val: s.to_string() };
+
+        // exactly two segments, so `eip155` and `eip155:1:2` are both rejected
+        let mut segments = s.split(':');
+        let (namespace, reference) = match (segments.next(), segments.next(), segments.next()) {
+            (Some(namespace), Some(reference), None) => (namespace, reference),
+            _ => return Err(invalid()),
+        };
+
+        // namespace: [-a-z0-9]{3,8}, reference: [-_a-zA-Z0-9]{1,32}
+        let valid_namespace = (3..=8).contains(&namespace.len())
+            && namespace.chars().all(|c| c == '-' || c.is_ascii_lowercase() || c.is_ascii_digit());
+        let valid_reference = (1..=32).contains(&reference.len())
+            && reference.chars().all(|c| c == '-' || c == '_' || c.is_ascii_alphanumeric());
+
+        if !valid_namespace || !valid_reference {
+            return Err(invalid());
+        }
+
+        Ok(Caip2 {
+            namespace: namespace.to_string(),
+            reference: reference.to_string(),
+        })
+    }
+}
+
+/// Split a CAIP-2 chain id into its namespace and reference, rejecting
+/// anything but two non-empty, well-formed segments
+pub fn parse_caip2(s: &str) -> Result<(String, String), ContractError> {
+    let chain_id: Caip2 = s.parse()?;
+    Ok((chain_id.namespace, chain_id.reference))
+}
+
+impl fmt::Display for Caip2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.namespace, self.reference)
+    }
+}
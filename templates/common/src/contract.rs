@@ -0,0 +1,2256 @@
+use crate::caip::{parse_caip2, Caip2};
+use crate::error::ContractError;
+use crate::msg::{CapabilitiesResponse, CreatorResponse, Eip155Response, ExecuteMsg, InstantiateMsg, LockProofResponse, MigrateMsg, NetworkAddressResponse, NotifyMsg, OwnerResponse, PackageCidResponse, ProvenanceProofResponse, QueryMsg, ShareAllocation, WidgetStateResponse};
+use crate::pagination::{MAX_LIMIT, page_size, paginate, paginate_list};
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::{Addr, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+use cosmwasm_std::{BankMsg, Binary, Coin, Event, Order, StdError, Storage, Timestamp, WasmMsg, to_json_binary, to_json_vec};
+use cw_storage_plus::{Bound, Map};
+#[cfg(feature = "entry-points")]
+use cosmwasm_std::entry_point;
+use cw2::{get_contract_version, set_contract_version};
+use semver::Version;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::convert::TryFrom;
+use blake2::Blake2b;
+use blake2::digest::consts::U32;
+use sha2::{Digest, Sha256};
+use crate::state::{NFT_ITEMS, CONFIG, METADATA, LOCKED, PACKAGE_CID, OWNABLE_INFO, NETWORK_ID, OWNABLE_ID, CREATOR, LOCK_ORIGIN, LOCK_INFO, THUMBNAIL, NATIVE, EVENT_LOG, BURNED, APPROVAL, LEASE, OWNERSHIP_HISTORY, OWNER_PUBLIC_KEY, PROCESSED_EVENTS, SHARES, TOTAL_SHARES, ATTRIBUTES, ALLOWED_NETWORKS, MERGED_FROM, MERGE_CANDIDATES, PROCESSED_EVENT_ORDER, LEGACY_NFT_ITEM, DEFAULT_DENOM, AuthPolicy, Config, EventRecord, Lease, LockInfo, LockKind, LockOrigin, OwnableType, OwnershipRecord, Thumbnail};
+use ownable_std::{address_eip155, address_lto, ExternalEventMsg, InfoResponse, Metadata, NFT, OwnableInfo};
+#[cfg(feature = "subscription")]
+use crate::state::EXPIRES_AT;
+#[cfg(feature = "consumable")]
+use crate::state::USES_REMAINING;
+#[cfg(feature = "artwork")]
+use crate::state::{ARTWORK, ArtworkInfo};
+
+// Log a handler decision through `deps.api.debug` with the `debug-trace`
+// feature; compiles to nothing otherwise
+macro_rules! trace {
+    ($api:expr, $($arg:tt)*) => {
+        #[cfg(feature = "debug-trace")]
+        $api.debug(&format!($($arg)*));
+    };
+}
+
+// version info for migration info
+pub(crate) const CONTRACT_NAME: &str = PLACEHOLDER4_CONTRACT_NAME;
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+// the ownable type is the single source for the type reported in OwnableInfo
+const OWNABLE_TYPE: OwnableType = PLACEHOLDER4_OWNABLE_TYPE;
+
+// LTO network bytes: mainnet and testnet
+const NETWORK_IDS: [u8; 2] = [b'L', b'T'];
+const MAX_FIELD_LENGTH: usize = 256;
+const MAX_MEMO_LENGTH: usize = 256;
+const MAX_THUMBNAIL_DATA_LENGTH: usize = 16 * 1024;
+
+const MAX_EVENT_LOG_LENGTH: usize = 50;
+// records kept in OWNERSHIP_HISTORY
+const MAX_OWNERSHIP_HISTORY: u64 = 100;
+// event ids remembered for replay protection; a relay would have to hold an
+// event back for this many newer ones before it could be replayed
+const MAX_PROCESSED_EVENTS: u64 = 1_000;
+const MAX_MERGED: usize = 50;
+const MAX_ROYALTY_BPS: u16 = 10_000;
+#[cfg(feature = "artwork")]
+const MIN_ARTWORK_YEAR: u16 = 1000;
+#[cfg(feature = "artwork")]
+const SECONDS_PER_YEAR: u64 = 31_556_952;
+
+// Attribute keys and values LTO's event indexer uses to classify ownable
+// actions. The `_contract_address` key is reserved and added by the runtime.
+const ATTR_ACTION: &str = "action";
+const ATTR_SENDER: &str = "sender";
+const ACTION_TRANSFER: &str = "transfer";
+const ACTION_LOCK: &str = "lock";
+const ACTION_RELEASE: &str = "release";
+const ACTION_UNLOCK: &str = "unlock";
+
+#[cfg_attr(feature = "entry-points", entry_point)]
+pub fn instantiate(
+    mut deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    validate_instantiate(&msg)?;
+    #[cfg(feature = "artwork")]
+    validate_artwork(&msg.artwork, &_env)?;
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let derived_addr = address_lto(
+        msg.network_id as char,
+        info.sender.to_string()
+    )?;
+
+    let ownable_info = OwnableInfo {
+        owner: derived_addr.clone(),
+        issuer: derived_addr.clone(),
+        ownable_type: Some(OWNABLE_TYPE.to_string()),
+    };
+
+    let metadata = match msg.metadata {
+        Some(provided) => merge_metadata(default_metadata(OWNABLE_TYPE), provided),
+        None => default_metadata(OWNABLE_TYPE),
+    };
+    if matches!(OWNABLE_TYPE, OwnableType::Image | OwnableType::Artwork) && metadata.image.as_deref().unwrap_or("").is_empty() {
+        return Err(ContractError::Validation {
+            field: "metadata.image".to_string(),
+            reason: "required for image ownables".to_string(),
+        });
+    }
+
+    NETWORK_ID.save(deps.storage, &msg.network_id)?;
+    let notify_contract = msg.notify_contract
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    let royalty_bps = msg.royalty_bps.unwrap_or(0);
+    ensure_valid_royalty(royalty_bps)?;
+    let accepted_denom = msg.accepted_denom.unwrap_or_else(|| DEFAULT_DENOM.to_string());
+    ensure_valid_denom(&accepted_denom)?;
+    let admin = msg.admin
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    CONFIG.save(deps.storage, &Some(Config {
+        notify_contract,
+        bridgeable: msg.bridgeable.unwrap_or(true),
+        royalty_bps,
+        accepted_denom,
+        transfer_policy: msg.transfer_policy.unwrap_or_default(),
+        lock_policy: msg.lock_policy.unwrap_or_default(),
+        transfer_locked_until: msg.transfer_locked_until,
+        admin,
+    }))?;
+    let creator = msg.creator
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    CREATOR.save(deps.storage, &creator)?;
+    ALLOWED_NETWORKS.save(deps.storage, &msg.allowed_networks.unwrap_or_default())?;
+    if let (Some(total_shares), Some(shares)) = (msg.total_shares, &msg.shares) {
+        save_shares(deps.branch(), total_shares, shares)?;
+    }
+    if let Some(nft) = msg.nft {
+        let nfts = nft.into_vec();
+        if !nfts.is_empty() {
+            NFT_ITEMS.save(deps.storage, &nfts)?;
+        }
+    }
+    METADATA.save(deps.storage, &metadata)?;
+    LOCKED.save(deps.storage, &false)?;
+    NATIVE.save(deps.storage, &false)?;
+    BURNED.save(deps.storage, &false)?;
+    OWNABLE_INFO.save(deps.storage, &ownable_info)?;
+    OWNER_PUBLIC_KEY.save(deps.storage, &Some(info.sender.to_string()))?;
+    PACKAGE_CID.save(deps.storage, &msg.package)?;
+    OWNABLE_ID.save(deps.storage, &msg.ownable_id)?;
+    ATTRIBUTES.save(deps.storage, &msg.attributes.unwrap_or_default())?;
+    THUMBNAIL.save(deps.storage, &Thumbnail {
+        url: msg.thumbnail_url,
+        data: msg.thumbnail_data,
+    })?;
+
+    #[cfg(feature = "subscription")]
+    EXPIRES_AT.save(deps.storage, &msg.expires_at)?;
+    #[cfg(feature = "consumable")]
+    USES_REMAINING.save(deps.storage, &msg.uses)?;
+    #[cfg(feature = "artwork")]
+    ARTWORK.save(deps.storage, &msg.artwork)?;
+
+    let mut response = Response::new()
+        .add_attribute("method", "instantiate")
+        .add_attribute("ownable_id", msg.ownable_id)
+        .add_attribute("owner", derived_addr.clone())
+        .add_attribute("issuer", derived_addr.clone());
+    #[cfg(feature = "subscription")]
+    {
+        response = response.add_attribute("expires_at", msg.expires_at.to_string());
+    }
+    #[cfg(feature = "consumable")]
+    {
+        response = response.add_attribute("uses_remaining", msg.uses.to_string());
+    }
+    if let Some(creator) = creator {
+        response = response.add_attribute("creator", creator);
+    }
+    Ok(response)
+}
+
+fn validate_instantiate(msg: &InstantiateMsg) -> Result<(), ContractError> {
+    if !NETWORK_IDS.contains(&msg.network_id) {
+        return Err(ContractError::InvalidNetwork {
+            val: (msg.network_id as char).to_string(),
+        });
+    }
+
+    let fields = [("ownable_id", &msg.ownable_id), ("package", &msg.package)];
+    for (field, value) in fields {
+        if value.is_empty() {
+            return Err(ContractError::Validation {
+                field: field.to_string(),
+                reason: "must not be empty".to_string(),
+            });
+        }
+        if value.len() > MAX_FIELD_LENGTH {
+            return Err(ContractError::Validation {
+                field: field.to_string(),
+                reason: format!("exceeds {} characters", MAX_FIELD_LENGTH),
+            });
+        }
+    }
+
+    // only evm chains share contract addresses across networks
+    for network in msg.allowed_networks.iter().flatten() {
+        let chain_id: Caip2 = network.parse()?;
+        if chain_id.namespace != "eip155" {
+            return Err(ContractError::Validation {
+                field: "allowed_networks".to_string(),
+                reason: format!("{} is not an eip155 network", network),
+            });
+        }
+    }
+
+    for attribute in msg.attributes.iter().flatten() {
+        if attribute.trait_type.is_empty() || attribute.value.is_empty() {
+            return Err(ContractError::Validation {
+                field: "attributes".to_string(),
+                reason: "trait_type and value must not be empty".to_string(),
+            });
+        }
+    }
+
+    if msg.total_shares.is_some() != msg.shares.is_some() {
+        return Err(ContractError::Validation {
+            field: "shares".to_string(),
+            reason: "total_shares and shares must be set together".to_string(),
+        });
+    }
+
+    let thumbnail = [
+        ("thumbnail_url", &msg.thumbnail_url, MAX_FIELD_LENGTH),
+        ("thumbnail_data", &msg.thumbnail_data, MAX_THUMBNAIL_DATA_LENGTH),
+    ];
+    for (field, value, max_length) in thumbnail {
+        let value = match value {
+            Some(value) => value,
+            None => continue,
+        };
+        if value.is_empty() {
+            return Err(ContractError::Validation {
+                field: field.to_string(),
+                reason: "must not be empty".to_string(),
+            });
+        }
+        if value.len() > max_length {
+            return Err(ContractError::Validation {
+                field: field.to_string(),
+                reason: format!("exceeds {} characters", max_length),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "artwork")]
+fn validate_artwork(artwork: &ArtworkInfo, env: &Env) -> Result<(), ContractError> {
+    let invalid = |field: &str, reason: String| ContractError::Validation {
+        field: format!("artwork.{}", field),
+        reason,
+    };
+
+    if artwork.medium.trim().is_empty() {
+        return Err(invalid("medium", "must not be empty".to_string()));
+    }
+    if artwork.medium.len() > MAX_FIELD_LENGTH {
+        return Err(invalid("medium", format!("exceeds {} characters", MAX_FIELD_LENGTH)));
+    }
+    if let Some(dimensions) = &artwork.dimensions {
+        if dimensions.is_empty() || dimensions.len() > MAX_FIELD_LENGTH {
+            return Err(invalid("dimensions", format!("must be 1 to {} characters", MAX_FIELD_LENGTH)));
+        }
+    }
+
+    // approximate, but enough to reject a year that hasn't happened yet
+    let current_year = 1970 + env.block.time.seconds() / SECONDS_PER_YEAR;
+    if artwork.year < MIN_ARTWORK_YEAR || u64::from(artwork.year) > current_year {
+        return Err(invalid("year", format!("must be between {} and {}", MIN_ARTWORK_YEAR, current_year)));
+    }
+
+    match (artwork.edition, artwork.edition_total) {
+        (None, None) => {}
+        (Some(edition), Some(total)) if edition >= 1 && edition <= total => {}
+        (Some(_), Some(total)) => {
+            return Err(invalid("edition", format!("must be between 1 and {}", total)));
+        }
+        _ => {
+            return Err(invalid("edition", "must be set together with edition_total".to_string()));
+        }
+    }
+
+    Ok(())
+}
+
+/// Metadata of a freshly instantiated ownable, pointing at the packaged assets
+pub fn default_metadata(ownable_type: OwnableType) -> Metadata {
+    let mut metadata = Metadata {
+        image: None,
+        image_data: PLACEHOLDER4_IMAGE_DATA,
+        external_url: None,
+        description: Some(PLACEHOLDER4_DESCRIPTION.to_string()),
+        name: Some(PLACEHOLDER4_NAME.to_string()),
+        background_color: None,
+        animation_url: None,
+        youtube_url: None
+    };
+
+    match ownable_type {
+        OwnableType::Image
+        | OwnableType::Subscription
+        | OwnableType::Consumable
+        | OwnableType::Artwork => {
+            metadata.image = Some("thumbnail.webp".to_string());
+        }
+        OwnableType::Document => {
+            metadata.image = Some("thumbnail.webp".to_string());
+            metadata.animation_url = Some("index.html".to_string());
+        }
+        OwnableType::Music => {
+            metadata.image = Some("thumbnail.webp".to_string());
+            metadata.background_color = Some("000000".to_string());
+            metadata.animation_url = Some("index.html".to_string());
+        }
+    }
+
+    metadata
+}
+
+fn merge_metadata(defaults: Metadata, provided: Metadata) -> Metadata {
+    Metadata {
+        image: provided.image.or(defaults.image),
+        image_data: provided.image_data.or(defaults.image_data),
+        external_url: provided.external_url.or(defaults.external_url),
+        description: provided.description.or(defaults.description),
+        name: provided.name.or(defaults.name),
+        background_color: provided.background_color.or(defaults.background_color),
+        animation_url: provided.animation_url.or(defaults.animation_url),
+        youtube_url: provided.youtube_url.or(defaults.youtube_url),
+    }
+}
+
+#[cfg_attr(feature = "entry-points", entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    let ownable_id = OWNABLE_ID.load(deps.storage)?;
+    if BURNED.may_load(deps.storage)?.unwrap_or(false) {
+        return Err(ContractError::Burned {});
+    }
+    trace!(deps.api, "execute {:?}", msg);
+    let response = match msg {
+        ExecuteMsg::Transfer { recipient, memo, sale_price } =>
+            try_transfer(info, deps, env, recipient, memo, sale_price),
+        ExecuteMsg::Lock {} => try_lock(info, deps, env),
+        ExecuteMsg::LockFor { network, contract, token_id } =>
+            try_lock_for(info, deps, env, LockOrigin { network, contract, token_id }),
+        ExecuteMsg::UpdateConfig { notify_contract, royalty_bps, accepted_denom, transfer_locked_until, admin } =>
+            try_update_config(info, deps, notify_contract, royalty_bps, accepted_denom, transfer_locked_until, admin),
+        ExecuteMsg::Merge { other, proof } => try_merge(info, deps, env, other, proof),
+        ExecuteMsg::TransferShares { recipient, amount } =>
+            try_transfer_shares(info, deps, recipient, amount),
+        ExecuteMsg::Bridge { to_network } => try_bridge(info, deps, env, to_network),
+        ExecuteMsg::Release { to } => try_owner_release(info, deps, env, to),
+        ExecuteMsg::Unlock {} => try_unlock(info, deps),
+        ExecuteMsg::Burn {} => try_burn(info, deps, env),
+        ExecuteMsg::Approve { spender } => try_approve(info, deps, spender),
+        ExecuteMsg::TransferAndApprove { recipient, spender } =>
+            try_transfer_and_approve(info, deps, env, recipient, spender),
+        ExecuteMsg::Lease { to, until } => try_lease(info, deps, env, to, until),
+        ExecuteMsg::RevokeApproval {} => try_revoke_approval(info, deps),
+        ExecuteMsg::TransferFrom { recipient } => try_transfer_from(info, deps, env, recipient),
+        ExecuteMsg::UpdateMetadata { description, external_url, image, animation_url } =>
+            try_update_metadata(info, deps, description, external_url, image, animation_url),
+        #[cfg(feature = "subscription")]
+        ExecuteMsg::Renew { additional_secs } => try_renew(info, deps, env, additional_secs),
+        #[cfg(feature = "consumable")]
+        ExecuteMsg::Consume { amount } => try_consume(info, deps, env, amount),
+    }?;
+    Ok(response.add_attribute("ownable_id", ownable_id))
+}
+
+pub fn try_lock(info: MessageInfo, deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let sender = authorize(AuthAction::Lock, deps.as_ref(), &info)?;
+    ensure_unlocked(deps.as_ref())?;
+    ensure_single_owner(deps.as_ref())?;
+    ensure_bridgeable(deps.as_ref())?;
+
+    let is_locked = true;
+    LOCKED.save(deps.storage, &is_locked)?;
+    let network = env.block.chain_id.clone();
+    save_lock_info(deps.storage, &env, &sender, network, LockKind::Owner)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "try_lock")
+        .add_attribute(ATTR_ACTION, ACTION_LOCK)
+        .add_attribute(ATTR_SENDER, sender)
+        .add_attribute("is_locked", is_locked.to_string())
+    )
+}
+
+pub fn try_lock_for(
+    info: MessageInfo,
+    deps: DepsMut,
+    env: Env,
+    origin: LockOrigin,
+) -> Result<Response, ContractError> {
+    let sender = authorize(AuthAction::Lock, deps.as_ref(), &info)?;
+    ensure_unlocked(deps.as_ref())?;
+    ensure_single_owner(deps.as_ref())?;
+    ensure_bridgeable(deps.as_ref())?;
+
+    // validate the target before touching any state
+    let nfts = load_backing_nfts(deps.as_ref())?;
+    let allowed_networks = load_allowed_networks(deps.as_ref())?;
+    if !nfts.iter().any(|nft| {
+        is_backing_nft(nft, &allowed_networks, &origin.network, &origin.contract, &origin.token_id)
+    }) {
+        return Err(ContractError::LockError { val: "Not a backing nft".to_string() });
+    }
+
+    LOCKED.save(deps.storage, &true)?;
+    LOCK_ORIGIN.save(deps.storage, &origin)?;
+    save_lock_info(deps.storage, &env, &sender, origin.network.clone(), LockKind::Bridge)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "try_lock_for")
+        .add_attribute(ATTR_ACTION, ACTION_LOCK)
+        .add_attribute(ATTR_SENDER, sender)
+        .add_attribute("is_locked", "true")
+        .add_attribute("network", origin.network)
+        .add_attribute("contract", origin.contract)
+        .add_attribute("token_id", origin.token_id)
+    )
+}
+
+#[cfg(feature = "subscription")]
+pub fn try_renew(
+    info: MessageInfo,
+    deps: DepsMut,
+    env: Env,
+    additional_secs: u64,
+) -> Result<Response, ContractError> {
+    // only ownable owner can renew it
+    let ownership = OWNABLE_INFO.load(deps.storage)?;
+    let network_id = NETWORK_ID.load(deps.storage)?;
+    let sender = address_lto(network_id as char, info.sender.to_string())?;
+    if sender != ownership.owner {
+        return Err(ContractError::Unauthorized {
+            val: "Unauthorized".into(),
+        });
+    }
+    ensure_unlocked(deps.as_ref())?;
+
+    let now = env.block.time.seconds();
+    let expires_at = EXPIRES_AT.load(deps.storage)?;
+    let renewed_until = expires_at.max(now)
+        .checked_add(additional_secs)
+        .ok_or(ContractError::Validation {
+            field: "additional_secs".to_string(),
+            reason: "expiry overflows".to_string(),
+        })?;
+    EXPIRES_AT.save(deps.storage, &renewed_until)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "try_renew")
+        .add_attribute(ATTR_SENDER, sender)
+        .add_attribute("expires_at", renewed_until.to_string())
+    )
+}
+
+/// Like `LockFor`, but the nft is looked up by network and the response
+/// carries the `ExternalEventMsg` attributes a relayer replays on that chain
+pub fn try_bridge(
+    info: MessageInfo,
+    deps: DepsMut,
+    env: Env,
+    to_network: String,
+) -> Result<Response, ContractError> {
+    let sender = authorize(AuthAction::Lock, deps.as_ref(), &info)?;
+    ensure_unlocked(deps.as_ref())?;
+    ensure_bridgeable(deps.as_ref())?;
+
+    let nfts = load_backing_nfts(deps.as_ref())?;
+    let allowed_networks = load_allowed_networks(deps.as_ref())?;
+    let nft = nfts.iter()
+        .find(|nft| is_backing_nft(nft, &allowed_networks, &to_network, &nft.address, &nft.id.to_string()))
+        .ok_or(ContractError::LockError {
+            val: format!("No backing nft on {}", to_network),
+        })?;
+    let origin = LockOrigin {
+        network: to_network,
+        contract: nft.address.clone(),
+        token_id: nft.id.to_string(),
+    };
+
+    LOCKED.save(deps.storage, &true)?;
+    LOCK_ORIGIN.save(deps.storage, &origin)?;
+    save_lock_info(deps.storage, &env, &sender, origin.network.clone(), LockKind::Bridge)?;
+
+    let owner = OWNABLE_INFO.load(deps.storage)?.owner;
+    Ok(Response::new()
+        .add_attribute("method", "try_bridge")
+        .add_attribute(ATTR_ACTION, ACTION_LOCK)
+        .add_attribute(ATTR_SENDER, sender)
+        .add_attribute("is_locked", "true")
+        .add_attribute("event_type", "lock")
+        .add_attribute("owner", owner)
+        .add_attribute("network", origin.network)
+        .add_attribute("contract", origin.contract)
+        .add_attribute("token_id", origin.token_id)
+    )
+}
+
+fn load_backing_nfts(deps: Deps) -> Result<Vec<NFT>, ContractError> {
+    NFT_ITEMS.may_load(deps.storage)?
+        .filter(|nfts| !nfts.is_empty())
+        .ok_or(ContractError::NoNftBacking {})
+}
+
+/// `allowed_networks` lets an eip155 nft match on a network it was bridged to;
+/// with none configured the nft's own network must match exactly
+fn is_backing_nft(
+    nft: &NFT,
+    allowed_networks: &[String],
+    network: &str,
+    contract: &str,
+    token_id: &str,
+) -> bool {
+    let network_matches = nft.network == network
+        || (nft.network.starts_with("eip155:") && allowed_networks.iter().any(|n| n == network));
+    network_matches && nft.address == contract && nft.id.to_string() == token_id
+}
+
+fn load_allowed_networks(deps: Deps) -> StdResult<Vec<String>> {
+    // ownables instantiated before allowed networks existed have none
+    Ok(ALLOWED_NETWORKS.may_load(deps.storage)?.unwrap_or_default())
+}
+
+fn ensure_bridge_enabled(deps: Deps) -> Result<(), ContractError> {
+    // ownables instantiated before `bridgeable` existed have no config
+    let bridgeable = CONFIG.may_load(deps.storage)?
+        .flatten()
+        .is_none_or(|config| config.bridgeable);
+    if !bridgeable {
+        return Err(ContractError::NotBridgeable {});
+    }
+    Ok(())
+}
+
+/// Transfers are blocked while a lease is active
+fn ensure_not_leased(deps: Deps, env: &Env) -> Result<(), ContractError> {
+    if active_lease(deps, env)?.is_some() {
+        return Err(ContractError::Leased {});
+    }
+    Ok(())
+}
+
+fn save_lock_info(
+    storage: &mut dyn Storage,
+    env: &Env,
+    locked_by: &Addr,
+    event_network: String,
+    kind: LockKind,
+) -> StdResult<()> {
+    LOCK_INFO.save(storage, &Some(LockInfo {
+        locked_by: locked_by.clone(),
+        at_height: env.block.height,
+        event_network,
+        kind,
+    }))
+}
+
+/// The configured `transfer_locked_until`, while it is still in the future
+fn active_transfer_lock(deps: Deps, env: &Env) -> StdResult<Option<Timestamp>> {
+    let locked_until = CONFIG.load(deps.storage)?.and_then(|config| config.transfer_locked_until);
+    Ok(locked_until.filter(|until| env.block.time < *until))
+}
+
+fn ensure_transfers_open(deps: Deps, env: &Env) -> Result<(), ContractError> {
+    if let Some(until) = active_transfer_lock(deps, env)? {
+        return Err(ContractError::CustomError {
+            val: format!("Transfers are locked until {}", until),
+        });
+    }
+    Ok(())
+}
+
+fn ensure_valid_royalty(royalty_bps: u16) -> Result<(), ContractError> {
+    if royalty_bps > MAX_ROYALTY_BPS {
+        return Err(ContractError::InvalidRoyalty { bps: royalty_bps, max: MAX_ROYALTY_BPS });
+    }
+    Ok(())
+}
+
+fn ensure_valid_denom(denom: &str) -> Result<(), ContractError> {
+    if denom.trim().is_empty() {
+        return Err(ContractError::Validation {
+            field: "accepted_denom".to_string(),
+            reason: "must not be empty".to_string(),
+        });
+    }
+    Ok(())
+}
+
+fn active_lease(deps: Deps, env: &Env) -> StdResult<Option<Lease>> {
+    Ok(LEASE.may_load(deps.storage)?
+        .flatten()
+        .filter(|lease| env.block.time < lease.until))
+}
+
+/// Actions whose allowed callers are set by a configured `AuthPolicy`
+#[derive(Clone, Copy)]
+enum AuthAction {
+    Transfer,
+    Lock,
+}
+
+/// Check the sender against the policy configured for `action` and return
+/// the sender's LTO address. The owner is always allowed.
+fn authorize(action: AuthAction, deps: Deps, info: &MessageInfo) -> Result<Addr, ContractError> {
+    let network_id = NETWORK_ID.load(deps.storage)?;
+    let sender = address_lto(network_id as char, info.sender.to_string())?;
+    let ownership = OWNABLE_INFO.load(deps.storage)?;
+    if sender == ownership.owner {
+        return Ok(sender);
+    }
+
+    let policy = match (action, CONFIG.load(deps.storage)?) {
+        (AuthAction::Transfer, Some(config)) => config.transfer_policy,
+        (AuthAction::Lock, Some(config)) => config.lock_policy,
+        (_, None) => AuthPolicy::OwnerOnly,
+    };
+    let allowed = match policy {
+        AuthPolicy::OwnerOnly => false,
+        AuthPolicy::OwnerOrApproved =>
+            APPROVAL.may_load(deps.storage)?.flatten().as_ref() == Some(&sender),
+        AuthPolicy::OwnerOrIssuer => sender == ownership.issuer,
+    };
+    if !allowed {
+        return Err(ContractError::Unauthorized {
+            val: "Unauthorized".into(),
+        });
+    }
+    Ok(sender)
+}
+
+/// In shares mode the single owner gives way to the share holders: only
+/// `TransferShares` moves the ownable, and the owner actions that would act
+/// for all holders (transfer, lock, lease, approve, burn, release, merge)
+/// are rejected
+fn ensure_single_owner(deps: Deps) -> Result<(), ContractError> {
+    if TOTAL_SHARES.may_load(deps.storage)?.is_some() {
+        return Err(ContractError::SharedOwnership {});
+    }
+    Ok(())
+}
+
+fn save_shares(
+    deps: DepsMut,
+    total_shares: u64,
+    shares: &[ShareAllocation],
+) -> Result<(), ContractError> {
+    let mut allocated: u64 = 0;
+    for allocation in shares {
+        let holder = deps.api.addr_validate(&allocation.holder)?;
+        if allocation.amount == 0 || SHARES.has(deps.storage, &holder) {
+            return Err(ContractError::Validation {
+                field: "shares".to_string(),
+                reason: format!("{} needs one allocation above zero", holder),
+            });
+        }
+        allocated = allocated.checked_add(allocation.amount).ok_or(ContractError::Validation {
+            field: "shares".to_string(),
+            reason: "allocations overflow".to_string(),
+        })?;
+        SHARES.save(deps.storage, &holder, &allocation.amount)?;
+    }
+    if allocated != total_shares {
+        return Err(ContractError::Validation {
+            field: "shares".to_string(),
+            reason: format!("allocate {} shares, not {}", total_shares, allocated),
+        });
+    }
+    TOTAL_SHARES.save(deps.storage, &total_shares)?;
+    Ok(())
+}
+
+pub fn try_merge(
+    info: MessageInfo,
+    deps: DepsMut,
+    env: Env,
+    other: String,
+    proof: Binary,
+) -> Result<Response, ContractError> {
+    // only the owner of both ownables can merge them
+    let network_id = NETWORK_ID.load(deps.storage)?;
+    let sender = address_lto(network_id as char, info.sender.to_string())?;
+    let owner = OWNABLE_INFO.load(deps.storage)?.owner;
+    if sender != owner {
+        return Err(ContractError::Unauthorized { val: "Unauthorized".into() });
+    }
+    ensure_unlocked(deps.as_ref())?;
+    ensure_not_leased(deps.as_ref(), &env)?;
+    ensure_single_owner(deps.as_ref())?;
+
+    let other = deps.api.addr_validate(&other)?;
+    if other == env.contract.address {
+        return Err(ContractError::CustomError { val: "Cannot merge an ownable into itself".into() });
+    }
+    let mut merged_from = MERGED_FROM.may_load(deps.storage)?.unwrap_or_default();
+    if merged_from.contains(&other) {
+        return Err(ContractError::AlreadyMerged { other: other.to_string() });
+    }
+    // dropping old entries would let them be merged again, so the list is capped
+    if merged_from.len() >= MAX_MERGED {
+        return Err(ContractError::Validation {
+            field: "other".to_string(),
+            reason: format!("at most {} ownables can be merged", MAX_MERGED),
+        });
+    }
+
+    // `other` can't be queried from here, so its signed burn event is what
+    // proves the owner held it and gave it up; a registered burn is only
+    // merged once
+    if !MERGE_CANDIDATES.has(deps.storage, &other) {
+        return Err(ContractError::Unauthorized {
+            val: format!("No burn of {} has been registered for merging", other),
+        });
+    }
+
+    // the owner's key signs which ownable is fused into which
+    let pubkey = bs58::decode(info.sender.as_str())
+        .into_vec()
+        .map_err(|_| ContractError::CustomError { val: "Sender is not a public key".into() })?;
+    let message = format!("merge:{}:{}", other, env.contract.address);
+    let verified = verify_owner_signature(
+        deps.as_ref(),
+        &owner,
+        network_id,
+        message.as_bytes(),
+        &proof,
+        &pubkey,
+    )?;
+    if !verified {
+        return Err(ContractError::Unauthorized { val: "Invalid merge proof".into() });
+    }
+
+    MERGE_CANDIDATES.remove(deps.storage, &other);
+    merged_from.push(other.clone());
+    MERGED_FROM.save(deps.storage, &merged_from)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "try_merge")
+        .add_attribute(ATTR_SENDER, sender)
+        .add_attribute("merged_from", other)
+    )
+}
+
+pub fn try_update_config(
+    info: MessageInfo,
+    deps: DepsMut,
+    notify_contract: Option<String>,
+    royalty_bps: Option<u16>,
+    accepted_denom: Option<String>,
+    transfer_locked_until: Option<Timestamp>,
+    admin: Option<String>,
+) -> Result<Response, ContractError> {
+    // only the config admin can update it
+    let network_id = NETWORK_ID.load(deps.storage)?;
+    let sender = address_lto(network_id as char, info.sender.to_string())?;
+    let mut config = match CONFIG.load(deps.storage)? {
+        Some(config) if config.admin.as_ref() == Some(&sender) => config,
+        _ => return Err(ContractError::Unauthorized {
+            val: "Only the config admin can update the config".into(),
+        }),
+    };
+
+    if let Some(addr) = notify_contract {
+        config.notify_contract = Some(deps.api.addr_validate(&addr)?);
+    }
+    if let Some(royalty_bps) = royalty_bps {
+        ensure_valid_royalty(royalty_bps)?;
+        config.royalty_bps = royalty_bps;
+    }
+    if let Some(denom) = accepted_denom {
+        ensure_valid_denom(&denom)?;
+        config.accepted_denom = denom;
+    }
+    if let Some(until) = transfer_locked_until {
+        config.transfer_locked_until = Some(until);
+    }
+    if let Some(addr) = admin {
+        config.admin = Some(deps.api.addr_validate(&addr)?);
+    }
+    CONFIG.save(deps.storage, &Some(config))?;
+
+    Ok(Response::new()
+        .add_attribute("method", "try_update_config")
+        .add_attribute(ATTR_SENDER, sender)
+    )
+}
+
+pub fn try_transfer_shares(
+    info: MessageInfo,
+    deps: DepsMut,
+    recipient: String,
+    amount: u64,
+) -> Result<Response, ContractError> {
+    if TOTAL_SHARES.may_load(deps.storage)?.is_none() {
+        return Err(ContractError::CustomError {
+            val: "Ownable is not owned in shares".to_string(),
+        });
+    }
+    ensure_unlocked(deps.as_ref())?;
+    let recipient = deps.api.addr_validate(&recipient)?;
+    let network_id = NETWORK_ID.load(deps.storage)?;
+    let sender = address_lto(network_id as char, info.sender.to_string())?;
+    if amount == 0 {
+        return Err(ContractError::Validation {
+            field: "amount".to_string(),
+            reason: "must be greater than zero".to_string(),
+        });
+    }
+    if sender == recipient {
+        return Err(ContractError::CustomError {
+            val: "Unable to transfer: Recipient address is the sender".to_string(),
+        });
+    }
+
+    let balance = SHARES.may_load(deps.storage, &sender)?.unwrap_or_default();
+    let remaining = balance.checked_sub(amount).ok_or(ContractError::Validation {
+        field: "amount".to_string(),
+        reason: format!("exceeds the {} shares held", balance),
+    })?;
+    if remaining == 0 {
+        SHARES.remove(deps.storage, &sender);
+    } else {
+        SHARES.save(deps.storage, &sender, &remaining)?;
+    }
+    SHARES.update(deps.storage, &recipient, |held| {
+        held.unwrap_or_default().checked_add(amount).ok_or(ContractError::Validation {
+            field: "amount".to_string(),
+            reason: "overflows the recipient's shares".to_string(),
+        })
+    })?;
+
+    Ok(Response::new()
+        .add_attribute("method", "try_transfer_shares")
+        .add_attribute(ATTR_ACTION, ACTION_TRANSFER)
+        .add_attribute(ATTR_SENDER, sender)
+        .add_attribute("recipient", recipient)
+        .add_attribute("amount", amount.to_string())
+    )
+}
+
+/// Every owner action that mutates the ownable must call this first
+fn ensure_unlocked(deps: Deps) -> Result<(), ContractError> {
+    if LOCKED.load(deps.storage)? {
+        return Err(ContractError::Locked {});
+    }
+    Ok(())
+}
+
+/// A native ownable's NFT was burned, so it can no longer be locked or bridged
+fn ensure_bridgeable(deps: Deps) -> Result<(), ContractError> {
+    ensure_bridge_enabled(deps)?;
+    if NATIVE.load(deps.storage)? {
+        return Err(ContractError::LockError {
+            val: "Ownable is native and can no longer be bridged".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Owner changes all go through `OWNABLE_INFO`. A locked ownable can only
+/// change hands through a bridge release, so if a release and a transfer land
+/// in the same block the transfer fails with `Locked` and the release wins.
+pub fn try_transfer(
+    info: MessageInfo,
+    deps: DepsMut,
+    env: Env,
+    recipient: String,
+    memo: Option<String>,
+    sale_price: Option<Coin>,
+) -> Result<Response, ContractError> {
+    ensure_unlocked(deps.as_ref())?;
+    ensure_not_leased(deps.as_ref(), &env)?;
+    ensure_single_owner(deps.as_ref())?;
+    ensure_transfers_open(deps.as_ref(), &env)?;
+    let recipient = deps.api.addr_validate(&recipient)?;
+    if let Some(memo) = &memo {
+        if memo.len() > MAX_MEMO_LENGTH {
+            return Err(ContractError::Validation {
+                field: "memo".to_string(),
+                reason: format!("exceeds {} characters", MAX_MEMO_LENGTH),
+            });
+        }
+    }
+    let address = authorize(AuthAction::Transfer, deps.as_ref(), &info)?;
+    let sale = settle_sale(deps.as_ref(), &info, sale_price)?;
+
+    let seller = OWNABLE_INFO.load(deps.storage)?.owner;
+    let ownership = OWNABLE_INFO.update(deps.storage, |mut config| -> Result<_, ContractError> {
+        if config.owner == recipient {
+            return Err(ContractError::CustomError {
+                val: "Unable to transfer: Recipient address is current owner".to_string(),
+            });
+        }
+        config.owner = recipient.clone();
+        Ok(config)
+    })?;
+    record_owner(deps.storage, &env, &recipient, memo.clone())?;
+    OWNER_PUBLIC_KEY.save(deps.storage, &None)?;
+    APPROVAL.save(deps.storage, &None)?;
+
+    let mut response = Response::new()
+        .add_attribute("method", "try_transfer")
+        .add_attribute(ATTR_ACTION, ACTION_TRANSFER)
+        .add_attribute(ATTR_SENDER, address)
+        .add_attribute("new_owner", recipient.to_string());
+    if let Some(memo) = memo {
+        response = response.add_attribute("memo", memo);
+    }
+    if let Some(sale) = sale {
+        response = response
+            .add_attribute("sale_price", sale.price.to_string())
+            .add_attribute("royalty", sale.royalty.to_string())
+            .add_event(Event::new("royalty")
+                .add_attribute("recipient", ownership.issuer.to_string())
+                .add_attribute("amount", sale.royalty.to_string()));
+        // the payment arrived with this message, so only it is passed on
+        for (to_address, amount) in [(ownership.issuer, sale.royalty), (seller, sale.proceeds)] {
+            if !amount.amount.is_zero() {
+                response = response.add_message(BankMsg::Send {
+                    to_address: to_address.to_string(),
+                    amount: vec![amount],
+                });
+            }
+        }
+    }
+    if let Some(notification) = owner_changed_notification(deps.as_ref(), &recipient)? {
+        response = response.add_message(notification);
+    }
+    Ok(response)
+}
+
+pub fn try_transfer_and_approve(
+    info: MessageInfo,
+    mut deps: DepsMut,
+    env: Env,
+    recipient: String,
+    spender: String,
+) -> Result<Response, ContractError> {
+    // only the owner itself; an approved spender could otherwise transfer
+    // and leave an approval of its choosing with the recipient
+    let ownership = OWNABLE_INFO.load(deps.storage)?;
+    let network_id = NETWORK_ID.load(deps.storage)?;
+    let sender = address_lto(network_id as char, info.sender.to_string())?;
+    if sender != ownership.owner {
+        return Err(ContractError::Unauthorized {
+            val: "Unauthorized".into(),
+        });
+    }
+    // the previous owner must not be able to take the ownable back
+    let spender = deps.api.addr_validate(&spender)?;
+    if spender == sender || spender.as_str() == recipient {
+        return Err(ContractError::Validation {
+            field: "spender".to_string(),
+            reason: "must be neither the sender nor the recipient".to_string(),
+        });
+    }
+
+    let response = try_transfer(info, deps.branch(), env, recipient, None, None)?;
+    APPROVAL.save(deps.storage, &Some(spender.clone()))?;
+
+    Ok(response.add_attribute("spender", spender))
+}
+
+pub fn try_approve(
+    info: MessageInfo,
+    deps: DepsMut,
+    spender: String,
+) -> Result<Response, ContractError> {
+    // only ownable owner can approve a spender
+    let ownership = OWNABLE_INFO.load(deps.storage)?;
+    let network_id = NETWORK_ID.load(deps.storage)?;
+    let sender = address_lto(network_id as char, info.sender.to_string())?;
+    if sender != ownership.owner {
+        return Err(ContractError::Unauthorized {
+            val: "Unauthorized".into(),
+        });
+    }
+    ensure_unlocked(deps.as_ref())?;
+    ensure_single_owner(deps.as_ref())?;
+    let spender = deps.api.addr_validate(&spender)?;
+
+    APPROVAL.save(deps.storage, &Some(spender.clone()))?;
+
+    Ok(Response::new()
+        .add_attribute("method", "try_approve")
+        .add_attribute(ATTR_SENDER, sender)
+        .add_attribute("spender", spender)
+    )
+}
+
+pub fn try_lease(
+    info: MessageInfo,
+    deps: DepsMut,
+    env: Env,
+    to: String,
+    until: Timestamp,
+) -> Result<Response, ContractError> {
+    // only ownable owner can lease it
+    let ownership = OWNABLE_INFO.load(deps.storage)?;
+    let network_id = NETWORK_ID.load(deps.storage)?;
+    let sender = address_lto(network_id as char, info.sender.to_string())?;
+    if sender != ownership.owner {
+        return Err(ContractError::Unauthorized {
+            val: "Unauthorized".into(),
+        });
+    }
+    ensure_unlocked(deps.as_ref())?;
+    ensure_not_leased(deps.as_ref(), &env)?;
+    ensure_single_owner(deps.as_ref())?;
+    if until <= env.block.time {
+        return Err(ContractError::Validation {
+            field: "until".to_string(),
+            reason: "must be in the future".to_string(),
+        });
+    }
+    let holder = deps.api.addr_validate(&to)?;
+
+    LEASE.save(deps.storage, &Some(Lease { holder: holder.clone(), until }))?;
+
+    Ok(Response::new()
+        .add_attribute("method", "try_lease")
+        .add_attribute(ATTR_SENDER, sender)
+        .add_attribute("holder", holder)
+        .add_attribute("until", until.seconds().to_string())
+    )
+}
+
+pub fn try_revoke_approval(info: MessageInfo, deps: DepsMut) -> Result<Response, ContractError> {
+    // only ownable owner can revoke an approval
+    let ownership = OWNABLE_INFO.load(deps.storage)?;
+    let network_id = NETWORK_ID.load(deps.storage)?;
+    let sender = address_lto(network_id as char, info.sender.to_string())?;
+    if sender != ownership.owner {
+        return Err(ContractError::Unauthorized {
+            val: "Unauthorized".into(),
+        });
+    }
+
+    APPROVAL.save(deps.storage, &None)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "try_revoke_approval")
+        .add_attribute(ATTR_SENDER, sender)
+    )
+}
+
+pub fn try_transfer_from(
+    info: MessageInfo,
+    deps: DepsMut,
+    env: Env,
+    recipient: String,
+) -> Result<Response, ContractError> {
+    ensure_unlocked(deps.as_ref())?;
+    ensure_not_leased(deps.as_ref(), &env)?;
+    ensure_single_owner(deps.as_ref())?;
+    ensure_transfers_open(deps.as_ref(), &env)?;
+    let recipient = deps.api.addr_validate(&recipient)?;
+    let network_id = NETWORK_ID.load(deps.storage)?;
+    let spender = address_lto(network_id as char, info.sender.to_string())?;
+
+    // the approval is used up by a successful transfer
+    let approved = APPROVAL.may_load(deps.storage)?.flatten();
+    if approved.as_ref() != Some(&spender) {
+        return Err(ContractError::Unauthorized {
+            val: "Sender is not the approved spender".to_string(),
+        });
+    }
+
+    let mut ownership = OWNABLE_INFO.load(deps.storage)?;
+    if ownership.owner == recipient {
+        return Err(ContractError::CustomError {
+            val: "Unable to transfer: Recipient address is current owner".to_string(),
+        });
+    }
+    ownership.owner = recipient.clone();
+    OWNABLE_INFO.save(deps.storage, &ownership)?;
+    APPROVAL.save(deps.storage, &None)?;
+    OWNER_PUBLIC_KEY.save(deps.storage, &None)?;
+    record_owner(deps.storage, &env, &recipient, None)?;
+
+    let mut response = Response::new()
+        .add_attribute("method", "try_transfer_from")
+        .add_attribute(ATTR_ACTION, ACTION_TRANSFER)
+        .add_attribute(ATTR_SENDER, spender.clone())
+        .add_attribute("spender", spender)
+        .add_attribute("new_owner", recipient.to_string());
+    if let Some(notification) = owner_changed_notification(deps.as_ref(), &recipient)? {
+        response = response.add_message(notification);
+    }
+    Ok(response)
+}
+
+/// A priced transfer's payment, split between the issuer and the seller
+struct Sale {
+    price: Coin,
+    royalty: Coin,
+    proceeds: Coin,
+}
+
+/// Check `sale_price` against the accepted denom and the funds sent with the
+/// transfer, and split it at the configured `royalty_bps`. The royalty is a
+/// cut of the declared price, so a transfer without a price, like one before
+/// royalties existed, or with a zero price pays nothing.
+fn settle_sale(
+    deps: Deps,
+    info: &MessageInfo,
+    sale_price: Option<Coin>,
+) -> Result<Option<Sale>, ContractError> {
+    let price = match sale_price {
+        Some(price) => price,
+        None => return Ok(None),
+    };
+    let (royalty_bps, accepted_denom) = match CONFIG.load(deps.storage)? {
+        Some(config) => (config.royalty_bps, config.accepted_denom),
+        None => (0, DEFAULT_DENOM.to_string()),
+    };
+    if price.denom != accepted_denom {
+        return Err(ContractError::InvalidDenom { expected: accepted_denom, got: price.denom });
+    }
+    // a zero coin can't be sent, so a zero price comes without funds
+    if price.amount.is_zero() {
+        ensure_funds(info, &[])?;
+    } else {
+        ensure_funds(info, std::slice::from_ref(&price))?;
+    }
+
+    let royalty = price.amount.multiply_ratio(royalty_bps, MAX_ROYALTY_BPS);
+    Ok(Some(Sale {
+        royalty: Coin { denom: price.denom.clone(), amount: royalty },
+        proceeds: Coin { denom: price.denom.clone(), amount: price.amount - royalty },
+        price,
+    }))
+}
+
+/// Funds sent with a message must be exactly what it pays, so nothing is
+/// left behind in the contract
+fn ensure_funds(info: &MessageInfo, expected: &[Coin]) -> Result<(), ContractError> {
+    if info.funds != expected {
+        return Err(ContractError::Validation {
+            field: "sale_price".to_string(),
+            reason: format!(
+                "funds sent ({}) must match the sale price ({})",
+                format_coins(&info.funds),
+                format_coins(expected),
+            ),
+        });
+    }
+    Ok(())
+}
+
+fn format_coins(coins: &[Coin]) -> String {
+    if coins.is_empty() {
+        return "none".to_string();
+    }
+    coins.iter().map(Coin::to_string).collect::<Vec<_>>().join(", ")
+}
+
+/// Notify the configured contract of a new owner. Delivery is fire and
+/// forget: the wallet runtime hands the message to its host without a reply,
+/// so a registry that fails to process it never reverts the ownership
+/// change, and it is not retried.
+fn owner_changed_notification(deps: Deps, owner: &Addr) -> Result<Option<WasmMsg>, ContractError> {
+    let notify_contract = match CONFIG.load(deps.storage)? {
+        Some(Config { notify_contract: Some(addr), .. }) => addr,
+        _ => return Ok(None),
+    };
+
+    Ok(Some(WasmMsg::Execute {
+        contract_addr: notify_contract.to_string(),
+        msg: to_json_binary(&NotifyMsg::OwnerChanged {
+            ownable_id: OWNABLE_ID.load(deps.storage)?,
+            owner: owner.clone(),
+        })?,
+        funds: vec![],
+    }))
+}
+
+/// Upgrade the stored state to this version. Migrating to the version
+/// already stored changes nothing.
+#[cfg_attr(feature = "entry-points", entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    let stored = get_contract_version(deps.storage)?;
+    if stored.contract != CONTRACT_NAME {
+        return Err(ContractError::MigrationError {
+            reason: format!("stored contract is {}, not {}", stored.contract, CONTRACT_NAME),
+        });
+    }
+
+    let parse = |version: &str| Version::parse(version).map_err(|error| ContractError::MigrationError {
+        reason: format!("invalid version {}: {}", version, error),
+    });
+    let from = parse(&stored.version)?;
+    let to = parse(CONTRACT_VERSION)?;
+    if to < from {
+        return Err(ContractError::InvalidMigration {
+            from: stored.version,
+            to: CONTRACT_VERSION.to_string(),
+        });
+    }
+
+    let response = Response::new()
+        .add_attribute("method", "migrate")
+        .add_attribute("from_version", stored.version)
+        .add_attribute("to_version", CONTRACT_VERSION);
+    if to == from {
+        return Ok(response.add_attribute("migrated", "false"));
+    }
+
+    upgrade_layout(deps.storage, msg)?;
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(response.add_attribute("migrated", "true"))
+}
+
+/// Fill in the items an older layout lacks with the values instantiate
+/// would have stored, so every `load` finds them
+fn upgrade_layout(storage: &mut dyn Storage, msg: MigrateMsg) -> Result<(), ContractError> {
+    if let Some(nft) = LEGACY_NFT_ITEM.may_load(storage)? {
+        NFT_ITEMS.save(storage, &vec![nft])?;
+        LEGACY_NFT_ITEM.remove(storage);
+    }
+    if OWNABLE_ID.may_load(storage)?.is_none() {
+        let ownable_id = msg.ownable_id.ok_or_else(|| ContractError::MigrationError {
+            reason: "ownable_id is required to upgrade a layout without one".to_string(),
+        })?;
+        OWNABLE_ID.save(storage, &ownable_id)?;
+    }
+    if CREATOR.may_load(storage)?.is_none() {
+        CREATOR.save(storage, &None)?;
+    }
+    if NATIVE.may_load(storage)?.is_none() {
+        NATIVE.save(storage, &false)?;
+    }
+    if BURNED.may_load(storage)?.is_none() {
+        BURNED.save(storage, &false)?;
+    }
+    Ok(())
+}
+
+/// An event attribute the relayer must supply; a missing attribute and an
+/// empty one are reported separately, both naming the key
+fn required_attr(event: &ExternalEventMsg, key: &str) -> Result<String, ContractError> {
+    let value = event.attributes.get(key)
+        .ok_or_else(|| ContractError::InvalidExternalEventArgs { key: key.to_string() })?;
+    if value.is_empty() {
+        return Err(ContractError::Validation {
+            field: key.to_string(),
+            reason: "must not be empty".to_string(),
+        });
+    }
+    Ok(value.clone())
+}
+
+/// Identify an event by its source chain and the relayer-supplied
+/// `event_id` attribute (the origin transaction hash and log index)
+fn processed_event_key(event: &ExternalEventMsg) -> Result<String, ContractError> {
+    let event_id = required_attr(event, "event_id")?;
+    let network = event.network.as_deref().unwrap_or_default();
+    Ok(format!("{}/{}", network, event_id))
+}
+
+pub fn register_external_event(
+    info: MessageInfo,
+    mut deps: DepsMut,
+    env: Env,
+    event: ExternalEventMsg,
+    ownable_id: String,
+) -> Result<Response, ContractError> {
+    // an event is bound to exactly one ownable, so it can't be replayed
+    // against another ownable backed by the same NFT
+    let stored_id = OWNABLE_ID.load(deps.storage)?;
+    let event_ownable_id = required_attr(&event, "ownable_id")?;
+    if event_ownable_id != stored_id || ownable_id != stored_id {
+        return Err(ContractError::Unauthorized {
+            val: "Event is bound to another ownable".to_string(),
+        });
+    }
+
+    // a flaky relay may deliver the same event twice
+    let event_id = processed_event_key(&event)?;
+    if PROCESSED_EVENTS.has(deps.storage, event_id.clone()) {
+        return Err(ContractError::DuplicateEvent { id: event_id });
+    }
+
+    let mut response = Response::new()
+        .add_attribute("method", "register_external_event")
+        .add_attribute("ownable_id", stored_id)
+        .add_attribute("event_id", event_id.clone());
+
+    let record = EventRecord {
+        event_type: event.event_type.clone(),
+        network: event.network.clone(),
+        token_id: event.attributes.get("token_id").cloned(),
+        at: env.block.time,
+    };
+
+    trace!(deps.api, "register_external_event {} on {:?}", event.event_type, event.network);
+    let event_response = match event.event_type.as_str() {
+        // the NFT was locked for, or unlocked on, its origin chain; either way
+        // the ownable goes back to the NFT owner
+        "lock" | "release" => {
+            ensure_bridge_enabled(deps.as_ref())?;
+            response = response.add_attribute("event_type", event.event_type.clone());
+            try_register_lock(
+                info,
+                deps.branch(),
+                env,
+                event,
+            )?
+        },
+        "burn" => {
+            ensure_bridge_enabled(deps.as_ref())?;
+            response = response.add_attribute("event_type", "burn");
+            try_register_burn(
+                info,
+                deps.branch(),
+                env,
+                event,
+            )?
+        },
+        // another ownable of the same owner was burned to merge into this one
+        "ownable_burn" => {
+            response = response.add_attribute("event_type", "ownable_burn");
+            try_register_ownable_burn(
+                info,
+                deps.branch(),
+                env,
+                event,
+            )?
+        },
+        _ => {
+            trace!(deps.api, "rejected unknown event type {}", event.event_type);
+            return Err(ContractError::MatchEventError { val: event.event_type });
+        },
+    };
+    mark_processed(deps.storage, event_id)?;
+    log_external_event(deps, record)?;
+
+    Ok(response
+        .add_attributes(event_response.attributes)
+        .add_submessages(event_response.messages))
+}
+
+/// The key after the highest key in `map`, or 0 for an empty map
+fn next_index<T>(storage: &dyn Storage, map: &Map<'_, u64, T>) -> Result<u64, ContractError>
+where
+    T: Serialize + DeserializeOwned,
+{
+    let last = map.keys(storage, None, None, Order::Descending).next().transpose()?;
+    match last {
+        None => Ok(0),
+        Some(last) => last.checked_add(1).ok_or(ContractError::CustomError {
+            val: "Index overflow".to_string(),
+        }),
+    }
+}
+
+fn mark_processed(storage: &mut dyn Storage, event_id: String) -> Result<(), ContractError> {
+    let index = next_index(storage, &PROCESSED_EVENT_ORDER)?;
+    if let Some(expired) = index.checked_sub(MAX_PROCESSED_EVENTS) {
+        if let Some(expired_id) = PROCESSED_EVENT_ORDER.may_load(storage, expired)? {
+            PROCESSED_EVENTS.remove(storage, expired_id);
+            PROCESSED_EVENT_ORDER.remove(storage, expired);
+        }
+    }
+    PROCESSED_EVENT_ORDER.save(storage, index, &event_id)?;
+    PROCESSED_EVENTS.save(storage, event_id, &true)?;
+    Ok(())
+}
+
+fn record_owner(
+    storage: &mut dyn Storage,
+    env: &Env,
+    owner: &Addr,
+    memo: Option<String>,
+) -> Result<(), ContractError> {
+    let index = next_index(storage, &OWNERSHIP_HISTORY)?;
+    // keep the indexes counting up so pagination cursors stay valid
+    if let Some(expired) = index.checked_sub(MAX_OWNERSHIP_HISTORY) {
+        OWNERSHIP_HISTORY.remove(storage, expired);
+    }
+    OWNERSHIP_HISTORY.save(storage, index, &OwnershipRecord {
+        owner: owner.clone(),
+        height: env.block.height,
+        timestamp: env.block.time,
+        memo,
+    })?;
+    Ok(())
+}
+
+fn log_external_event(deps: DepsMut, record: EventRecord) -> Result<(), ContractError> {
+    let mut log = EVENT_LOG.may_load(deps.storage)?.unwrap_or_default();
+    log.push(record);
+    if log.len() > MAX_EVENT_LOG_LENGTH {
+        log.drain(..log.len() - MAX_EVENT_LOG_LENGTH);
+    }
+    EVENT_LOG.save(deps.storage, &log)?;
+    Ok(())
+}
+
+pub fn try_update_metadata(
+    info: MessageInfo,
+    deps: DepsMut,
+    description: Option<String>,
+    external_url: Option<String>,
+    image: Option<String>,
+    animation_url: Option<String>,
+) -> Result<Response, ContractError> {
+    // only ownable issuer can update metadata
+    let ownership = OWNABLE_INFO.load(deps.storage)?;
+    let network_id = NETWORK_ID.load(deps.storage)?;
+    let sender = address_lto(network_id as char, info.sender.to_string())?;
+    if sender != ownership.issuer {
+        return Err(ContractError::Unauthorized {
+            val: "Only the issuer can update metadata".into(),
+        });
+    }
+    ensure_unlocked(deps.as_ref())?;
+
+    METADATA.update(deps.storage, |mut metadata| -> Result<_, ContractError> {
+        metadata.description = description.or(metadata.description);
+        metadata.external_url = external_url.or(metadata.external_url);
+        metadata.image = image.or(metadata.image);
+        metadata.animation_url = animation_url.or(metadata.animation_url);
+        Ok(metadata)
+    })?;
+
+    Ok(Response::new()
+        .add_attribute("method", "update_metadata")
+        .add_attribute(ATTR_SENDER, sender)
+    )
+}
+
+/// The response carries the `burn_message` the owner signs on this ownable's
+/// event chain, so the burn can be proven to the ownable it is merged into
+pub fn try_burn(info: MessageInfo, deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    // only ownable owner can burn it
+    let ownership = OWNABLE_INFO.load(deps.storage)?;
+    let network_id = NETWORK_ID.load(deps.storage)?;
+    let sender = address_lto(network_id as char, info.sender.to_string())?;
+    if sender != ownership.owner {
+        return Err(ContractError::Unauthorized {
+            val: "Unauthorized".into(),
+        });
+    }
+    ensure_unlocked(deps.as_ref())?;
+    ensure_single_owner(deps.as_ref())?;
+
+    BURNED.save(deps.storage, &true)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "try_burn")
+        .add_attribute(ATTR_SENDER, sender.clone())
+        .add_attribute("is_burned", "true")
+        .add_attribute("burn_message", burn_message(&env.contract.address, &sender))
+    )
+}
+
+/// What the owner signs when burning `ownable`, binding the burn to the
+/// burned ownable and to who held it
+pub fn burn_message(ownable: &Addr, owner: &Addr) -> String {
+    format!("burn:{}:{}", ownable, owner)
+}
+
+/// Exhausting the uses only stops `Consume`; the ownable can still be
+/// transferred.
+#[cfg(feature = "consumable")]
+pub fn try_consume(
+    info: MessageInfo,
+    deps: DepsMut,
+    env: Env,
+    amount: u32,
+) -> Result<Response, ContractError> {
+    // only ownable owner can consume it
+    let ownership = OWNABLE_INFO.load(deps.storage)?;
+    let network_id = NETWORK_ID.load(deps.storage)?;
+    let sender = address_lto(network_id as char, info.sender.to_string())?;
+    if sender != ownership.owner {
+        return Err(ContractError::Unauthorized {
+            val: "Unauthorized".into(),
+        });
+    }
+    ensure_unlocked(deps.as_ref())?;
+    ensure_not_leased(deps.as_ref(), &env)?;
+    if amount == 0 {
+        return Err(ContractError::Validation {
+            field: "amount".to_string(),
+            reason: "must be greater than zero".to_string(),
+        });
+    }
+
+    let remaining = USES_REMAINING.load(deps.storage)?;
+    let remaining = remaining.checked_sub(amount)
+        .ok_or(ContractError::InsufficientUses { requested: amount, remaining })?;
+    USES_REMAINING.save(deps.storage, &remaining)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "try_consume")
+        .add_attribute(ATTR_SENDER, sender)
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("uses_remaining", remaining.to_string())
+    )
+}
+
+pub fn try_unlock(info: MessageInfo, deps: DepsMut) -> Result<Response, ContractError> {
+    // only ownable owner can unlock it
+    let ownership = OWNABLE_INFO.load(deps.storage)?;
+    let network_id = NETWORK_ID.load(deps.storage)?;
+    let sender = address_lto(network_id as char, info.sender.to_string())?;
+    if sender != ownership.owner {
+        return Err(ContractError::Unauthorized {
+            val: "Unauthorized".into(),
+        });
+    }
+    if !LOCKED.load(deps.storage)? {
+        return Err(ContractError::LockError { val: "Not locked".to_string() });
+    }
+
+    ensure_owner_lock(deps.as_ref(), "unlocked")?;
+
+    LOCKED.save(deps.storage, &false)?;
+    LOCK_INFO.save(deps.storage, &None)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "try_unlock")
+        .add_attribute(ATTR_ACTION, ACTION_UNLOCK)
+        .add_attribute(ATTR_SENDER, sender)
+        .add_attribute("is_locked", "false")
+    )
+}
+
+pub fn try_owner_release(
+    info: MessageInfo,
+    deps: DepsMut,
+    env: Env,
+    to: String,
+) -> Result<Response, ContractError> {
+    // only ownable owner can release it
+    let ownership = OWNABLE_INFO.load(deps.storage)?;
+    let network_id = NETWORK_ID.load(deps.storage)?;
+    let sender = address_lto(network_id as char, info.sender.to_string())?;
+    if sender != ownership.owner {
+        return Err(ContractError::Unauthorized {
+            val: "Unauthorized".into(),
+        });
+    }
+    if !LOCKED.load(deps.storage)? {
+        return Err(ContractError::LockError { val: "Not locked".to_string() });
+    }
+    ensure_single_owner(deps.as_ref())?;
+    ensure_owner_lock(deps.as_ref(), "released")?;
+    // handing the ownable to someone else is a `Transfer`, with its lease,
+    // shares, transfer lock and royalty checks
+    let to = deps.api.addr_validate(&to)?;
+    if to != ownership.owner {
+        return Err(ContractError::Validation {
+            field: "to".to_string(),
+            reason: "must be the current owner; use transfer to hand the ownable to someone else".to_string(),
+        });
+    }
+
+    try_release(deps, env, to)
+}
+
+/// A lock for the NFT chain is undone by the bridge's release event, never by
+/// the owner; locks from before lock info was recorded can't be told apart
+fn ensure_owner_lock(deps: Deps, action: &str) -> Result<(), ContractError> {
+    let owner_locked = LOCK_INFO.may_load(deps.storage)?
+        .flatten()
+        .is_some_and(|lock| lock.kind == LockKind::Owner);
+    if !owner_locked {
+        return Err(ContractError::LockError {
+            val: format!("Only an owner lock can be {}; a bridge lock is released by its bridge event", action),
+        });
+    }
+    Ok(())
+}
+
+/// Hand the ownable to `to` and unlock it; callers check who may release it
+fn try_release(
+    deps: DepsMut,
+    env: Env,
+    to: Addr,
+) -> Result<Response, ContractError> {
+    let mut is_locked = LOCKED.load(deps.storage)?;
+    if !is_locked {
+        trace!(deps.api, "release to {} rejected: not locked", to);
+        return Err(ContractError::LockError { val: "Not locked".to_string() });
+    }
+
+    // transfer ownership and unlock
+    let mut ownership = OWNABLE_INFO.load(deps.storage)?;
+    ownership.owner = to;
+    is_locked = false;
+
+    OWNABLE_INFO.save(deps.storage, &ownership)?;
+    LOCKED.save(deps.storage, &is_locked)?;
+    LOCK_INFO.save(deps.storage, &None)?;
+    record_owner(deps.storage, &env, &ownership.owner, None)?;
+    // an approval from before the ownable was bridged no longer applies
+    APPROVAL.save(deps.storage, &None)?;
+
+    let mut response = Response::new()
+        .add_attribute("method", "try_release")
+        .add_attribute(ATTR_ACTION, ACTION_RELEASE)
+        .add_attribute("is_locked", is_locked.to_string())
+        .add_attribute("owner", ownership.owner.to_string());
+    if let Some(notification) = owner_changed_notification(deps.as_ref(), &ownership.owner)? {
+        response = response.add_message(notification);
+    }
+    Ok(response)
+}
+
+fn try_register_lock(
+    info: MessageInfo,
+    mut deps: DepsMut,
+    env: Env,
+    event: ExternalEventMsg,
+) -> Result<Response, ContractError> {
+    ensure_bridgeable(deps.as_ref())?;
+    let owner = verify_nft_event(&info, deps.as_ref(), event)?;
+
+    // a duplicate relay for an ownable that is already released to this owner
+    let ownership = OWNABLE_INFO.load(deps.storage)?;
+    if !LOCKED.load(deps.storage)? && ownership.owner == owner {
+        trace!(deps.api, "release skipped: already released to {}", owner);
+        return Ok(Response::new()
+            .add_attribute("method", "try_release")
+            .add_attribute("is_locked", "false")
+            .add_attribute("owner", owner.to_string())
+            .add_attribute("noop", "true"));
+    }
+
+    let response = try_release(deps.branch(), env, owner)?;
+    // `verify_nft_event` checked the sender's key is the NFT owner's
+    OWNER_PUBLIC_KEY.save(deps.storage, &Some(info.sender.to_string()))?;
+    Ok(response)
+}
+
+/// The backing NFT was burned on its origin chain: release the ownable to
+/// the NFT owner if needed and make it native for good
+fn try_register_burn(
+    info: MessageInfo,
+    mut deps: DepsMut,
+    env: Env,
+    event: ExternalEventMsg,
+) -> Result<Response, ContractError> {
+    let owner = verify_nft_event(&info, deps.as_ref(), event)?;
+    let response = if LOCKED.load(deps.storage)? {
+        let response = try_release(deps.branch(), env, owner)?;
+        OWNER_PUBLIC_KEY.save(deps.storage, &Some(info.sender.to_string()))?;
+        response
+    } else {
+        Response::new()
+    };
+    NATIVE.save(deps.storage, &true)?;
+
+    Ok(response.add_attribute("is_native", "true"))
+}
+
+/// Record that the owner burned the ownable `burned_ownable`, so it can be
+/// merged into this one with `Merge`. The event relays the burn from the
+/// burned ownable's chain: `signature` (base64) is the owner's signature over
+/// its `burn_message`, made with `public_key` (base58).
+fn try_register_ownable_burn(
+    info: MessageInfo,
+    deps: DepsMut,
+    env: Env,
+    event: ExternalEventMsg,
+) -> Result<Response, ContractError> {
+    let network_id = NETWORK_ID.load(deps.storage)?;
+    let sender = address_lto(network_id as char, info.sender.to_string())?;
+    let owner = deps.api.addr_validate(&required_attr(&event, "owner")?)?;
+    let burned = deps.api.addr_validate(&required_attr(&event, "burned_ownable")?)?;
+    if owner != sender || owner != OWNABLE_INFO.load(deps.storage)?.owner {
+        return Err(ContractError::Unauthorized {
+            val: "Only the owner of both ownables can register a burn for merging".to_string(),
+        });
+    }
+    if burned == env.contract.address {
+        return Err(ContractError::CustomError { val: "Cannot merge an ownable into itself".into() });
+    }
+
+    // the burn must be signed by the key that held the burned ownable, which
+    // is this ownable's owner
+    let invalid_proof = || ContractError::Unauthorized { val: "Invalid burn proof".to_string() };
+    let signature = Binary::from_base64(&required_attr(&event, "signature")?)
+        .map_err(|_| invalid_proof())?;
+    let public_key = bs58::decode(required_attr(&event, "public_key")?)
+        .into_vec()
+        .map_err(|_| invalid_proof())?;
+    let verified = verify_owner_signature(
+        deps.as_ref(),
+        &owner,
+        network_id,
+        burn_message(&burned, &owner).as_bytes(),
+        &signature,
+        &public_key,
+    )?;
+    if !verified {
+        return Err(invalid_proof());
+    }
+
+    let pending = MERGE_CANDIDATES.keys(deps.storage, None, None, Order::Ascending).count();
+    if pending >= MAX_MERGED && !MERGE_CANDIDATES.has(deps.storage, &burned) {
+        return Err(ContractError::Validation {
+            field: "burned_ownable".to_string(),
+            reason: format!("at most {} burns can wait to be merged", MAX_MERGED),
+        });
+    }
+    MERGE_CANDIDATES.save(deps.storage, &burned, &env.block.time)?;
+
+    Ok(Response::new().add_attribute("merge_candidate", burned))
+}
+
+/// Checks an NFT event against the backing NFT and returns the LTO address
+/// of the NFT owner that sent it. The event network is a CAIP-2 chain id:
+/// `eip155:*` owners are secp256k1 public keys, `solana:*` owners are base58
+/// ed25519 public keys
+fn verify_nft_event(
+    info: &MessageInfo,
+    deps: Deps,
+    event: ExternalEventMsg,
+) -> Result<Addr, ContractError> {
+    // an ownable instantiated without an nft can't be driven by bridge events
+    let nfts = load_backing_nfts(deps)?;
+
+    let owner = required_attr(&event, "owner")?;
+    let nft_id = required_attr(&event, "token_id")?;
+    let contract_addr = required_attr(&event, "contract")?;
+
+    let event_network = event.network.unwrap_or("".to_string());
+    if event_network.is_empty() {
+        return Err(ContractError::MatchChainIdError { val: "No network".to_string() })
+    }
+    let (namespace, _) = parse_caip2(&event_network)?;
+
+    let allowed_networks = load_allowed_networks(deps)?;
+    if !nfts.iter().any(|nft| is_backing_nft(nft, &allowed_networks, &event_network, &contract_addr, &nft_id)) {
+        // the right nft reported from the wrong chain
+        let nft = nfts.iter()
+            .find(|nft| nft.address == contract_addr && nft.id.to_string() == nft_id);
+        if let Some(nft) = nft {
+            return Err(ContractError::MatchChainIdError {
+                val: format!(
+                    "{} is not the chain of the backing nft ({})",
+                    event_network, nft.network
+                ),
+            });
+        }
+        return Err(ContractError::LockError {
+            val: "Not a backing nft".to_string()
+        });
+    }
+
+    match namespace.as_str() {
+        "eip155" => {
+            // assert that owner address is the eip155 of info.sender pk
+            let address = eip155_address(info.sender.as_str())?;
+            if address != eip155_address(&owner)? {
+                return Err(ContractError::Unauthorized {
+                    val: "Only the owner can release an ownable".to_string(),
+                });
+            }
+
+            let network_id = NETWORK_ID.load(deps.storage)?;
+            Ok(address_lto(network_id as char, owner)?)
+        }
+        "solana" => {
+            // assert that owner address is the solana address of info.sender pk
+            let address = address_solana(info.sender.to_string())?;
+            if address != address_solana(owner.clone())? {
+                return Err(ContractError::Unauthorized {
+                    val: "Only the owner can release an ownable".to_string(),
+                });
+            }
+
+            let network_id = NETWORK_ID.load(deps.storage)?;
+            Ok(address_lto(network_id as char, owner)?)
+        }
+        _ => Err(ContractError::MatchChainIdError { val: event_network }),
+    }
+}
+
+/// `address_eip155` panics on anything but a secp256k1 public key, so the
+/// key is parsed first
+fn eip155_address(public_key: &str) -> StdResult<Addr> {
+    let bytes = bs58::decode(public_key)
+        .into_vec()
+        .map_err(|_| StdError::generic_err(format!("Invalid public key: {}", public_key)))?;
+    if secp256k1::PublicKey::from_slice(&bytes).is_err() {
+        return Err(StdError::generic_err(format!("Not a secp256k1 public key: {}", public_key)));
+    }
+    address_eip155(public_key.to_string())
+}
+
+/// A Solana address is the base58 encoding of a 32 byte ed25519 public key;
+/// `public_key` must already be in that form
+fn address_solana(public_key: String) -> Result<String, ContractError> {
+    let invalid = || ContractError::CustomError {
+        val: format!("Invalid solana address: {}", public_key),
+    };
+    let bytes = bs58::decode(&public_key).into_vec().map_err(|_| invalid())?;
+    if bytes.len() != 32 {
+        return Err(invalid());
+    }
+    Ok(bs58::encode(bytes).into_string())
+}
+
+
+#[cfg_attr(feature = "entry-points", entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::GetInfo {} => query_ownable_info(deps),
+        QueryMsg::GetMetadata {} => query_ownable_metadata(deps),
+        QueryMsg::GetWidgetState {} => query_ownable_widget_state(deps, env),
+        QueryMsg::IsLocked {} => query_lock_state(deps),
+        QueryMsg::GetCapabilities {} => query_capabilities(),
+        QueryMsg::Multi { queries } => query_multi(deps, env, queries),
+        QueryMsg::GetExternalEventLog { start_after, limit } =>
+            query_external_event_log(deps, start_after, limit),
+        QueryMsg::GetCreator {} => query_creator(deps),
+        QueryMsg::GetPackageCid {} => query_package_cid(deps),
+        QueryMsg::GetAttributes { start_after, limit } =>
+            query_attributes(deps, start_after, limit),
+        QueryMsg::GetConfig {} => to_json_binary(&CONFIG.may_load(deps.storage)?.flatten()),
+        QueryMsg::GetShares { address } => query_shares(deps, address),
+        QueryMsg::GetEip155Address {} => query_eip155_address(deps),
+        QueryMsg::GetAddressForNetwork { network_id } =>
+            query_address_for_network(deps, network_id),
+        QueryMsg::GetOwner {} => query_owner(deps),
+        QueryMsg::GetEffectiveOwner {} => query_effective_owner(deps, env),
+        QueryMsg::GetOwnershipHistory { start_after, limit } =>
+            query_ownership_history(deps, start_after, limit),
+        QueryMsg::GetProvenanceProof { index } => query_provenance_proof(deps, index),
+        QueryMsg::VerifyProvenance { index, record, proof } =>
+            query_verify_provenance(deps, index, record, proof),
+        QueryMsg::GetNfts { start_after, limit } => query_nfts(deps, start_after, limit),
+        QueryMsg::GetLockProof {} => query_lock_proof(deps, env),
+        QueryMsg::GetLockInfo {} => to_json_binary(&LOCK_INFO.may_load(deps.storage)?.flatten()),
+        QueryMsg::IsActive {} => query_is_active(deps, env),
+        QueryMsg::IsOwner { address } => query_is_owner(deps, address),
+        QueryMsg::VerifyOwnerSig { message, signature, pubkey } =>
+            query_verify_owner_sig(deps, message, signature, pubkey),
+        #[cfg(feature = "consumable")]
+        QueryMsg::GetUsesRemaining {} => to_json_binary(&USES_REMAINING.load(deps.storage)?),
+        #[cfg(feature = "artwork")]
+        QueryMsg::GetArtworkInfo {} => to_json_binary(&ARTWORK.load(deps.storage)?),
+    }
+}
+
+fn query_package_cid(deps: Deps) -> StdResult<Binary> {
+    to_json_binary(&PackageCidResponse {
+        cid: PACKAGE_CID.load(deps.storage)?,
+    })
+}
+
+/// An eip155 address is derived from a secp256k1 public key, not from the
+/// LTO address, so this needs the owner's key. Uses the same conversion as
+/// `verify_nft_event`; an owner without an eip155 form is an error rather
+/// than an empty address.
+fn query_eip155_address(deps: Deps) -> StdResult<Binary> {
+    let public_key = OWNER_PUBLIC_KEY.may_load(deps.storage)?
+        .flatten()
+        .ok_or_else(|| StdError::generic_err(
+            "The owner's public key is not known; it is recorded at instantiate and by bridge releases",
+        ))?;
+    let address = eip155_address(&public_key)?;
+    to_json_binary(&Eip155Response {
+        address: address.to_string(),
+    })
+}
+
+fn query_address_for_network(deps: Deps, network_id: u8) -> StdResult<Binary> {
+    let owner = OWNABLE_INFO.load(deps.storage)?.owner;
+    let address = address_for_network(&owner, network_id)
+        .map_err(|error| StdError::generic_err(error.to_string()))?;
+    to_json_binary(&NetworkAddressResponse {
+        network_id: (network_id as char).to_string(),
+        address,
+    })
+}
+
+/// An LTO address is `[version, network, 20 byte key hash, 4 byte checksum]`
+/// in base58, so the same key on another network only differs in the
+/// network byte and the checksum over the first 22 bytes
+pub fn address_for_network(address: &Addr, network_id: u8) -> Result<String, ContractError> {
+    if !NETWORK_IDS.contains(&network_id) {
+        return Err(ContractError::InvalidNetwork { val: (network_id as char).to_string() });
+    }
+    let invalid = || ContractError::CustomError {
+        val: format!("Invalid LTO address: {}", address),
+    };
+    let mut bytes = bs58::decode(address.as_str()).into_vec().map_err(|_| invalid())?;
+    if bytes.len() != 26 || bytes[0] != 1 {
+        return Err(invalid());
+    }
+
+    bytes[1] = network_id;
+    let checksum = Sha256::digest(Blake2b::<U32>::digest(&bytes[..22]));
+    bytes[22..].copy_from_slice(&checksum[..4]);
+    Ok(bs58::encode(bytes).into_string())
+}
+
+fn query_shares(deps: Deps, address: String) -> StdResult<Binary> {
+    let address = deps.api.addr_validate(&address)?;
+    let shares = SHARES.may_load(deps.storage, &address)?.unwrap_or_default();
+    to_json_binary(&shares)
+}
+
+fn query_attributes(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    // ownables instantiated before attributes existed have none
+    let attributes = ATTRIBUTES.may_load(deps.storage)?.unwrap_or_default();
+    to_json_binary(&paginate_list(attributes, start_after, limit, MAX_LIMIT))
+}
+
+fn query_creator(deps: Deps) -> StdResult<Binary> {
+    let ownable_info = OWNABLE_INFO.load(deps.storage)?;
+    to_json_binary(&CreatorResponse {
+        creator: CREATOR.load(deps.storage)?,
+        issuer: ownable_info.issuer,
+    })
+}
+
+fn query_external_event_log(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let log = EVENT_LOG.may_load(deps.storage)?.unwrap_or_default();
+    to_json_binary(&paginate_list(log, start_after, limit, MAX_LIMIT))
+}
+
+fn query_multi(deps: Deps, env: Env, queries: Vec<QueryMsg>) -> StdResult<Binary> {
+    let mut results: Vec<Binary> = Vec::with_capacity(queries.len());
+    for msg in queries {
+        if let QueryMsg::Multi { .. } = msg {
+            return Err(StdError::generic_err("Nested multi queries are not allowed"));
+        }
+        results.push(query(deps, env.clone(), msg)?);
+    }
+    to_json_binary(&results)
+}
+
+fn query_capabilities() -> StdResult<Binary> {
+    let features = [
+        ("backtraces", cfg!(feature = "backtraces")),
+        ("library", cfg!(feature = "library")),
+        ("debug-trace", cfg!(feature = "debug-trace")),
+        ("subscription", cfg!(feature = "subscription")),
+        ("consumable", cfg!(feature = "consumable")),
+        ("artwork", cfg!(feature = "artwork")),
+    ];
+    to_json_binary(&CapabilitiesResponse {
+        features: features.iter()
+            .filter(|(_, enabled)| *enabled)
+            .map(|(name, _)| name.to_string())
+            .collect(),
+    })
+}
+
+fn query_ownable_widget_state(deps: Deps, env: Env) -> StdResult<Binary> {
+    to_json_binary(&WidgetStateResponse {
+        contract_address: env.contract.address,
+        ownable_type: OWNABLE_TYPE,
+        locked: LOCKED.load(deps.storage)?,
+        config: CONFIG.load(deps.storage)?,
+        thumbnail: THUMBNAIL.may_load(deps.storage)?.unwrap_or_default(),
+    })
+}
+
+fn query_lock_state(deps: Deps) -> StdResult<Binary> {
+    let is_locked = LOCKED.load(deps.storage)?;
+    to_json_binary(&is_locked)
+}
+
+fn query_is_active(deps: Deps, env: Env) -> StdResult<Binary> {
+    #[allow(unused_mut)]
+    let mut is_active = active_transfer_lock(deps, &env)?.is_none();
+    #[cfg(feature = "subscription")]
+    {
+        is_active = is_active && env.block.time.seconds() < EXPIRES_AT.load(deps.storage)?;
+    }
+    to_json_binary(&is_active)
+}
+
+fn query_verify_owner_sig(
+    deps: Deps,
+    message: Binary,
+    signature: Binary,
+    pubkey: Binary,
+) -> StdResult<Binary> {
+    let ownable_info = OWNABLE_INFO.load(deps.storage)?;
+    let network_id = NETWORK_ID.load(deps.storage)?;
+    let verified = verify_owner_signature(
+        deps,
+        &ownable_info.owner,
+        network_id,
+        &message,
+        &signature,
+        &pubkey,
+    )?;
+    to_json_binary(&verified)
+}
+
+/// A signature is the owner's when it verifies against `pubkey` and that key
+/// derives the owner's LTO address
+pub fn verify_owner_signature(
+    deps: Deps,
+    owner: &Addr,
+    network_id: u8,
+    message: &[u8],
+    signature: &[u8],
+    pubkey: &[u8],
+) -> StdResult<bool> {
+    let signer = address_lto(network_id as char, bs58::encode(pubkey).into_string())?;
+    if signer != *owner {
+        return Ok(false);
+    }
+    deps.api.ed25519_verify(message, signature, pubkey)
+        .map_err(|error| StdError::generic_err(error.to_string()))
+}
+
+fn query_ownership_history(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    // read one record past the page so `paginate` knows whether there is more
+    let records = OWNERSHIP_HISTORY
+        .range(deps.storage, start_after.map(Bound::exclusive), None, Order::Ascending)
+        .take(page_size(limit, MAX_LIMIT) + 1)
+        .collect::<StdResult<Vec<(u64, OwnershipRecord)>>>()?;
+    to_json_binary(&paginate(records, start_after, limit, MAX_LIMIT))
+}
+
+type Hash = [u8; 32];
+
+fn provenance_leaf(index: u64, record: &OwnershipRecord) -> StdResult<Hash> {
+    let mut hasher = Sha256::new();
+    hasher.update([0u8]);
+    hasher.update(index.to_be_bytes());
+    hasher.update(to_json_vec(record)?);
+    Ok(hasher.finalize().into())
+}
+
+fn provenance_node(left: &Hash, right: &Hash) -> Hash {
+    let mut hasher = Sha256::new();
+    hasher.update([1u8]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// Leaf hashes of the kept ownership records, by index
+fn provenance_leaves(deps: Deps) -> StdResult<Vec<(u64, Hash)>> {
+    OWNERSHIP_HISTORY
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (index, record) = item?;
+            Ok((index, provenance_leaf(index, &record)?))
+        })
+        .collect()
+}
+
+/// Merkle root over a non-empty `level` and the sibling path of the leaf at
+/// `position`. A node without a sibling moves up a level unchanged.
+fn merkle_root(mut level: Vec<Hash>, mut position: Option<usize>) -> (Hash, Vec<Hash>) {
+    let mut proof = vec![];
+    while level.len() > 1 {
+        if let Some(sibling) = position.map(|position| position ^ 1).filter(|&sibling| sibling < level.len()) {
+            proof.push(level[sibling]);
+        }
+        level = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => provenance_node(left, right),
+                [single] => *single,
+                _ => unreachable!(),
+            })
+            .collect();
+        position = position.map(|position| position / 2);
+    }
+    (level[0], proof)
+}
+
+/// Fold a sibling path into the root it proves for the leaf at `position`
+/// of `len` leaves; `None` when the path has the wrong length
+fn fold_provenance_proof(leaf: Hash, mut position: usize, mut len: usize, proof: &[Hash]) -> Option<Hash> {
+    let mut hash = leaf;
+    let mut siblings = proof.iter();
+    while len > 1 {
+        if position ^ 1 < len {
+            let sibling = siblings.next()?;
+            hash = if position.is_multiple_of(2) {
+                provenance_node(&hash, sibling)
+            } else {
+                provenance_node(sibling, &hash)
+            };
+        }
+        position /= 2;
+        len = len.div_ceil(2);
+    }
+    siblings.next().is_none().then_some(hash)
+}
+
+fn query_provenance_proof(deps: Deps, index: Option<u64>) -> StdResult<Binary> {
+    let leaves = provenance_leaves(deps)?;
+    if leaves.is_empty() {
+        return Err(StdError::generic_err("No ownership history to prove"));
+    }
+    let position = match index {
+        Some(index) => Some(
+            leaves.iter().position(|(i, _)| *i == index)
+                .ok_or_else(|| StdError::generic_err(format!("No ownership record at index {}", index)))?,
+        ),
+        None => None,
+    };
+    let records = leaves.len() as u64;
+    let (root, proof) = merkle_root(leaves.into_iter().map(|(_, leaf)| leaf).collect(), position);
+
+    to_json_binary(&ProvenanceProofResponse {
+        root: hex::encode(root),
+        records,
+        proof: proof.iter().map(hex::encode).collect(),
+    })
+}
+
+fn query_verify_provenance(
+    deps: Deps,
+    index: u64,
+    record: OwnershipRecord,
+    proof: Vec<String>,
+) -> StdResult<Binary> {
+    let proof = proof.iter()
+        .map(|hash| hex::decode(hash).ok().and_then(|bytes| Hash::try_from(bytes).ok()))
+        .collect::<Option<Vec<Hash>>>()
+        .ok_or_else(|| StdError::generic_err("Provenance proof hashes must be 32 bytes of hex"))?;
+    let leaves = provenance_leaves(deps)?;
+    let position = match leaves.iter().position(|(i, _)| *i == index) {
+        Some(position) => position,
+        None => return to_json_binary(&false),
+    };
+    let len = leaves.len();
+    let (root, _) = merkle_root(leaves.into_iter().map(|(_, leaf)| leaf).collect(), None);
+    let proven = fold_provenance_proof(provenance_leaf(index, &record)?, position, len, &proof);
+    to_json_binary(&(proven == Some(root)))
+}
+
+fn query_is_owner(deps: Deps, address: String) -> StdResult<Binary> {
+    let address = deps.api.addr_validate(&address)?;
+    let ownable_info = OWNABLE_INFO.load(deps.storage)?;
+    to_json_binary(&(ownable_info.owner == address))
+}
+
+fn query_owner(deps: Deps) -> StdResult<Binary> {
+    let ownable_info = OWNABLE_INFO.load(deps.storage)?;
+    to_json_binary(&OwnerResponse {
+        owner: ownable_info.owner,
+        issuer: ownable_info.issuer,
+    })
+}
+
+fn query_effective_owner(deps: Deps, env: Env) -> StdResult<Binary> {
+    let owner = match active_lease(deps, &env)? {
+        Some(lease) => lease.holder,
+        None => OWNABLE_INFO.load(deps.storage)?.owner,
+    };
+    to_json_binary(&owner)
+}
+
+fn query_lock_proof(deps: Deps, env: Env) -> StdResult<Binary> {
+    let origin = match LOCK_ORIGIN.may_load(deps.storage)? {
+        Some(origin) if LOCKED.load(deps.storage)? => origin,
+        _ => return Err(StdError::generic_err("Ownable is not locked for an origin nft")),
+    };
+    let ownable_id = OWNABLE_ID.load(deps.storage)?;
+    let owner = OWNABLE_INFO.load(deps.storage)?.owner;
+
+    let fields = [
+        ownable_id.as_str(),
+        env.contract.address.as_str(),
+        origin.network.as_str(),
+        origin.contract.as_str(),
+        origin.token_id.as_str(),
+        owner.as_str(),
+    ];
+    let commitment = hex::encode(Sha256::digest(fields.join("\n").as_bytes()));
+
+    to_json_binary(&LockProofResponse {
+        ownable_id,
+        contract_address: env.contract.address,
+        network: origin.network,
+        contract: origin.contract,
+        token_id: origin.token_id,
+        owner,
+        commitment,
+    })
+}
+
+fn query_nfts(deps: Deps, start_after: Option<u64>, limit: Option<u32>) -> StdResult<Binary> {
+    let nfts = NFT_ITEMS.may_load(deps.storage)?.unwrap_or_default();
+    to_json_binary(&paginate_list(nfts, start_after, limit, MAX_LIMIT))
+}
+
+fn query_ownable_info(deps: Deps) -> StdResult<Binary> {
+    // InfoResponse has room for one nft, the first of the set
+    let nft = NFT_ITEMS.may_load(deps.storage)?
+        .and_then(|nfts| nfts.into_iter().next());
+    let ownable_info = OWNABLE_INFO.load(deps.storage)?;
+    to_json_binary(&InfoResponse {
+        owner: ownable_info.owner,
+        issuer: ownable_info.issuer,
+        nft,
+        ownable_type: ownable_info.ownable_type,
+    })
+}
+
+fn query_ownable_metadata(deps: Deps) -> StdResult<Binary> {
+    // partial state shouldn't make the ownable unreadable, so fall back to
+    // the template's name and description
+    let cw721 = METADATA.may_load(deps.storage)?
+        .unwrap_or_else(|| default_metadata(OWNABLE_TYPE));
+    to_json_binary(&Metadata {
+        image: cw721.image,
+        image_data: cw721.image_data,
+        external_url: cw721.external_url,
+        description: cw721.description,
+        name: cw721.name,
+        background_color: cw721.background_color,
+        animation_url: cw721.animation_url,
+        youtube_url: cw721.youtube_url,
+    })
+}
+
@@ -0,0 +1,78 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized error: {val:?}")]
+    Unauthorized { val: String },
+
+    #[error("{val:?}")]
+    CustomError { val: String },
+
+    #[error("Lock error: {val:?}")]
+    LockError { val: String },
+
+    #[error("Ownable is locked")]
+    Locked {},
+
+    #[error("Ownable is leased")]
+    Leased {},
+
+    #[error("Ownable is burned")]
+    Burned {},
+
+    #[error("Ownable is owned in shares; use TransferShares")]
+    SharedOwnership {},
+
+    #[error("Ownable {other} is already merged into this ownable")]
+    AlreadyMerged { other: String },
+
+    #[error("Unknown event type: {val:?}")]
+    MatchEventError { val: String },
+
+    #[error("Unknown chain id: {val:?}")]
+    MatchChainIdError { val: String },
+
+    #[error("Ownable is not bridgeable")]
+    NotBridgeable {},
+
+    #[error("Ownable is not backed by an NFT")]
+    NoNftBacking {},
+
+    #[error("Invalid external event args: missing `{key}` attribute")]
+    InvalidExternalEventArgs { key: String },
+
+    #[error("Method is not implemented for this Ownable")]
+    NotImplemented {},
+
+    #[error("Unknown message: {variant:?}")]
+    UnknownMessage { variant: String },
+
+    #[error("Invalid LTO network: {val:?}")]
+    InvalidNetwork { val: String },
+
+    #[error("Invalid {field}: {reason}")]
+    Validation { field: String, reason: String },
+
+    #[error("Invalid migration from {from} to {to}")]
+    InvalidMigration { from: String, to: String },
+
+    #[error("Migration failed: {reason}")]
+    MigrationError { reason: String },
+
+    #[error("Invalid royalty: {bps} basis points exceeds {max}")]
+    InvalidRoyalty { bps: u16, max: u16 },
+
+    #[error("Invalid denom: expected {expected}, got {got}")]
+    InvalidDenom { expected: String, got: String },
+
+    #[error("Event {id} has already been processed")]
+    DuplicateEvent { id: String },
+
+    #[cfg(feature = "consumable")]
+    #[error("Insufficient uses: requested {requested}, {remaining} remaining")]
+    InsufficientUses { requested: u32, remaining: u32 },
+}
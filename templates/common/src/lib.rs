@@ -5,14 +5,25 @@ use std::str;
 use contract::instantiate;
 use cosmwasm_std::{MessageInfo};
 use ownable_std::{create_lto_env, ExternalEventMsg, get_json_response, IdbStateDump, load_lto_deps};
-use msg::{ExecuteMsg, InstantiateMsg};
-use serde_json::{to_string};
+use error::ContractError;
+use msg::{ExecuteMsg, InstantiateMsg, MigrateMsg};
+use schemars::schema::Schema;
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde_json::{to_string, Value};
 use wasm_bindgen::prelude::*;
 
+pub mod caip;
 pub mod contract;
 pub mod error;
 pub mod msg;
+pub mod pagination;
 pub mod state;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod testing;
+
+#[cfg(test)]
+mod tests;
 
 #[wasm_bindgen]
 extern "C" {
@@ -22,6 +33,45 @@ extern "C" {
     pub fn log(s: &str);
 }
 
+/// Variant names of an externally tagged message enum, as they appear in
+/// JSON; read from its schema so they include variants added by the
+/// `ownables_*` macros and cargo features
+fn message_variants<T: JsonSchema>() -> Vec<String> {
+    let root = schemars::schema_for!(T);
+    let variants = root.schema.subschemas.and_then(|subschemas| subschemas.one_of);
+    let mut names = Vec::new();
+    for variant in variants.unwrap_or_default() {
+        if let Schema::Object(object) = variant {
+            // `{ "variant": { .. } }` requires its tag, unit variants are strings
+            names.extend(object.object.into_iter().flat_map(|object| object.required));
+            names.extend(object.enum_values.into_iter().flatten()
+                .filter_map(|value| value.as_str().map(String::from)));
+        }
+    }
+    names
+}
+
+/// The variant a message is tagged with, if it is shaped like one
+fn message_tag(msg: &Value) -> Option<String> {
+    match msg {
+        Value::Object(fields) if fields.len() == 1 => fields.keys().next().cloned(),
+        Value::String(name) => Some(name.clone()),
+        _ => None,
+    }
+}
+
+/// Deserialize an execute or query message, reporting a variant this
+/// version of the contract doesn't know as `ContractError::UnknownMessage`
+fn parse_msg<T: DeserializeOwned + JsonSchema>(msg: JsValue) -> Result<T, JsError> {
+    let value: Value = serde_wasm_bindgen::from_value(msg.clone())?;
+    if let Some(variant) = message_tag(&value) {
+        if !message_variants::<T>().contains(&variant) {
+            return Err(JsError::from(ContractError::UnknownMessage { variant }));
+        }
+    }
+    Ok(serde_wasm_bindgen::from_value(msg)?)
+}
+
 #[wasm_bindgen]
 pub async fn instantiate_contract(
     msg: JsValue,
@@ -49,7 +99,7 @@ pub async fn execute_contract(
     info: JsValue,
     idb: JsValue,
 ) -> Result<JsValue, JsError> {
-    let message: ExecuteMsg = serde_wasm_bindgen::from_value(msg.clone())?;
+    let message: ExecuteMsg = parse_msg(msg)?;
     let info: MessageInfo = serde_wasm_bindgen::from_value(info)?;
     let state_dump: IdbStateDump = serde_wasm_bindgen::from_value(idb)?;
     let mut deps = load_lto_deps(Some(state_dump));
@@ -85,6 +135,7 @@ pub async fn register_external_event(
     let result = contract::register_external_event(
         info,
         deps.as_mut(),
+        create_lto_env(),
         external_event,
         ownable_id,
     );
@@ -98,6 +149,26 @@ pub async fn register_external_event(
     }
 }
 
+#[wasm_bindgen]
+pub async fn migrate_contract(
+    msg: JsValue,
+    idb: JsValue,
+) -> Result<JsValue, JsError> {
+    let msg: MigrateMsg = serde_wasm_bindgen::from_value(msg)?;
+    let state_dump: IdbStateDump = serde_wasm_bindgen::from_value(idb)?;
+    let mut deps = load_lto_deps(Some(state_dump));
+
+    let result = contract::migrate(deps.as_mut(), create_lto_env(), msg);
+
+    match result {
+        Ok(response) => {
+            let resp = get_json_response(deps.storage, response)?;
+            Ok(resp)
+        }
+        Err(error) => Err(JsError::from(error)),
+    }
+}
+
 #[wasm_bindgen]
 pub async fn query_contract_state(
     msg: JsValue,
@@ -109,7 +180,7 @@ pub async fn query_contract_state(
     let query_result = contract::query(
         deps.as_ref(),
         create_lto_env(),
-        serde_wasm_bindgen::from_value(msg)?
+        parse_msg(msg)?
     );
 
     match query_result {
@@ -125,3 +196,4 @@ pub async fn query_contract_state(
         Err(error) => panic!("contract state query failed. error {:?}", error),
     }
 }
+
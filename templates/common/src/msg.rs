@@ -0,0 +1,352 @@
+use cosmwasm_std::{Addr, Binary, Coin, Timestamp};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use ownable_std_macros::{
+    ownables_lock,
+    ownables_query_info, ownables_query_locked, ownables_query_metadata,
+    ownables_query_widget_state,
+};
+use ownable_std::{Metadata, NFT};
+use crate::state::{AuthPolicy, Config, OwnableType, OwnershipRecord, Thumbnail, Trait};
+#[cfg(feature = "artwork")]
+use crate::state::ArtworkInfo;
+
+// Fields are declared explicitly (rather than via `ownables_instantiate_msg`)
+// so their doc comments end up as descriptions in the generated schema.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {
+    /// Unique id of this ownable, assigned by the wallet when the event chain is created
+    pub ownable_id: String,
+    /// IPFS CID of the ownable package (wasm, widget assets and metadata)
+    pub package: String,
+    /// NFT, or set of NFTs, backing this ownable if it can be bridged
+    pub nft: Option<NftInput>,
+    /// Split ownership into shares; `shares` must add up to `total_shares`
+    pub total_shares: Option<u64>,
+    pub shares: Option<Vec<ShareAllocation>>,
+    /// Other `eip155:` networks the NFT may be bridged to and locked on,
+    /// besides its own network
+    pub allowed_networks: Option<Vec<String>>,
+    /// Ownable type requested by the wallet; the template's compiled type is authoritative
+    pub ownable_type: Option<String>,
+    /// LTO network byte the owner address is derived for (`L` mainnet, `T` testnet)
+    pub network_id: u8,
+    /// Overrides for the type's default metadata; omitted fields keep the default
+    pub metadata: Option<Metadata>,
+    /// Structured traits describing the ownable
+    pub attributes: Option<Vec<Trait>>,
+    /// Contract to notify when ownership changes
+    pub notify_contract: Option<String>,
+    /// Set to `false` for a native-only ownable that rejects bridge events and
+    /// locks; defaults to `true`
+    pub bridgeable: Option<bool>,
+    /// Royalty paid to the issuer on priced transfers, in basis points (max 10000)
+    pub royalty_bps: Option<u16>,
+    /// Denom sale prices are paid in; defaults to `lto`
+    pub accepted_denom: Option<String>,
+    /// Who may transfer the ownable; defaults to the owner only
+    pub transfer_policy: Option<AuthPolicy>,
+    /// Who may lock the ownable; defaults to the owner only
+    pub lock_policy: Option<AuthPolicy>,
+    /// Reject transfers before this time, e.g. during a drop's cool-down
+    pub transfer_locked_until: Option<Timestamp>,
+    /// Address allowed to update the config; without one it is fixed
+    pub admin: Option<String>,
+    /// Artist credited for the ownable when it is minted on their behalf
+    pub creator: Option<String>,
+    /// URL of a small preview image for widgets
+    pub thumbnail_url: Option<String>,
+    /// Inline preview image data for widgets
+    pub thumbnail_data: Option<String>,
+    /// Unix time in seconds the subscription is active until
+    #[cfg(feature = "subscription")]
+    pub expires_at: u64,
+    /// Number of times the consumable can be used
+    #[cfg(feature = "consumable")]
+    pub uses: u32,
+    /// Medium, size, year and print edition of the piece
+    #[cfg(feature = "artwork")]
+    pub artwork: ArtworkInfo,
+}
+
+/// A single NFT or a list, so existing single-NFT messages keep working
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(untagged)]
+pub enum NftInput {
+    One(NFT),
+    Many(Vec<NFT>),
+}
+
+impl NftInput {
+    pub fn into_vec(self) -> Vec<NFT> {
+        match self {
+            NftInput::One(nft) => vec![nft],
+            NftInput::Many(nfts) => nfts,
+        }
+    }
+}
+
+/// Initial shares held by `holder` in shares mode
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ShareAllocation {
+    pub holder: String,
+    pub amount: u64,
+}
+
+#[ownables_lock]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    Transfer {
+        /// Recipient address, validated by the contract
+        recipient: String,
+        /// Off-chain reference (e.g. a marketplace order id), emitted as an
+        /// attribute and kept in the ownership history record
+        memo: Option<String>,
+        /// Price paid for the ownable, sent along as the message's funds. The
+        /// issuer's royalty is paid from it and the rest goes to the seller.
+        /// Without a price, or with a zero price, no royalty is paid.
+        sale_price: Option<Coin>,
+    },
+    /// Lock the ownable for the backing NFT on the given network in one step
+    LockFor {
+        /// CAIP-2 chain id of the NFT, e.g. `eip155:1`
+        network: String,
+        /// Address of the NFT contract
+        contract: String,
+        /// Token id of the NFT
+        token_id: String,
+    },
+    /// Lock the ownable so its backing NFT on `to_network` can move, emitting
+    /// lock event attributes for a relayer
+    Bridge {
+        /// CAIP-2 chain id of the backing NFT, e.g. `eip155:1`
+        to_network: String,
+    },
+    /// Change config fields; callable by the config `admin`. Omitted fields
+    /// keep their value
+    UpdateConfig {
+        notify_contract: Option<String>,
+        royalty_bps: Option<u16>,
+        accepted_denom: Option<String>,
+        transfer_locked_until: Option<Timestamp>,
+        admin: Option<String>,
+    },
+    /// Fuse the ownable contract `other` into this one. The sender must have
+    /// burned `other` and relayed its signed burn as an `ownable_burn`
+    /// external event, and `proof` is their signature over
+    /// `merge:<other>:<this contract>`
+    Merge { other: String, proof: Binary },
+    /// Move `amount` of the sender's shares to `recipient`; shares mode only
+    TransferShares { recipient: String, amount: u64 },
+    /// Correct metadata after mint; callable by the issuer. Omitted fields are
+    /// left unchanged and the name can't be changed.
+    UpdateMetadata {
+        description: Option<String>,
+        external_url: Option<String>,
+        image: Option<String>,
+        animation_url: Option<String>,
+    },
+    /// Permanently retire the ownable; it can no longer be transferred, locked
+    /// or released, but can still be queried
+    Burn {},
+    /// Allow `spender` to move the ownable once with `TransferFrom`
+    Approve { spender: String },
+    /// Withdraw the current approval
+    RevokeApproval {},
+    /// Transfer to `recipient` and approve `spender` for them in one call;
+    /// owner only. The recipient can revoke the approval, and `spender` may
+    /// be neither the sender nor the recipient. It carries no sale price, so
+    /// no royalty is paid
+    TransferAndApprove { recipient: String, spender: String },
+    /// Move the ownable to `recipient`; callable by the approved spender
+    TransferFrom { recipient: String },
+    /// Lease the ownable to `to` until the given time; the owner can't
+    /// transfer it while the lease is active
+    Lease { to: String, until: Timestamp },
+    /// Release an owner-locked ownable back to its owner, who must be `to`;
+    /// callable by the current owner. Bridge locks are only released by their
+    /// bridge event.
+    Release { to: String },
+    /// Undo the owner's own `Lock`; locks made for a bridge can't be undone
+    Unlock {},
+    /// Extend the subscription; an expired subscription is renewed from now
+    #[cfg(feature = "subscription")]
+    Renew { additional_secs: u64 },
+    /// Use up `amount` of the remaining uses; callable by the current owner
+    #[cfg(feature = "consumable")]
+    Consume { amount: u32 },
+}
+
+#[ownables_query_info]
+#[ownables_query_locked]
+#[ownables_query_metadata]
+#[ownables_query_widget_state]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    /// Cargo features this ownable was compiled with
+    GetCapabilities {},
+    /// Run several queries at once; results are returned in order as a list of
+    /// binaries. A `multi` query can't contain another `multi` query.
+    Multi { queries: Vec<QueryMsg> },
+    /// Recently processed bridge events, oldest first, paginated
+    GetExternalEventLog {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    GetCreator {},
+    /// CID of the package bundle backing this ownable
+    GetPackageCid {},
+    /// Structured traits set at instantiate, paginated
+    GetAttributes {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Stored config, or `null` for ownables instantiated without one
+    GetConfig {},
+    /// Shares held by `address`; zero outside shares mode
+    GetShares { address: String },
+    /// The owner in `eip155` form, as compared against lock events. Needs the
+    /// owner's secp256k1 key, known from instantiate or a bridge release; a
+    /// transfer only names an address, so this errors after one.
+    GetEip155Address {},
+    /// The owner's LTO address on another network, e.g. `T` while developing
+    /// against testnet; the stored network stays canonical
+    GetAddressForNetwork { network_id: u8 },
+    /// Current owner and issuer
+    GetOwner {},
+    /// The lease holder while a lease is active, otherwise the owner
+    GetEffectiveOwner {},
+    /// Previous owners, oldest first, paginated; only the latest 100 are kept
+    GetOwnershipHistory {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Merkle root over the kept ownership history, with the inclusion proof
+    /// of the record at `index` when one is given
+    GetProvenanceProof { index: Option<u64> },
+    /// Whether `record` is the ownership record at `index`, proven by the
+    /// sibling hashes of `GetProvenanceProof` against the current root
+    VerifyProvenance {
+        index: u64,
+        record: OwnershipRecord,
+        proof: Vec<String>,
+    },
+    /// NFTs backing this ownable, paginated
+    GetNfts {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Proof of the current `LockFor` lock for relaying to the origin chain
+    GetLockProof {},
+    /// Who locked the ownable and when, or `null` while it is unlocked
+    GetLockInfo {},
+    /// Whether `address` is the current owner
+    IsOwner { address: String },
+    /// Whether `signature` over `message` was made by the current owner's
+    /// ed25519 key `pubkey`, e.g. to check a wallet-signed challenge
+    VerifyOwnerSig {
+        message: Binary,
+        signature: Binary,
+        pubkey: Binary,
+    },
+    /// Whether the ownable is active at the current block time: transfers are
+    /// open, i.e. any configured `transfer_locked_until` has passed, and a
+    /// subscription has not yet expired
+    IsActive {},
+    /// Uses left before the consumable is exhausted
+    #[cfg(feature = "consumable")]
+    GetUsesRemaining {},
+    /// Medium, size, year and print edition of the piece
+    #[cfg(feature = "artwork")]
+    GetArtworkInfo {},
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CapabilitiesResponse {
+    /// Cargo features compiled into this build
+    pub features: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct MigrateMsg {
+    /// Id to store for an ownable whose layout predates `ownable_id`
+    #[serde(default)]
+    pub ownable_id: Option<String>,
+}
+
+/// Lock details a relayer submits to the origin chain after a `LockFor`.
+/// The contract holds no signing key, so `commitment` is the sha256 hex of
+/// `ownable_id`, `contract_address`, `network`, `contract`, `token_id` and
+/// `owner` joined with `\n`; the relayer signs it with its own key.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LockProofResponse {
+    pub ownable_id: String,
+    pub contract_address: Addr,
+    pub network: String,
+    pub contract: String,
+    pub token_id: String,
+    pub owner: Addr,
+    pub commitment: String,
+}
+
+/// Commitment to the ownership history for off-chain provenance checks. A
+/// leaf is the sha256 of `0x00`, the record's big-endian u64 index and its
+/// JSON; a node the sha256 of `0x01` and its two children, and a node
+/// without a sibling moves up a level as is. Hashes are hex.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ProvenanceProofResponse {
+    pub root: String,
+    /// Number of records the root covers
+    pub records: u64,
+    /// Sibling hashes from the leaf at the requested index up to the root
+    pub proof: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OwnerResponse {
+    pub owner: Addr,
+    pub issuer: Addr,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Eip155Response {
+    pub address: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct NetworkAddressResponse {
+    pub network_id: String,
+    pub address: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PackageCidResponse {
+    pub cid: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CreatorResponse {
+    pub creator: Option<Addr>,
+    pub issuer: Addr,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct WidgetStateResponse {
+    /// Address of this ownable, for building query URLs from the widget
+    pub contract_address: Addr,
+    pub ownable_type: OwnableType,
+    pub locked: bool,
+    pub config: Option<Config>,
+    pub thumbnail: Thumbnail,
+}
+
+/// Message sent to the configured `notify_contract`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum NotifyMsg {
+    OwnerChanged { ownable_id: String, owner: Addr },
+}
@@ -0,0 +1,56 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Page size when a list query doesn't pass `limit`
+pub const DEFAULT_LIMIT: u32 = 10;
+/// Largest page a list query returns, whatever `limit` asks for
+pub const MAX_LIMIT: u32 = 30;
+
+/// One page of a list query. Pass `next_start_after` back as `start_after`
+/// to fetch the next page; it is `None` on the last page.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_start_after: Option<u64>,
+}
+
+/// Number of items a query should read for `limit`, capped at `max`
+pub fn page_size(limit: Option<u32>, max: u32) -> usize {
+    limit.unwrap_or(DEFAULT_LIMIT).min(max) as usize
+}
+
+/// Take the page after the `start_after` cursor from items keyed by
+/// ascending cursor, e.g. a list index or a map key
+pub fn paginate<T>(
+    items: impl IntoIterator<Item = (u64, T)>,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+    max: u32,
+) -> Page<T> {
+    let size = page_size(limit, max);
+    let mut remaining = items.into_iter()
+        .filter(|(cursor, _)| start_after.is_none_or(|after| *cursor > after));
+
+    let mut items = Vec::with_capacity(size);
+    let mut last = None;
+    for (cursor, item) in remaining.by_ref().take(size) {
+        items.push(item);
+        last = Some(cursor);
+    }
+    let next_start_after = if remaining.next().is_some() { last } else { None };
+
+    Page { items, next_start_after }
+}
+
+/// `paginate` over a list, using the item index as the cursor
+pub fn paginate_list<T>(
+    items: Vec<T>,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+    max: u32,
+) -> Page<T> {
+    let indexed = items.into_iter()
+        .enumerate()
+        .map(|(index, item)| (index as u64, item));
+    paginate(indexed, start_after, limit, max)
+}
@@ -0,0 +1,223 @@
+use std::fmt;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use cosmwasm_std::{Addr, Timestamp};
+use cw_storage_plus::{Item, Map};
+use ownable_std::{Metadata, NFT, OwnableInfo};
+
+/// Serialized in lowercase (`"music"`), matching `Display` and the CLI `--type` flag
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OwnableType {
+    Image,
+    Music,
+    Subscription,
+    Consumable,
+    Artwork,
+    Document,
+}
+
+impl fmt::Display for OwnableType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OwnableType::Image => write!(f, "image"),
+            OwnableType::Music => write!(f, "music"),
+            OwnableType::Subscription => write!(f, "subscription"),
+            OwnableType::Consumable => write!(f, "consumable"),
+            OwnableType::Artwork => write!(f, "artwork"),
+            OwnableType::Document => write!(f, "document"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Config {
+    /// Contract notified with `NotifyMsg::OwnerChanged` whenever the owner changes
+    pub notify_contract: Option<Addr>,
+    /// Whether bridge events and locks are accepted; native-only ownables turn
+    /// this off at instantiate
+    #[serde(default = "default_bridgeable")]
+    pub bridgeable: bool,
+    /// Royalty paid to the issuer on priced transfers, in basis points
+    #[serde(default)]
+    pub royalty_bps: u16,
+    /// Denom a transfer's `sale_price` must be paid in
+    #[serde(default = "default_accepted_denom")]
+    pub accepted_denom: String,
+    /// Who may call `Transfer`
+    #[serde(default)]
+    pub transfer_policy: AuthPolicy,
+    /// Who may call `Lock` and `LockFor`
+    #[serde(default)]
+    pub lock_policy: AuthPolicy,
+    /// `Transfer` and `TransferFrom` are rejected before this time
+    #[serde(default)]
+    pub transfer_locked_until: Option<Timestamp>,
+    /// Address allowed to change the config with `UpdateConfig`
+    #[serde(default)]
+    pub admin: Option<Addr>,
+}
+
+fn default_bridgeable() -> bool {
+    true
+}
+
+pub const DEFAULT_DENOM: &str = "lto";
+
+fn default_accepted_denom() -> String {
+    DEFAULT_DENOM.to_string()
+}
+
+/// Who besides the owner may perform an action; checked by `authorize`
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthPolicy {
+    #[default]
+    OwnerOnly,
+    /// The owner or the spender set with `Approve`
+    OwnerOrApproved,
+    /// The owner or the ownable's issuer
+    OwnerOrIssuer,
+}
+
+/// A processed external (bridge) event
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EventRecord {
+    pub event_type: String,
+    pub network: Option<String>,
+    pub token_id: Option<String>,
+    pub at: Timestamp,
+}
+
+/// The NFT an owner-initiated lock was made for
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LockOrigin {
+    pub network: String,
+    pub contract: String,
+    pub token_id: String,
+}
+
+/// Who locked the ownable, at which height and for which network
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LockInfo {
+    pub locked_by: Addr,
+    pub at_height: u64,
+    /// CAIP-2 id of the target chain, or the local chain id for a plain `Lock`
+    pub event_network: String,
+    pub kind: LockKind,
+}
+
+/// A plain `Lock` can be undone with `Unlock` or `Release`; a lock for the
+/// NFT chain is only undone by its bridge event
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LockKind {
+    Owner,
+    Bridge,
+}
+
+/// Art-specific details of the piece, beyond its image
+#[cfg(feature = "artwork")]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ArtworkInfo {
+    /// e.g. "oil on canvas" or "generative"
+    pub medium: String,
+    /// Physical or pixel size, e.g. "60 x 90 cm"
+    pub dimensions: Option<String>,
+    pub year: u16,
+    /// Number of this print within the edition, from 1
+    pub edition: Option<u32>,
+    /// Size of the print edition
+    pub edition_total: Option<u32>,
+}
+
+/// Lightweight preview for widgets, separate from the full metadata image
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct Thumbnail {
+    pub url: Option<String>,
+    /// Data URI or base64 image data
+    pub data: Option<String>,
+}
+
+/// OpenSea-style trait, kept beside `Metadata` since that type is fixed by ownable_std
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Trait {
+    pub trait_type: String,
+    pub value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_type: Option<String>,
+}
+
+/// Temporary control granted by the owner, reverting once `until` passes
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Lease {
+    pub holder: Addr,
+    pub until: Timestamp,
+}
+
+/// An owner the ownable passed to, by transfer or bridge release
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OwnershipRecord {
+    pub owner: Addr,
+    pub height: u64,
+    pub timestamp: Timestamp,
+    /// Memo of the transfer that made this owner, if it carried one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
+}
+
+pub const CONFIG: Item<Option<Config>> = Item::new("config");
+pub const OWNABLE_INFO: Item<OwnableInfo> = Item::new("ownable_info");
+pub const METADATA: Item<Metadata> = Item::new("metadata");
+// NFTs backing the ownable; a bridge event for any of them drives it
+pub const NFT_ITEMS: Item<Vec<NFT>> = Item::new("nfts");
+// single backing NFT of the first layout, moved into NFT_ITEMS by migrate
+pub const LEGACY_NFT_ITEM: Item<NFT> = Item::new("nft");
+pub const LOCKED: Item<bool> = Item::new("is_locked");
+pub const PACKAGE_CID: Item<String> = Item::new("package_cid");
+pub const NETWORK_ID: Item<u8> = Item::new("network_id");
+pub const OWNABLE_ID: Item<String> = Item::new("ownable_id");
+// credited creator, separate from the issuer that holds on-chain authority
+pub const CREATOR: Item<Option<Addr>> = Item::new("creator");
+pub const LOCK_ORIGIN: Item<LockOrigin> = Item::new("lock_origin");
+pub const LOCK_INFO: Item<Option<LockInfo>> = Item::new("lock_info");
+pub const THUMBNAIL: Item<Thumbnail> = Item::new("thumbnail");
+// set once the backing NFT is burned on its origin chain
+pub const NATIVE: Item<bool> = Item::new("is_native");
+// most recent processed external events, oldest first
+pub const EVENT_LOG: Item<Vec<EventRecord>> = Item::new("event_log");
+// owners in the order they received the ownable, keyed by an index counting
+// from 0; only the most recent records are kept
+pub const OWNERSHIP_HISTORY: Map<u64, OwnershipRecord> = Map::new("ownership_history");
+// base58 public key of the owner, while it is known: the instantiating key or
+// the NFT owner's key from a bridge release; a transfer only names an address
+pub const OWNER_PUBLIC_KEY: Item<Option<String>> = Item::new("owner_public_key");
+// address the owner allowed to move the ownable with `TransferFrom`
+pub const APPROVAL: Item<Option<Addr>> = Item::new("approval");
+pub const LEASE: Item<Option<Lease>> = Item::new("lease");
+// set once the owner permanently retires the ownable
+pub const BURNED: Item<bool> = Item::new("is_burned");
+// shares mode: fractional holdings that replace the single owner for transfers
+pub const SHARES: Map<&Addr, u64> = Map::new("shares");
+pub const TOTAL_SHARES: Item<u64> = Item::new("total_shares");
+pub const ATTRIBUTES: Item<Vec<Trait>> = Item::new("attributes");
+// further eip155 networks the backing nfts may be locked on after bridging
+pub const ALLOWED_NETWORKS: Item<Vec<String>> = Item::new("allowed_networks");
+// external events already applied, keyed by `<network>/<event_id>`
+pub const PROCESSED_EVENTS: Map<String, bool> = Map::new("processed_events");
+// keys of `PROCESSED_EVENTS` in the order they were applied, so the oldest
+// can be dropped once the cap is reached
+pub const PROCESSED_EVENT_ORDER: Map<u64, String> = Map::new("processed_event_order");
+/// Ownables fused into this one by `Merge`, oldest first
+pub const MERGED_FROM: Item<Vec<Addr>> = Item::new("merged_from");
+// ownables the owner burned to merge into this one, registered through an
+// `ownable_burn` external event and consumed by `Merge`
+pub const MERGE_CANDIDATES: Map<&Addr, Timestamp> = Map::new("merge_candidates");
+// unix time in seconds the subscription stays active until
+#[cfg(feature = "subscription")]
+pub const EXPIRES_AT: Item<u64> = Item::new("expires_at");
+// uses left before the consumable is exhausted
+#[cfg(feature = "consumable")]
+pub const USES_REMAINING: Item<u32> = Item::new("uses_remaining");
+#[cfg(feature = "artwork")]
+pub const ARTWORK: Item<ArtworkInfo> = Item::new("artwork");
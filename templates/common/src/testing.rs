@@ -0,0 +1,124 @@
+//! Helpers for running the contract natively, shared by the unit tests and
+//! `examples/simulate.rs`
+
+use cosmwasm_std::testing::{MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{
+    Addr, Api, CanonicalAddr, OwnedDeps, RecoverPubkeyError, StdError, StdResult,
+    VerificationError,
+};
+use ownable_std::address_lto;
+use serde_json::{json, Value};
+
+use crate::msg::InstantiateMsg;
+
+pub const NETWORK_ID: u8 = b'T';
+
+/// `MockApi` rejects mixed-case addresses, but LTO addresses are base58
+#[derive(Default)]
+pub struct LtoApi(MockApi);
+
+impl Api for LtoApi {
+    fn addr_validate(&self, human: &str) -> StdResult<Addr> {
+        if human.is_empty() {
+            return Err(StdError::generic_err("Empty address"));
+        }
+        Ok(Addr::unchecked(human))
+    }
+
+    fn addr_canonicalize(&self, human: &str) -> StdResult<CanonicalAddr> {
+        Ok(CanonicalAddr::from(human.as_bytes()))
+    }
+
+    fn addr_humanize(&self, canonical: &CanonicalAddr) -> StdResult<Addr> {
+        Ok(Addr::unchecked(String::from_utf8(canonical.as_slice().to_vec())?))
+    }
+
+    fn secp256k1_verify(
+        &self,
+        message_hash: &[u8],
+        signature: &[u8],
+        public_key: &[u8],
+    ) -> Result<bool, VerificationError> {
+        self.0.secp256k1_verify(message_hash, signature, public_key)
+    }
+
+    fn secp256k1_recover_pubkey(
+        &self,
+        message_hash: &[u8],
+        signature: &[u8],
+        recovery_param: u8,
+    ) -> Result<Vec<u8>, RecoverPubkeyError> {
+        self.0.secp256k1_recover_pubkey(message_hash, signature, recovery_param)
+    }
+
+    fn ed25519_verify(
+        &self,
+        message: &[u8],
+        signature: &[u8],
+        public_key: &[u8],
+    ) -> Result<bool, VerificationError> {
+        self.0.ed25519_verify(message, signature, public_key)
+    }
+
+    fn ed25519_batch_verify(
+        &self,
+        messages: &[&[u8]],
+        signatures: &[&[u8]],
+        public_keys: &[&[u8]],
+    ) -> Result<bool, VerificationError> {
+        self.0.ed25519_batch_verify(messages, signatures, public_keys)
+    }
+
+    fn debug(&self, message: &str) {
+        println!("{}", message);
+    }
+}
+
+pub fn mock_lto_deps() -> OwnedDeps<MockStorage, LtoApi, MockQuerier> {
+    OwnedDeps {
+        storage: MockStorage::default(),
+        api: LtoApi::default(),
+        querier: MockQuerier::default(),
+        custom_query_type: Default::default(),
+    }
+}
+
+/// A fixed base58 public key, so every run derives the same addresses
+pub fn public_key(seed: u8) -> Addr {
+    Addr::unchecked(bs58::encode([seed; 32]).into_string())
+}
+
+/// The LTO address of `public_key(seed)`
+pub fn address(seed: u8) -> Addr {
+    address_lto(NETWORK_ID as char, public_key(seed).to_string())
+        .expect("derive address")
+}
+
+/// The smallest valid `InstantiateMsg` for this ownable type, with `fields`
+/// set on top of it
+pub fn instantiate_msg(fields: Value) -> InstantiateMsg {
+    #[allow(unused_mut)]
+    let mut msg = json!({
+        "ownable_id": "simulate",
+        "package": "bafkreihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku",
+        "network_id": NETWORK_ID,
+    });
+    #[cfg(feature = "subscription")]
+    {
+        msg["expires_at"] = json!(4_102_444_800u64);
+    }
+    #[cfg(feature = "consumable")]
+    {
+        msg["uses"] = json!(3);
+    }
+    #[cfg(feature = "artwork")]
+    {
+        msg["artwork"] = json!({ "medium": "digital", "year": 2015 });
+    }
+    if let Value::Object(fields) = fields {
+        for (key, value) in fields {
+            msg[key] = value;
+        }
+    }
+    serde_json::from_value(msg).expect("build InstantiateMsg")
+}
@@ -0,0 +1,167 @@
+use cosmwasm_std::Addr;
+use serde_json::json;
+
+use super::{attr, exec, query_as, setup, TestDeps, ALICE, BOB, ISSUER};
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, OwnerResponse, QueryMsg};
+use crate::testing::address;
+
+const CAROL: u8 = 4;
+
+fn transfer_and_approve(recipient: u8, spender: u8) -> ExecuteMsg {
+    ExecuteMsg::TransferAndApprove {
+        recipient: address(recipient).to_string(),
+        spender: address(spender).to_string(),
+    }
+}
+
+fn transfer_from(recipient: u8) -> ExecuteMsg {
+    ExecuteMsg::TransferFrom { recipient: address(recipient).to_string() }
+}
+
+fn owner(deps: &TestDeps) -> Addr {
+    query_as::<OwnerResponse>(deps, QueryMsg::GetOwner {}).owner
+}
+
+fn approve(spender: u8) -> ExecuteMsg {
+    ExecuteMsg::Approve { spender: address(spender).to_string() }
+}
+
+#[test]
+fn approved_spender_transfers_once() {
+    let mut deps = setup(json!({}));
+    exec(&mut deps, ISSUER, approve(BOB)).unwrap();
+
+    let response = exec(&mut deps, BOB, transfer_from(ALICE)).unwrap();
+
+    assert_eq!(attr(&response, "spender"), Some(address(BOB).as_str()));
+    assert_eq!(owner(&deps), address(ALICE));
+    let error = exec(&mut deps, BOB, transfer_from(CAROL)).unwrap_err();
+    assert!(matches!(error, ContractError::Unauthorized { .. }));
+}
+
+#[test]
+fn unapproved_sender_is_rejected() {
+    let mut deps = setup(json!({}));
+    exec(&mut deps, ISSUER, approve(BOB)).unwrap();
+
+    let error = exec(&mut deps, ALICE, transfer_from(ALICE)).unwrap_err();
+
+    assert!(matches!(error, ContractError::Unauthorized { .. }));
+    assert_eq!(owner(&deps), address(ISSUER));
+}
+
+#[test]
+fn only_the_owner_approves_and_revokes() {
+    let mut deps = setup(json!({}));
+
+    let error = exec(&mut deps, BOB, approve(BOB)).unwrap_err();
+    assert!(matches!(error, ContractError::Unauthorized { .. }));
+
+    exec(&mut deps, ISSUER, approve(BOB)).unwrap();
+    let error = exec(&mut deps, BOB, ExecuteMsg::RevokeApproval {}).unwrap_err();
+    assert!(matches!(error, ContractError::Unauthorized { .. }));
+}
+
+#[test]
+fn owner_transfer_clears_the_approval() {
+    let mut deps = setup(json!({}));
+    exec(&mut deps, ISSUER, approve(BOB)).unwrap();
+    exec(&mut deps, ISSUER, ExecuteMsg::Transfer {
+        recipient: address(ALICE).to_string(),
+        memo: None,
+        sale_price: None,
+    })
+    .unwrap();
+
+    let error = exec(&mut deps, BOB, transfer_from(CAROL)).unwrap_err();
+
+    assert!(matches!(error, ContractError::Unauthorized { .. }));
+    assert_eq!(owner(&deps), address(ALICE));
+}
+
+#[test]
+fn transfer_from_to_the_current_owner_is_rejected() {
+    let mut deps = setup(json!({}));
+    exec(&mut deps, ISSUER, approve(BOB)).unwrap();
+
+    let error = exec(&mut deps, BOB, transfer_from(ISSUER)).unwrap_err();
+
+    assert!(matches!(error, ContractError::CustomError { .. }));
+}
+
+#[test]
+fn recipient_owns_it_with_the_spender_approved() {
+    let mut deps = setup(json!({}));
+
+    let response = exec(&mut deps, ISSUER, transfer_and_approve(ALICE, BOB)).unwrap();
+
+    assert_eq!(attr(&response, "new_owner"), Some(address(ALICE).as_str()));
+    assert_eq!(attr(&response, "spender"), Some(address(BOB).as_str()));
+    assert_eq!(owner(&deps), address(ALICE));
+    exec(&mut deps, BOB, transfer_from(CAROL)).unwrap();
+    assert_eq!(owner(&deps), address(CAROL));
+}
+
+#[test]
+fn recipient_revokes_the_approval() {
+    let mut deps = setup(json!({}));
+    exec(&mut deps, ISSUER, transfer_and_approve(ALICE, BOB)).unwrap();
+
+    exec(&mut deps, ALICE, ExecuteMsg::RevokeApproval {}).unwrap();
+
+    let error = exec(&mut deps, BOB, transfer_from(CAROL)).unwrap_err();
+    assert!(matches!(error, ContractError::Unauthorized { .. }));
+    assert_eq!(owner(&deps), address(ALICE));
+}
+
+#[test]
+fn sender_cannot_approve_itself() {
+    let mut deps = setup(json!({}));
+
+    let error = exec(&mut deps, ISSUER, transfer_and_approve(ALICE, ISSUER)).unwrap_err();
+
+    assert!(matches!(error, ContractError::Validation { field, .. } if field == "spender"));
+    assert_eq!(owner(&deps), address(ISSUER));
+}
+
+#[test]
+fn recipient_is_not_its_own_spender() {
+    let mut deps = setup(json!({}));
+
+    let error = exec(&mut deps, ISSUER, transfer_and_approve(ALICE, ALICE)).unwrap_err();
+
+    assert!(matches!(error, ContractError::Validation { field, .. } if field == "spender"));
+}
+
+#[test]
+fn approved_spender_cannot_pass_on_an_approval() {
+    let mut deps = setup(json!({}));
+    exec(&mut deps, ISSUER, approve(BOB)).unwrap();
+
+    let error = exec(&mut deps, BOB, transfer_and_approve(ALICE, CAROL)).unwrap_err();
+
+    assert!(matches!(error, ContractError::Unauthorized { .. }));
+    assert_eq!(owner(&deps), address(ISSUER));
+}
+
+#[test]
+fn locked_ownable_is_not_transferred_or_approved() {
+    let mut deps = setup(json!({}));
+    exec(&mut deps, ISSUER, ExecuteMsg::Lock {}).unwrap();
+
+    let error = exec(&mut deps, ISSUER, transfer_and_approve(ALICE, BOB)).unwrap_err();
+
+    assert!(matches!(error, ContractError::Locked {}));
+    assert_eq!(owner(&deps), address(ISSUER));
+}
+
+#[test]
+fn transfer_and_approve_pays_no_royalty() {
+    let mut deps = setup(json!({ "royalty_bps": 500 }));
+
+    let response = exec(&mut deps, ISSUER, transfer_and_approve(ALICE, BOB)).unwrap();
+
+    assert!(response.messages.is_empty());
+    assert_eq!(owner(&deps), address(ALICE));
+}
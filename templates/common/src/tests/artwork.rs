@@ -0,0 +1,77 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use serde_json::{json, Value};
+
+use super::{query_as, setup, ISSUER};
+use crate::contract::instantiate;
+use crate::error::ContractError;
+use crate::msg::QueryMsg;
+use crate::state::ArtworkInfo;
+use crate::testing::{instantiate_msg, mock_lto_deps, public_key};
+
+fn invalid_field(artwork: Value) -> String {
+    let mut deps = mock_lto_deps();
+    let error = instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(public_key(ISSUER).as_str(), &[]),
+        instantiate_msg(json!({ "artwork": artwork })),
+    )
+    .unwrap_err();
+
+    match error {
+        ContractError::Validation { field, .. } => field,
+        other => panic!("expected a validation error, got {:?}", other),
+    }
+}
+
+#[test]
+fn artwork_fields_are_returned_by_the_query() {
+    let deps = setup(json!({
+        "artwork": {
+            "medium": "oil on canvas",
+            "dimensions": "60 x 90 cm",
+            "year": 2018,
+            "edition": 3,
+            "edition_total": 10,
+        },
+    }));
+
+    let artwork: ArtworkInfo = query_as(&deps, QueryMsg::GetArtworkInfo {});
+
+    assert_eq!(artwork, ArtworkInfo {
+        medium: "oil on canvas".to_string(),
+        dimensions: Some("60 x 90 cm".to_string()),
+        year: 2018,
+        edition: Some(3),
+        edition_total: Some(10),
+    });
+}
+
+#[test]
+fn edition_is_optional() {
+    let deps = setup(json!({ "artwork": { "medium": "generative", "year": 2019 } }));
+
+    let artwork: ArtworkInfo = query_as(&deps, QueryMsg::GetArtworkInfo {});
+
+    assert_eq!(artwork.edition, None);
+    assert_eq!(artwork.edition_total, None);
+}
+
+#[test]
+fn invalid_artwork_names_the_field() {
+    let cases = [
+        (json!({ "medium": " ", "year": 2015 }), "artwork.medium"),
+        (json!({ "medium": "digital", "dimensions": "", "year": 2015 }), "artwork.dimensions"),
+        (json!({ "medium": "digital", "year": 999 }), "artwork.year"),
+        // mock_env's block time falls in 2019
+        (json!({ "medium": "digital", "year": 2020 }), "artwork.year"),
+        (json!({ "medium": "digital", "year": 2015, "edition": 0, "edition_total": 5 }), "artwork.edition"),
+        (json!({ "medium": "digital", "year": 2015, "edition": 6, "edition_total": 5 }), "artwork.edition"),
+        (json!({ "medium": "digital", "year": 2015, "edition": 1 }), "artwork.edition"),
+        (json!({ "medium": "digital", "year": 2015, "edition_total": 5 }), "artwork.edition"),
+    ];
+
+    for (artwork, expected) in cases {
+        assert_eq!(invalid_field(artwork.clone()), expected, "{}", artwork);
+    }
+}
@@ -0,0 +1,46 @@
+//! Response attributes indexers correlate actions by
+
+use cosmwasm_std::testing::{mock_env, mock_info};
+use serde_json::json;
+
+use super::{attr, exec, setup, ALICE, ISSUER};
+use crate::contract::instantiate;
+use crate::msg::ExecuteMsg;
+use crate::testing::{address, instantiate_msg, mock_lto_deps, public_key};
+
+#[test]
+fn instantiate_reports_the_ownable_id() {
+    let mut deps = mock_lto_deps();
+
+    let response = instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(public_key(ISSUER).as_str(), &[]),
+        instantiate_msg(json!({ "ownable_id": "ownable-1" })),
+    )
+    .unwrap();
+
+    assert_eq!(attr(&response, "ownable_id"), Some("ownable-1"));
+}
+
+#[test]
+fn every_execute_reports_the_ownable_id() {
+    let mut deps = setup(json!({ "ownable_id": "ownable-1" }));
+    let messages = [
+        ExecuteMsg::Lock {},
+        ExecuteMsg::Unlock {},
+        ExecuteMsg::Approve { spender: address(ALICE).to_string() },
+        ExecuteMsg::RevokeApproval {},
+        ExecuteMsg::Transfer {
+            recipient: address(ALICE).to_string(),
+            memo: None,
+            sale_price: None,
+        },
+    ];
+
+    for msg in messages {
+        let response = exec(&mut deps, ISSUER, msg).unwrap();
+        assert_eq!(attr(&response, "ownable_id"), Some("ownable-1"));
+        assert_eq!(response.attributes.iter().filter(|a| a.key == "ownable_id").count(), 1);
+    }
+}
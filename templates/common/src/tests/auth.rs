@@ -0,0 +1,66 @@
+use cosmwasm_std::Response;
+use serde_json::{json, Value};
+
+use super::{exec, query_as, setup, TestDeps, ALICE, BOB, ISSUER};
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, OwnerResponse, QueryMsg};
+use crate::testing::address;
+
+fn transfer(recipient: u8) -> ExecuteMsg {
+    ExecuteMsg::Transfer { recipient: address(recipient).to_string(), memo: None, sale_price: None }
+}
+
+/// Owned by `ALICE`, who approved `BOB`, under the given policies
+fn owned_by_alice(policies: Value) -> TestDeps {
+    let mut deps = setup(policies);
+    exec(&mut deps, ISSUER, transfer(ALICE)).unwrap();
+    exec(&mut deps, ALICE, ExecuteMsg::Approve { spender: address(BOB).to_string() }).unwrap();
+    deps
+}
+
+fn owner(deps: &TestDeps) -> String {
+    query_as::<OwnerResponse>(deps, QueryMsg::GetOwner {}).owner.to_string()
+}
+
+fn assert_unauthorized(result: Result<Response, ContractError>) {
+    assert!(matches!(result, Err(ContractError::Unauthorized { .. })), "{:?}", result);
+}
+
+#[test]
+fn owner_only_transfer_policy() {
+    let mut deps = owned_by_alice(json!({ "transfer_policy": "owner_only" }));
+
+    assert_unauthorized(exec(&mut deps, ISSUER, transfer(BOB)));
+    assert_unauthorized(exec(&mut deps, BOB, transfer(BOB)));
+    exec(&mut deps, ALICE, transfer(BOB)).unwrap();
+    assert_eq!(owner(&deps), address(BOB).as_str());
+}
+
+#[test]
+fn owner_or_approved_transfer_policy() {
+    let mut deps = owned_by_alice(json!({ "transfer_policy": "owner_or_approved" }));
+
+    assert_unauthorized(exec(&mut deps, ISSUER, transfer(BOB)));
+    exec(&mut deps, BOB, transfer(ISSUER)).unwrap();
+    assert_eq!(owner(&deps), address(ISSUER).as_str());
+}
+
+#[test]
+fn owner_or_issuer_transfer_policy() {
+    let mut deps = owned_by_alice(json!({ "transfer_policy": "owner_or_issuer" }));
+
+    assert_unauthorized(exec(&mut deps, BOB, transfer(BOB)));
+    exec(&mut deps, ISSUER, transfer(BOB)).unwrap();
+    assert_eq!(owner(&deps), address(BOB).as_str());
+}
+
+#[test]
+fn lock_policy_is_separate_from_the_transfer_policy() {
+    let mut deps = owned_by_alice(json!({
+        "transfer_policy": "owner_or_issuer",
+        "lock_policy": "owner_only",
+    }));
+
+    assert_unauthorized(exec(&mut deps, ISSUER, ExecuteMsg::Lock {}));
+    exec(&mut deps, ALICE, ExecuteMsg::Lock {}).unwrap();
+}
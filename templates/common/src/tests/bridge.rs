@@ -0,0 +1,91 @@
+//! Owner-initiated bridging back to an NFT chain
+
+use cosmwasm_std::testing::mock_env;
+use serde_json::json;
+use sha2::{Digest, Sha256};
+
+use super::{attr, exec, query_as, setup, TestDeps, ALICE, ISSUER};
+use crate::error::ContractError;
+use crate::contract::query;
+use crate::msg::{ExecuteMsg, LockProofResponse, QueryMsg};
+use crate::testing::address;
+
+const NETWORK: &str = "eip155:1";
+
+fn backed() -> TestDeps {
+    setup(json!({ "nft": { "network": NETWORK, "address": "0xabc", "id": "7" } }))
+}
+
+fn bridge(network: &str) -> ExecuteMsg {
+    ExecuteMsg::Bridge { to_network: network.to_string() }
+}
+
+#[test]
+fn bridge_emits_the_backing_nft_for_the_relayer() {
+    let mut deps = backed();
+
+    let response = exec(&mut deps, ISSUER, bridge(NETWORK)).unwrap();
+
+    assert_eq!(attr(&response, "event_type"), Some("lock"));
+    assert_eq!(attr(&response, "owner"), Some(address(ISSUER).as_str()));
+    assert_eq!(attr(&response, "network"), Some(NETWORK));
+    assert_eq!(attr(&response, "contract"), Some("0xabc"));
+    assert_eq!(attr(&response, "token_id"), Some("7"));
+    assert!(query_as::<bool>(&deps, QueryMsg::IsLocked {}));
+}
+
+#[test]
+fn second_bridge_is_rejected() {
+    let mut deps = backed();
+    exec(&mut deps, ISSUER, bridge(NETWORK)).unwrap();
+
+    let error = exec(&mut deps, ISSUER, bridge(NETWORK)).unwrap_err();
+
+    assert!(matches!(error, ContractError::Locked {}));
+}
+
+#[test]
+fn bridge_needs_a_backing_nft_on_the_network() {
+    let mut deps = setup(json!({}));
+    let error = exec(&mut deps, ISSUER, bridge(NETWORK)).unwrap_err();
+    assert!(matches!(error, ContractError::NoNftBacking {}));
+
+    let mut deps = backed();
+    let error = exec(&mut deps, ISSUER, bridge("eip155:137")).unwrap_err();
+    assert!(matches!(error, ContractError::LockError { .. }));
+    assert!(!query_as::<bool>(&deps, QueryMsg::IsLocked {}));
+}
+
+#[test]
+fn only_the_owner_bridges() {
+    let mut deps = backed();
+
+    let error = exec(&mut deps, ALICE, bridge(NETWORK)).unwrap_err();
+
+    assert!(matches!(error, ContractError::Unauthorized { .. }));
+}
+
+#[test]
+fn lock_proof_names_the_nft_and_is_deterministic() {
+    let mut deps = backed();
+    exec(&mut deps, ISSUER, bridge(NETWORK)).unwrap();
+
+    let proof: LockProofResponse = query_as(&deps, QueryMsg::GetLockProof {});
+
+    assert_eq!((proof.network.as_str(), proof.contract.as_str(), proof.token_id.as_str()), (NETWORK, "0xabc", "7"));
+    assert_eq!(proof.owner, address(ISSUER));
+    let (contract_address, owner) = (mock_env().contract.address, address(ISSUER));
+    let signed = ["simulate", contract_address.as_str(), NETWORK, "0xabc", "7", owner.as_str()];
+    assert_eq!(proof.commitment, hex::encode(Sha256::digest(signed.join("\n").as_bytes())));
+    assert_eq!(query_as::<LockProofResponse>(&deps, QueryMsg::GetLockProof {}), proof);
+}
+
+#[test]
+fn owner_lock_has_no_lock_proof() {
+    let mut deps = backed();
+    assert!(query(deps.as_ref(), mock_env(), QueryMsg::GetLockProof {}).is_err());
+
+    exec(&mut deps, ISSUER, ExecuteMsg::Lock {}).unwrap();
+
+    assert!(query(deps.as_ref(), mock_env(), QueryMsg::GetLockProof {}).is_err());
+}
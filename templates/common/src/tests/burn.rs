@@ -0,0 +1,61 @@
+use cosmwasm_std::testing::mock_env;
+use ownable_std::InfoResponse;
+use serde_json::json;
+
+use super::{attr, exec, query_as, setup, ALICE, ISSUER};
+use crate::contract::burn_message;
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, QueryMsg};
+use crate::testing::address;
+
+#[test]
+fn burned_ownable_rejects_every_execute() {
+    let mut deps = setup(json!({}));
+
+    let response = exec(&mut deps, ISSUER, ExecuteMsg::Burn {}).unwrap();
+
+    assert_eq!(attr(&response, "is_burned"), Some("true"));
+    let actions = [
+        ExecuteMsg::Transfer {
+            recipient: address(ALICE).to_string(),
+            memo: None,
+            sale_price: None,
+        },
+        ExecuteMsg::Lock {},
+        ExecuteMsg::Burn {},
+    ];
+    for msg in actions {
+        let error = exec(&mut deps, ISSUER, msg).unwrap_err();
+        assert!(matches!(error, ContractError::Burned {}));
+    }
+}
+
+#[test]
+fn burned_ownable_can_still_be_queried() {
+    let mut deps = setup(json!({}));
+    exec(&mut deps, ISSUER, ExecuteMsg::Burn {}).unwrap();
+
+    let info: InfoResponse = query_as(&deps, QueryMsg::GetInfo {});
+
+    assert_eq!(info.owner, address(ISSUER));
+}
+
+#[test]
+fn only_the_owner_burns() {
+    let mut deps = setup(json!({}));
+
+    let error = exec(&mut deps, ALICE, ExecuteMsg::Burn {}).unwrap_err();
+
+    assert!(matches!(error, ContractError::Unauthorized { .. }));
+    exec(&mut deps, ISSUER, ExecuteMsg::Lock {}).unwrap();
+}
+
+#[test]
+fn burn_returns_the_message_to_sign_for_a_merge() {
+    let mut deps = setup(json!({}));
+
+    let response = exec(&mut deps, ISSUER, ExecuteMsg::Burn {}).unwrap();
+
+    let expected = burn_message(&mock_env().contract.address, &address(ISSUER));
+    assert_eq!(attr(&response, "burn_message"), Some(expected.as_str()));
+}
@@ -0,0 +1,141 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use ownable_std::ExternalEventMsg;
+use serde_json::json;
+
+use super::{exec, query_as, setup, ALICE, ISSUER};
+use crate::caip::{parse_caip2, Caip2};
+use crate::contract::register_external_event;
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, OwnerResponse, QueryMsg};
+use crate::testing::{address, public_key};
+
+#[test]
+fn chain_id_is_split_into_namespace_and_reference() {
+    let chain_id: Caip2 = "eip155:137".parse().unwrap();
+
+    assert_eq!(chain_id, Caip2 { namespace: "eip155".to_string(), reference: "137".to_string() });
+    assert_eq!(chain_id.to_string(), "eip155:137");
+    assert_eq!(parse_caip2("lto:T").unwrap(), ("lto".to_string(), "T".to_string()));
+}
+
+#[test]
+fn malformed_chain_ids_are_rejected() {
+    let long_reference = format!("eip155:{}", "1".repeat(33));
+    let malformed = ["", ":", "eip155", "eip155:", ":1", "eip155:1:2", "EIP155:1", "ab:1", "eip155:1.0", &long_reference];
+
+    for chain_id in malformed {
+        let error = chain_id.parse::<Caip2>().unwrap_err();
+        assert!(
+            matches!(&error, ContractError::MatchChainIdError { val } if val == chain_id),
+            "{:?} gave {:?}",
+            chain_id,
+            error
+        );
+    }
+}
+
+#[test]
+fn event_from_a_malformed_network_is_rejected() {
+    let mut deps = setup(json!({
+        "nft": { "network": "eip155:1", "address": "0xabc", "id": "1" },
+    }));
+    let event: ExternalEventMsg = serde_json::from_value(json!({
+        "network": "eip155",
+        "event_type": "lock",
+        "attributes": {
+            "event_id": "0x01",
+            "ownable_id": "simulate",
+            "owner": address(ISSUER),
+            "token_id": "1",
+            "contract": "0xabc",
+        },
+    }))
+    .unwrap();
+
+    let error = register_external_event(
+        mock_info(public_key(ISSUER).as_str(), &[]),
+        deps.as_mut(),
+        mock_env(),
+        event,
+        "simulate".to_string(),
+    )
+    .unwrap_err();
+
+    assert!(matches!(error, ContractError::MatchChainIdError { val } if val == "eip155"));
+}
+
+#[test]
+fn solana_nft_owner_releases_the_ownable() {
+    let mut deps = setup(json!({
+        "nft": { "network": "solana:mainnet", "address": "Token1111", "id": "1" },
+    }));
+    exec(&mut deps, ISSUER, ExecuteMsg::LockFor {
+        network: "solana:mainnet".to_string(),
+        contract: "Token1111".to_string(),
+        token_id: "1".to_string(),
+    })
+    .unwrap();
+    // a solana owner is its base58 ed25519 public key
+    let nft_owner = public_key(ALICE);
+    let event: ExternalEventMsg = serde_json::from_value(json!({
+        "network": "solana:mainnet",
+        "event_type": "release",
+        "attributes": {
+            "event_id": "1",
+            "ownable_id": "simulate",
+            "owner": nft_owner,
+            "token_id": "1",
+            "contract": "Token1111",
+        },
+    }))
+    .unwrap();
+
+    register_external_event(
+        mock_info(nft_owner.as_str(), &[]),
+        deps.as_mut(),
+        mock_env(),
+        event,
+        "simulate".to_string(),
+    )
+    .unwrap();
+
+    let owner: OwnerResponse = query_as(&deps, QueryMsg::GetOwner {});
+    assert_eq!(owner.owner, address(ALICE));
+}
+
+#[test]
+fn solana_owner_must_be_a_32_byte_key() {
+    let mut deps = setup(json!({
+        "nft": { "network": "solana:mainnet", "address": "Token1111", "id": "1" },
+    }));
+    exec(&mut deps, ISSUER, ExecuteMsg::LockFor {
+        network: "solana:mainnet".to_string(),
+        contract: "Token1111".to_string(),
+        token_id: "1".to_string(),
+    })
+    .unwrap();
+    // an LTO address is base58 too, but 26 bytes long
+    let event: ExternalEventMsg = serde_json::from_value(json!({
+        "network": "solana:mainnet",
+        "event_type": "release",
+        "attributes": {
+            "event_id": "1",
+            "ownable_id": "simulate",
+            "owner": address(ISSUER),
+            "token_id": "1",
+            "contract": "Token1111",
+        },
+    }))
+    .unwrap();
+
+    let error = register_external_event(
+        mock_info(address(ISSUER).as_str(), &[]),
+        deps.as_mut(),
+        mock_env(),
+        event,
+        "simulate".to_string(),
+    )
+    .unwrap_err();
+
+    assert!(matches!(error, ContractError::CustomError { val } if val.starts_with("Invalid solana address")));
+}
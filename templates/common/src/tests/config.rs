@@ -0,0 +1,81 @@
+use cosmwasm_std::Timestamp;
+use serde_json::json;
+
+use super::{attr, exec, query_as, setup, TestDeps, ALICE, BOB, ISSUER};
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, QueryMsg};
+use crate::state::{AuthPolicy, Config, DEFAULT_DENOM};
+use crate::testing::address;
+
+fn update_royalty(royalty_bps: u16) -> ExecuteMsg {
+    ExecuteMsg::UpdateConfig {
+        notify_contract: None,
+        royalty_bps: Some(royalty_bps),
+        accepted_denom: None,
+        transfer_locked_until: None,
+        admin: None,
+    }
+}
+
+fn config(deps: &TestDeps) -> Config {
+    query_as::<Option<Config>>(deps, QueryMsg::GetConfig {}).expect("config")
+}
+
+#[test]
+fn instantiated_config_is_returned() {
+    let deps = setup(json!({
+        "royalty_bps": 250,
+        "transfer_locked_until": Timestamp::from_seconds(1_700_000_000),
+        "admin": address(ALICE),
+        "transfer_policy": "owner_or_issuer",
+    }));
+
+    let config = config(&deps);
+
+    assert_eq!(config.royalty_bps, 250);
+    assert_eq!(config.transfer_locked_until, Some(Timestamp::from_seconds(1_700_000_000)));
+    assert_eq!(config.admin, Some(address(ALICE)));
+    assert_eq!(config.transfer_policy, AuthPolicy::OwnerOrIssuer);
+    assert_eq!(config.lock_policy, AuthPolicy::OwnerOnly);
+}
+
+#[test]
+fn config_defaults_without_fields() {
+    let deps = setup(json!({}));
+
+    let config = config(&deps);
+
+    assert_eq!(config.royalty_bps, 0);
+    assert_eq!(config.accepted_denom, DEFAULT_DENOM);
+    assert!(config.bridgeable);
+    assert_eq!(config.admin, None);
+    assert_eq!(config.transfer_locked_until, None);
+}
+
+#[test]
+fn admin_updates_only_the_given_fields() {
+    let mut deps = setup(json!({ "royalty_bps": 250, "admin": address(ALICE) }));
+    let before = config(&deps);
+
+    let response = exec(&mut deps, ALICE, update_royalty(500)).unwrap();
+
+    assert_eq!(attr(&response, "method"), Some("try_update_config"));
+    let after = config(&deps);
+    assert_eq!(after.royalty_bps, 500);
+    assert_eq!(Config { royalty_bps: before.royalty_bps, ..after }, before);
+}
+
+#[test]
+fn only_the_admin_updates_the_config() {
+    // the owner isn't the admin unless configured as one
+    let mut deps = setup(json!({ "admin": address(ALICE) }));
+    for sender in [ISSUER, BOB] {
+        let error = exec(&mut deps, sender, update_royalty(500)).unwrap_err();
+        assert!(matches!(error, ContractError::Unauthorized { .. }), "{:?}", error);
+    }
+
+    let mut fixed = setup(json!({}));
+    let error = exec(&mut fixed, ISSUER, update_royalty(500)).unwrap_err();
+    assert!(matches!(error, ContractError::Unauthorized { .. }));
+    assert_eq!(config(&fixed).royalty_bps, 0);
+}
@@ -0,0 +1,76 @@
+use serde_json::json;
+
+use super::{attr, exec, query_as, setup, TestDeps, ALICE, ISSUER};
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, OwnerResponse, QueryMsg};
+use crate::testing::address;
+
+fn consumable(uses: u32) -> TestDeps {
+    setup(json!({ "uses": uses }))
+}
+
+fn uses_remaining(deps: &TestDeps) -> u32 {
+    query_as(deps, QueryMsg::GetUsesRemaining {})
+}
+
+fn consume(amount: u32) -> ExecuteMsg {
+    ExecuteMsg::Consume { amount }
+}
+
+#[test]
+fn partial_consumption_decrements_the_counter() {
+    let mut deps = consumable(5);
+
+    let response = exec(&mut deps, ISSUER, consume(2)).unwrap();
+
+    assert_eq!(attr(&response, "uses_remaining"), Some("3"));
+    assert_eq!(uses_remaining(&deps), 3);
+}
+
+#[test]
+fn over_consumption_leaves_the_counter_untouched() {
+    let mut deps = consumable(2);
+
+    let error = exec(&mut deps, ISSUER, consume(3)).unwrap_err();
+
+    assert!(matches!(error, ContractError::InsufficientUses { requested: 3, remaining: 2 }));
+    assert_eq!(uses_remaining(&deps), 2);
+}
+
+#[test]
+fn exhausted_consumable_is_still_transferred() {
+    let mut deps = consumable(1);
+    exec(&mut deps, ISSUER, consume(1)).unwrap();
+
+    let error = exec(&mut deps, ISSUER, consume(1)).unwrap_err();
+    assert!(matches!(error, ContractError::InsufficientUses { requested: 1, remaining: 0 }));
+
+    exec(&mut deps, ISSUER, ExecuteMsg::Transfer {
+        recipient: address(ALICE).to_string(),
+        memo: None,
+        sale_price: None,
+    })
+    .unwrap();
+    let owner: OwnerResponse = query_as(&deps, QueryMsg::GetOwner {});
+    assert_eq!(owner.owner, address(ALICE));
+    assert_eq!(uses_remaining(&deps), 0);
+}
+
+#[test]
+fn only_the_owner_consumes() {
+    let mut deps = consumable(3);
+
+    let error = exec(&mut deps, ALICE, consume(1)).unwrap_err();
+
+    assert!(matches!(error, ContractError::Unauthorized { .. }));
+    assert_eq!(uses_remaining(&deps), 3);
+}
+
+#[test]
+fn zero_amount_is_rejected() {
+    let mut deps = consumable(3);
+
+    let error = exec(&mut deps, ISSUER, consume(0)).unwrap_err();
+
+    assert!(matches!(error, ContractError::Validation { field, .. } if field == "amount"));
+}
@@ -0,0 +1,67 @@
+//! Counters and amounts at the edge of their integer range
+
+use cosmwasm_std::testing::mock_env;
+use cosmwasm_std::{coin, Coin, Uint128};
+use serde_json::json;
+
+use super::{attr, exec, exec_at, query_as, setup, ALICE, BOB, ISSUER};
+#[cfg(any(feature = "subscription", feature = "consumable"))]
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, QueryMsg};
+use crate::testing::address;
+
+#[test]
+fn shares_reach_u64_max() {
+    let mut deps = setup(json!({
+        "total_shares": u64::MAX,
+        "shares": [
+            { "holder": address(ALICE), "amount": u64::MAX - 1 },
+            { "holder": address(BOB), "amount": 1 },
+        ],
+    }));
+
+    exec(&mut deps, BOB, ExecuteMsg::TransferShares { recipient: address(ALICE).to_string(), amount: 1 })
+        .unwrap();
+
+    let held: u64 = query_as(&deps, QueryMsg::GetShares { address: address(ALICE).to_string() });
+    assert_eq!(held, u64::MAX);
+}
+
+#[test]
+fn royalty_on_the_largest_price_does_not_overflow() {
+    let mut deps = setup(json!({ "royalty_bps": 10_000 }));
+    let price = Coin { denom: "lto".to_string(), amount: Uint128::MAX };
+    let funds = [price.clone()];
+
+    let response = exec_at(&mut deps, mock_env(), ISSUER, &funds, ExecuteMsg::Transfer {
+        recipient: address(ALICE).to_string(),
+        memo: None,
+        sale_price: Some(price),
+    })
+    .unwrap();
+
+    assert_eq!(attr(&response, "royalty"), Some(coin(u128::MAX, "lto").to_string().as_str()));
+}
+
+#[cfg(feature = "subscription")]
+#[test]
+fn renewal_past_u64_max_is_rejected() {
+    let mut deps = setup(json!({ "expires_at": u64::MAX - 10 }));
+
+    exec(&mut deps, ISSUER, ExecuteMsg::Renew { additional_secs: 10 }).unwrap();
+    let error = exec(&mut deps, ISSUER, ExecuteMsg::Renew { additional_secs: 1 }).unwrap_err();
+
+    assert!(matches!(error, ContractError::Validation { field, .. } if field == "additional_secs"));
+}
+
+#[cfg(feature = "consumable")]
+#[test]
+fn consuming_more_than_remains_is_rejected() {
+    let mut deps = setup(json!({ "uses": 3 }));
+
+    exec(&mut deps, ISSUER, ExecuteMsg::Consume { amount: 3 }).unwrap();
+    let error = exec(&mut deps, ISSUER, ExecuteMsg::Consume { amount: 1 }).unwrap_err();
+
+    assert!(matches!(error, ContractError::InsufficientUses { requested: 1, remaining: 0 }));
+    assert_eq!(query_as::<u32>(&deps, QueryMsg::GetUsesRemaining {}), 0);
+}
@@ -0,0 +1,86 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::{from_json, StdError};
+use ownable_std::{address_eip155, ExternalEventMsg};
+use serde_json::json;
+
+use super::{exec, secp256k1_key, setup, TestDeps, ALICE, ISSUER};
+use crate::contract::{instantiate, query, register_external_event};
+use crate::msg::{Eip155Response, ExecuteMsg, QueryMsg};
+use crate::testing::{address, instantiate_msg, mock_lto_deps};
+
+fn eip155_address(deps: &TestDeps) -> Result<String, StdError> {
+    query(deps.as_ref(), mock_env(), QueryMsg::GetEip155Address {})
+        .map(|binary| from_json::<Eip155Response>(&binary).unwrap().address)
+}
+
+#[test]
+fn instantiating_secp256k1_key_gives_the_eip155_address() {
+    let key = secp256k1_key(7);
+    let mut deps = mock_lto_deps();
+    instantiate(deps.as_mut(), mock_env(), mock_info(&key, &[]), instantiate_msg(json!({}))).unwrap();
+
+    let address = eip155_address(&deps).unwrap();
+
+    assert_eq!(address, address_eip155(key).unwrap().to_string());
+    assert!(address.starts_with("0x") && address.len() == 42, "{}", address);
+}
+
+#[test]
+fn bridge_release_records_the_address_lock_events_compare() {
+    let mut deps = setup(json!({
+        "nft": { "network": "eip155:1", "address": "0xabc", "id": "1" },
+    }));
+    exec(&mut deps, ISSUER, ExecuteMsg::LockFor {
+        network: "eip155:1".to_string(),
+        contract: "0xabc".to_string(),
+        token_id: "1".to_string(),
+    })
+    .unwrap();
+    let nft_owner = secp256k1_key(8);
+    let event: ExternalEventMsg = serde_json::from_value(json!({
+        "network": "eip155:1",
+        "event_type": "release",
+        "attributes": {
+            "event_id": "0x01",
+            "ownable_id": "simulate",
+            "owner": nft_owner,
+            "token_id": "1",
+            "contract": "0xabc",
+        },
+    }))
+    .unwrap();
+    register_external_event(
+        mock_info(&nft_owner, &[]),
+        deps.as_mut(),
+        mock_env(),
+        event,
+        "simulate".to_string(),
+    )
+    .unwrap();
+
+    assert_eq!(eip155_address(&deps).unwrap(), address_eip155(nft_owner).unwrap().to_string());
+}
+
+#[test]
+fn ed25519_owner_has_no_eip155_address() {
+    let deps = setup(json!({}));
+
+    let error = eip155_address(&deps).unwrap_err();
+
+    assert!(error.to_string().contains("Not a secp256k1 public key"), "{}", error);
+}
+
+#[test]
+fn transferred_owner_has_no_known_key() {
+    let mut deps = setup(json!({}));
+    exec(&mut deps, ISSUER, ExecuteMsg::Transfer {
+        recipient: address(ALICE).to_string(),
+        memo: None,
+        sale_price: None,
+    })
+    .unwrap();
+
+    let error = eip155_address(&deps).unwrap_err();
+
+    assert!(error.to_string().contains("public key is not known"), "{}", error);
+}
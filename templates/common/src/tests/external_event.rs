@@ -0,0 +1,298 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use ownable_std::{address_lto, ExternalEventMsg, NFT};
+use serde_json::{json, Value};
+
+use super::{attr, exec, exec_as, query_as, relay, secp256k1_key, setup, TestDeps, ISSUER};
+use crate::contract::register_external_event;
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, OwnerResponse, QueryMsg};
+use crate::pagination::Page;
+use crate::state::{EventRecord, OwnershipRecord};
+use crate::testing::{address, public_key, NETWORK_ID};
+
+const NETWORK: &str = "eip155:1";
+const CONTRACT: &str = "0xabc";
+
+fn lock_event_error(attributes: Value) -> ContractError {
+    let mut deps = setup(json!({
+        "nft": { "network": "eip155:1", "address": "0xabc", "id": "1" },
+    }));
+    let event: ExternalEventMsg = serde_json::from_value(json!({
+        "network": "eip155:1",
+        "event_type": "lock",
+        "attributes": attributes,
+    }))
+    .unwrap();
+
+    register_external_event(
+        mock_info(public_key(ISSUER).as_str(), &[]),
+        deps.as_mut(),
+        mock_env(),
+        event,
+        "simulate".to_string(),
+    )
+    .unwrap_err()
+}
+
+fn lock_attributes() -> Value {
+    json!({
+        "event_id": "0x01",
+        "ownable_id": "simulate",
+        "owner": address(ISSUER),
+        "token_id": "1",
+        "contract": "0xabc",
+    })
+}
+
+#[test]
+fn missing_attribute_is_named() {
+    for key in ["owner", "token_id", "contract"] {
+        let mut attributes = lock_attributes();
+        attributes.as_object_mut().unwrap().remove(key);
+
+        let error = lock_event_error(attributes);
+
+        assert!(
+            matches!(&error, ContractError::InvalidExternalEventArgs { key: missing } if missing == key),
+            "{} gave {:?}",
+            key,
+            error
+        );
+    }
+}
+
+#[test]
+fn empty_attribute_is_named() {
+    for key in ["owner", "token_id", "contract"] {
+        let mut attributes = lock_attributes();
+        attributes[key] = json!("");
+
+        let error = lock_event_error(attributes);
+
+        assert!(
+            matches!(&error, ContractError::Validation { field, .. } if field == key),
+            "{} gave {:?}",
+            key,
+            error
+        );
+    }
+}
+
+/// An ownable backed by token 1 of `CONTRACT` on `NETWORK`, with `fields` set
+/// on the `InstantiateMsg`, and locked for it by the issuer
+fn bridged(fields: Value) -> TestDeps {
+    let mut fields = fields;
+    if fields.get("nft").is_none() {
+        fields["nft"] = json!({ "network": NETWORK, "address": CONTRACT, "id": "1" });
+    }
+    let mut deps = setup(fields);
+    exec(&mut deps, ISSUER, lock_for(NETWORK, "1")).unwrap();
+    deps
+}
+
+fn lock_for(network: &str, token_id: &str) -> ExecuteMsg {
+    ExecuteMsg::LockFor {
+        network: network.to_string(),
+        contract: CONTRACT.to_string(),
+        token_id: token_id.to_string(),
+    }
+}
+
+/// Attributes of an event for token `token_id` owned by the key `owner`
+fn nft_event(event_id: &str, owner: &str, token_id: &str) -> Value {
+    json!({ "event_id": event_id, "owner": owner, "token_id": token_id, "contract": CONTRACT })
+}
+
+/// The LTO address of the NFT owner's key
+fn lto_address(key: &str) -> String {
+    address_lto(NETWORK_ID as char, key.to_string()).unwrap().to_string()
+}
+
+fn owner(deps: &TestDeps) -> String {
+    query_as::<OwnerResponse>(deps, QueryMsg::GetOwner {}).owner.to_string()
+}
+
+fn is_locked(deps: &TestDeps) -> bool {
+    query_as(deps, QueryMsg::IsLocked {})
+}
+
+#[test]
+fn processed_event_appears_in_the_log() {
+    let mut deps = bridged(json!({}));
+    let key = secp256k1_key(8);
+    // a rejected event isn't logged
+    relay(&mut deps, &key, NETWORK, "release", nft_event("0x01", &key, "2")).unwrap_err();
+
+    relay(&mut deps, &key, NETWORK, "release", nft_event("0x02", &key, "1")).unwrap();
+
+    let log: Page<EventRecord> = query_as(&deps, QueryMsg::GetExternalEventLog { start_after: None, limit: None });
+    assert_eq!(log.items, vec![EventRecord {
+        event_type: "release".to_string(),
+        network: Some(NETWORK.to_string()),
+        token_id: Some("1".to_string()),
+        at: mock_env().block.time,
+    }]);
+}
+
+#[test]
+fn lock_event_without_a_backing_nft_is_rejected() {
+    let mut deps = setup(json!({}));
+    let key = secp256k1_key(8);
+
+    for event_type in ["lock", "release", "burn"] {
+        let error = relay(&mut deps, &key, NETWORK, event_type, nft_event("0x01", &key, "1")).unwrap_err();
+        assert!(matches!(error, ContractError::NoNftBacking {}), "{} gave {:?}", event_type, error);
+    }
+}
+
+#[test]
+fn non_bridgeable_ownable_rejects_bridge_events_and_locks() {
+    let mut deps = setup(json!({
+        "bridgeable": false,
+        "nft": { "network": NETWORK, "address": CONTRACT, "id": "1" },
+    }));
+    let key = secp256k1_key(8);
+
+    for event_type in ["lock", "release", "burn"] {
+        let error = relay(&mut deps, &key, NETWORK, event_type, nft_event("0x01", &key, "1")).unwrap_err();
+        assert!(matches!(error, ContractError::NotBridgeable {}), "{} gave {:?}", event_type, error);
+    }
+    for msg in [ExecuteMsg::Lock {}, lock_for(NETWORK, "1"), ExecuteMsg::Bridge { to_network: NETWORK.to_string() }] {
+        let error = exec(&mut deps, ISSUER, msg.clone()).unwrap_err();
+        assert!(matches!(error, ContractError::NotBridgeable {}), "{:?} gave {:?}", msg, error);
+    }
+}
+
+#[test]
+fn redundant_release_is_a_no_op() {
+    let mut deps = bridged(json!({}));
+    let key = secp256k1_key(8);
+    relay(&mut deps, &key, NETWORK, "release", nft_event("0x01", &key, "1")).unwrap();
+    let history: Page<OwnershipRecord> = query_as(&deps, QueryMsg::GetOwnershipHistory { start_after: None, limit: None });
+
+    // a second relay of the release, under another event id
+    let response = relay(&mut deps, &key, NETWORK, "release", nft_event("0x02", &key, "1")).unwrap();
+
+    assert_eq!(attr(&response, "noop"), Some("true"));
+    assert_eq!(owner(&deps), lto_address(&key));
+    assert!(!is_locked(&deps));
+    let after: Page<OwnershipRecord> = query_as(&deps, QueryMsg::GetOwnershipHistory { start_after: None, limit: None });
+    assert_eq!(after, history);
+}
+
+#[test]
+fn release_event_returns_the_ownable_to_the_nft_owner() {
+    let mut deps = bridged(json!({}));
+    let key = secp256k1_key(8);
+
+    let response = relay(&mut deps, &key, NETWORK, "release", nft_event("0x01", &key, "1")).unwrap();
+
+    assert_eq!(attr(&response, "event_type"), Some("release"));
+    assert_eq!(owner(&deps), lto_address(&key));
+    assert!(!is_locked(&deps));
+}
+
+#[test]
+fn release_event_for_another_nft_is_rejected() {
+    let mut deps = bridged(json!({}));
+    let key = secp256k1_key(8);
+
+    let error = relay(&mut deps, &key, NETWORK, "release", nft_event("0x01", &key, "2")).unwrap_err();
+
+    assert!(matches!(error, ContractError::LockError { val } if val == "Not a backing nft"));
+    assert_eq!(owner(&deps), address(ISSUER).as_str());
+    assert!(is_locked(&deps));
+}
+
+#[test]
+fn release_event_from_another_key_is_rejected() {
+    let mut deps = bridged(json!({}));
+    let key = secp256k1_key(8);
+
+    let error = relay(&mut deps, &secp256k1_key(9), NETWORK, "release", nft_event("0x01", &key, "1")).unwrap_err();
+
+    assert!(matches!(error, ContractError::Unauthorized { .. }));
+    assert!(is_locked(&deps));
+}
+
+#[test]
+fn native_ownable_rejects_lock_events() {
+    let mut deps = bridged(json!({}));
+    let key = secp256k1_key(8);
+    let response = relay(&mut deps, &key, NETWORK, "burn", nft_event("0x01", &key, "1")).unwrap();
+    assert_eq!(attr(&response, "is_native"), Some("true"));
+    assert_eq!(owner(&deps), lto_address(&key));
+
+    let error = relay(&mut deps, &key, NETWORK, "lock", nft_event("0x02", &key, "1")).unwrap_err();
+    assert!(matches!(&error, ContractError::LockError { val } if val.contains("native")), "{:?}", error);
+    // nor can its new owner lock it for the burned nft
+    let error = exec_as(&mut deps, &key, lock_for(NETWORK, "1")).unwrap_err();
+    assert!(matches!(&error, ContractError::LockError { val } if val.contains("native")), "{:?}", error);
+}
+
+#[test]
+fn nft_is_locked_and_released_on_an_allowed_secondary_network() {
+    let mut deps = setup(json!({
+        "nft": { "network": NETWORK, "address": CONTRACT, "id": "1" },
+        "allowed_networks": ["eip155:137"],
+    }));
+    let key = secp256k1_key(8);
+    exec(&mut deps, ISSUER, lock_for("eip155:137", "1")).unwrap();
+
+    relay(&mut deps, &key, "eip155:137", "release", nft_event("0x01", &key, "1")).unwrap();
+
+    assert_eq!(owner(&deps), lto_address(&key));
+}
+
+#[test]
+fn nft_is_not_released_on_a_network_that_is_not_allowed() {
+    let mut deps = bridged(json!({ "allowed_networks": ["eip155:137"] }));
+    let key = secp256k1_key(8);
+
+    let error = relay(&mut deps, &key, "eip155:10", "release", nft_event("0x01", &key, "1")).unwrap_err();
+
+    assert!(matches!(error, ContractError::MatchChainIdError { val } if val.starts_with("eip155:10")));
+    assert!(is_locked(&deps));
+}
+
+fn nft_set() -> Value {
+    json!([
+        { "network": NETWORK, "address": CONTRACT, "id": "1" },
+        { "network": NETWORK, "address": CONTRACT, "id": "2" },
+    ])
+}
+
+#[test]
+fn nft_set_is_listed() {
+    let deps = setup(json!({ "nft": nft_set() }));
+
+    let nfts: Page<NFT> = query_as(&deps, QueryMsg::GetNfts { start_after: None, limit: None });
+
+    let ids: Vec<String> = nfts.items.iter().map(|nft| nft.id.to_string()).collect();
+    assert_eq!(ids, ["1", "2"]);
+    assert!(nfts.items.iter().all(|nft| nft.network == NETWORK && nft.address == CONTRACT));
+}
+
+#[test]
+fn member_of_the_nft_set_releases_the_ownable() {
+    let mut deps = setup(json!({ "nft": nft_set() }));
+    let key = secp256k1_key(8);
+    exec(&mut deps, ISSUER, lock_for(NETWORK, "2")).unwrap();
+
+    relay(&mut deps, &key, NETWORK, "release", nft_event("0x01", &key, "2")).unwrap();
+
+    assert_eq!(owner(&deps), lto_address(&key));
+}
+
+#[test]
+fn nft_outside_the_set_is_rejected() {
+    let mut deps = setup(json!({ "nft": nft_set() }));
+    let key = secp256k1_key(8);
+
+    let error = exec(&mut deps, ISSUER, lock_for(NETWORK, "3")).unwrap_err();
+    assert!(matches!(&error, ContractError::LockError { val } if val == "Not a backing nft"), "{:?}", error);
+
+    exec(&mut deps, ISSUER, lock_for(NETWORK, "1")).unwrap();
+    let error = relay(&mut deps, &key, NETWORK, "release", nft_event("0x01", &key, "3")).unwrap_err();
+    assert!(matches!(&error, ContractError::LockError { val } if val == "Not a backing nft"), "{:?}", error);
+}
@@ -0,0 +1,208 @@
+use cosmwasm_std::testing::mock_env;
+use cosmwasm_std::to_json_vec;
+use serde_json::json;
+use sha2::{Digest, Sha256};
+
+use super::{attr, exec, exec_at, query_as, setup, TestDeps, ALICE, BOB, ISSUER};
+use crate::contract::query;
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, ProvenanceProofResponse, QueryMsg};
+use crate::pagination::Page;
+use crate::state::{OwnershipRecord, OWNERSHIP_HISTORY};
+use crate::testing::address;
+
+fn transfer(recipient: u8) -> ExecuteMsg {
+    ExecuteMsg::Transfer { recipient: address(recipient).to_string(), memo: None, sale_price: None }
+}
+
+fn history(deps: &TestDeps, start_after: Option<u64>, limit: Option<u32>) -> Page<OwnershipRecord> {
+    query_as(deps, QueryMsg::GetOwnershipHistory { start_after, limit })
+}
+
+fn owners(page: &Page<OwnershipRecord>) -> Vec<String> {
+    page.items.iter().map(|record| record.owner.to_string()).collect()
+}
+
+/// `ALICE`, then `BOB`, then `ISSUER` again, each a block apart
+fn three_transfers() -> TestDeps {
+    let mut deps = setup(json!({}));
+    for (block, (sender, recipient)) in [(ISSUER, ALICE), (ALICE, BOB), (BOB, ISSUER)].iter().enumerate() {
+        let mut env = mock_env();
+        env.block.height += block as u64;
+        exec_at(&mut deps, env, *sender, &[], transfer(*recipient)).unwrap();
+    }
+    deps
+}
+
+#[test]
+fn history_lists_owners_oldest_first() {
+    let deps = three_transfers();
+
+    let page = history(&deps, None, None);
+
+    assert_eq!(owners(&page), vec![address(ALICE), address(BOB), address(ISSUER)]);
+    assert!(page.items.windows(2).all(|pair| pair[0].height < pair[1].height));
+    assert_eq!(page.next_start_after, None);
+}
+
+#[test]
+fn history_pages_follow_the_cursor() {
+    let deps = three_transfers();
+
+    let first = history(&deps, None, Some(2));
+    assert_eq!(owners(&first), vec![address(ALICE), address(BOB)]);
+    assert_eq!(first.next_start_after, Some(1));
+
+    let last = history(&deps, first.next_start_after, Some(2));
+    assert_eq!(owners(&last), vec![address(ISSUER)]);
+    assert_eq!(last.next_start_after, None);
+}
+
+#[test]
+fn history_keeps_only_the_latest_records() {
+    let mut deps = setup(json!({}));
+    let mut holder = ISSUER;
+    for _ in 0..105 {
+        let recipient = if holder == ALICE { BOB } else { ALICE };
+        exec(&mut deps, holder, transfer(recipient)).unwrap();
+        holder = recipient;
+    }
+
+    let page = history(&deps, None, Some(100));
+
+    // pages are capped at 30 like other lists, and the five oldest records
+    // were dropped
+    assert_eq!(page.items.len(), 30);
+    assert_eq!(page.next_start_after, Some(34));
+    assert!(!OWNERSHIP_HISTORY.has(&deps.storage, 4));
+    assert!(OWNERSHIP_HISTORY.has(&deps.storage, 5));
+    let mut kept = page.items.len();
+    let mut cursor = page.next_start_after;
+    while let Some(start_after) = cursor {
+        let page = history(&deps, Some(start_after), Some(30));
+        kept += page.items.len();
+        cursor = page.next_start_after;
+    }
+    assert_eq!(kept, 100);
+}
+
+#[test]
+fn history_index_does_not_overflow() {
+    let mut deps = setup(json!({}));
+    let record = OwnershipRecord {
+        owner: address(ISSUER),
+        height: 1,
+        timestamp: mock_env().block.time,
+        memo: None,
+    };
+    OWNERSHIP_HISTORY.save(&mut deps.storage, u64::MAX - 1, &record).unwrap();
+
+    exec(&mut deps, ISSUER, transfer(ALICE)).unwrap();
+    assert!(OWNERSHIP_HISTORY.has(&deps.storage, u64::MAX));
+
+    let error = exec(&mut deps, ALICE, transfer(BOB)).unwrap_err();
+    assert!(matches!(error, ContractError::CustomError { val } if val == "Index overflow"));
+}
+
+#[test]
+fn memo_is_emitted_and_kept_with_the_record() {
+    let mut deps = setup(json!({}));
+    let msg = ExecuteMsg::Transfer {
+        recipient: address(ALICE).to_string(),
+        memo: Some("order-1234".to_string()),
+        sale_price: None,
+    };
+
+    let response = exec(&mut deps, ISSUER, msg).unwrap();
+    exec(&mut deps, ALICE, transfer(BOB)).unwrap();
+
+    assert_eq!(attr(&response, "memo"), Some("order-1234"));
+    let page = history(&deps, None, None);
+    assert_eq!(page.items[0].memo.as_deref(), Some("order-1234"));
+    assert_eq!(page.items[1].memo, None);
+}
+
+#[test]
+fn oversized_memo_is_rejected() {
+    let mut deps = setup(json!({}));
+    let msg = ExecuteMsg::Transfer {
+        recipient: address(ALICE).to_string(),
+        memo: Some("x".repeat(257)),
+        sale_price: None,
+    };
+
+    let error = exec(&mut deps, ISSUER, msg).unwrap_err();
+
+    assert!(matches!(error, ContractError::Validation { field, .. } if field == "memo"));
+    assert!(history(&deps, None, None).items.is_empty());
+}
+
+fn provenance_proof(deps: &TestDeps, index: Option<u64>) -> ProvenanceProofResponse {
+    query_as(deps, QueryMsg::GetProvenanceProof { index })
+}
+
+fn verify_provenance(deps: &TestDeps, index: u64, record: OwnershipRecord, proof: Vec<String>) -> bool {
+    query_as(deps, QueryMsg::VerifyProvenance { index, record, proof })
+}
+
+fn sha256(parts: &[&[u8]]) -> Vec<u8> {
+    parts.iter().fold(Sha256::new(), |hasher, part| hasher.chain_update(part)).finalize().to_vec()
+}
+
+#[test]
+fn provenance_root_commits_to_the_history() {
+    let deps = three_transfers();
+    let records = history(&deps, None, None).items;
+
+    let leaves: Vec<Vec<u8>> = records.iter().enumerate()
+        .map(|(index, record)| {
+            sha256(&[&[0], &(index as u64).to_be_bytes(), &to_json_vec(record).unwrap()])
+        })
+        .collect();
+    // the third leaf has no sibling and moves up as is
+    let root = sha256(&[&[1], &sha256(&[&[1], &leaves[0], &leaves[1]]), &leaves[2]]);
+
+    let proof = provenance_proof(&deps, None);
+    assert_eq!(proof.root, hex::encode(root));
+    assert_eq!(proof.records, 3);
+    assert!(proof.proof.is_empty());
+}
+
+#[test]
+fn each_record_is_proven_against_the_root() {
+    let deps = three_transfers();
+    let records = history(&deps, None, None).items;
+
+    for (index, record) in records.into_iter().enumerate() {
+        let proof = provenance_proof(&deps, Some(index as u64));
+        assert_eq!(proof.root, provenance_proof(&deps, None).root);
+        assert!(verify_provenance(&deps, index as u64, record, proof.proof));
+    }
+}
+
+#[test]
+fn altered_record_is_not_proven() {
+    let deps = three_transfers();
+    let records = history(&deps, None, None).items;
+    let proof = provenance_proof(&deps, Some(1)).proof;
+
+    let mut forged = records[1].clone();
+    forged.owner = address(ISSUER);
+    assert!(!verify_provenance(&deps, 1, forged, proof.clone()));
+    // a record is bound to its index
+    assert!(!verify_provenance(&deps, 0, records[1].clone(), proof.clone()));
+    assert!(!verify_provenance(&deps, 1, records[1].clone(), proof[..1].to_vec()));
+    assert!(!verify_provenance(&deps, 7, records[1].clone(), proof));
+}
+
+#[test]
+fn provenance_needs_a_history() {
+    let deps = setup(json!({}));
+
+    let error = query(deps.as_ref(), mock_env(), QueryMsg::GetProvenanceProof { index: None }).unwrap_err();
+
+    assert!(error.to_string().contains("No ownership history"));
+    let deps = three_transfers();
+    let error = query(deps.as_ref(), mock_env(), QueryMsg::GetProvenanceProof { index: Some(3) }).unwrap_err();
+    assert!(error.to_string().contains("No ownership record at index 3"));
+}
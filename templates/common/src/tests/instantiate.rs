@@ -0,0 +1,68 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use serde_json::{json, Value};
+
+use super::ISSUER;
+use crate::contract::instantiate;
+use crate::error::ContractError;
+use crate::testing::{instantiate_msg, mock_lto_deps, public_key};
+
+fn instantiate_error(fields: Value) -> ContractError {
+    let mut deps = mock_lto_deps();
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(public_key(ISSUER).as_str(), &[]),
+        instantiate_msg(fields),
+    )
+    .unwrap_err()
+}
+
+fn invalid_field(error: ContractError) -> String {
+    match error {
+        ContractError::Validation { field, .. } => field,
+        other => panic!("expected a validation error, got {:?}", other),
+    }
+}
+
+#[test]
+fn empty_package_names_the_field() {
+    let error = instantiate_error(json!({ "package": "" }));
+
+    assert!(matches!(
+        error,
+        ContractError::Validation { field, reason } if field == "package" && reason == "must not be empty"
+    ));
+}
+
+#[test]
+fn oversized_ownable_id_names_the_field() {
+    let error = instantiate_error(json!({ "ownable_id": "x".repeat(257) }));
+
+    assert!(matches!(
+        error,
+        ContractError::Validation { field, reason } if field == "ownable_id" && reason.contains("256")
+    ));
+}
+
+#[test]
+fn unknown_network_is_rejected() {
+    let error = instantiate_error(json!({ "network_id": b'X' }));
+
+    assert!(matches!(error, ContractError::InvalidNetwork { val } if val == "X"));
+}
+
+#[test]
+fn other_fields_are_named() {
+    let cases = [
+        (json!({ "allowed_networks": ["cosmos:hub"] }), "allowed_networks"),
+        (json!({ "attributes": [{ "trait_type": "", "value": "x" }] }), "attributes"),
+        (json!({ "total_shares": 10 }), "shares"),
+        (json!({ "thumbnail_url": "" }), "thumbnail_url"),
+        (json!({ "thumbnail_data": "x".repeat(100_000) }), "thumbnail_data"),
+        (json!({ "royalty_bps": 1_000, "accepted_denom": "" }), "accepted_denom"),
+    ];
+
+    for (fields, expected) in cases {
+        assert_eq!(invalid_field(instantiate_error(fields)), expected);
+    }
+}
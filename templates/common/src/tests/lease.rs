@@ -0,0 +1,80 @@
+use cosmwasm_std::{Addr, Timestamp};
+use serde_json::json;
+
+use super::{at, exec_at, query_at, setup, TestDeps, ALICE, BOB, ISSUER};
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, QueryMsg};
+use crate::testing::address;
+
+const NOW: u64 = 1_700_000_000;
+const UNTIL: u64 = NOW + 3_600;
+
+fn lease(to: u8, until: u64) -> ExecuteMsg {
+    ExecuteMsg::Lease { to: address(to).to_string(), until: Timestamp::from_seconds(until) }
+}
+
+fn transfer(recipient: u8) -> ExecuteMsg {
+    ExecuteMsg::Transfer {
+        recipient: address(recipient).to_string(),
+        memo: None,
+        sale_price: None,
+    }
+}
+
+fn leased() -> TestDeps {
+    let mut deps = setup(json!({}));
+    exec_at(&mut deps, at(NOW), ISSUER, &[], lease(ALICE, UNTIL)).unwrap();
+    deps
+}
+
+fn effective_owner(deps: &TestDeps, seconds: u64) -> Addr {
+    query_at(deps, at(seconds), QueryMsg::GetEffectiveOwner {})
+}
+
+#[test]
+fn holder_is_the_effective_owner_until_the_lease_ends() {
+    let deps = leased();
+
+    assert_eq!(effective_owner(&deps, UNTIL - 1), address(ALICE));
+    assert_eq!(effective_owner(&deps, UNTIL), address(ISSUER));
+}
+
+#[test]
+fn owner_transfers_only_after_the_lease_ends() {
+    let mut deps = leased();
+
+    let error = exec_at(&mut deps, at(UNTIL - 1), ISSUER, &[], transfer(BOB)).unwrap_err();
+    assert!(matches!(error, ContractError::Leased {}));
+
+    exec_at(&mut deps, at(UNTIL), ISSUER, &[], transfer(BOB)).unwrap();
+    assert_eq!(effective_owner(&deps, UNTIL), address(BOB));
+}
+
+#[test]
+fn active_lease_is_not_replaced() {
+    let mut deps = leased();
+
+    let error = exec_at(&mut deps, at(NOW + 1), ISSUER, &[], lease(BOB, UNTIL + 1)).unwrap_err();
+
+    assert!(matches!(error, ContractError::Leased {}));
+    assert_eq!(effective_owner(&deps, NOW + 1), address(ALICE));
+}
+
+#[test]
+fn lease_must_end_in_the_future() {
+    let mut deps = setup(json!({}));
+
+    let error = exec_at(&mut deps, at(NOW), ISSUER, &[], lease(ALICE, NOW)).unwrap_err();
+
+    assert!(matches!(error, ContractError::Validation { field, .. } if field == "until"));
+}
+
+#[test]
+fn only_the_owner_leases() {
+    let mut deps = setup(json!({}));
+
+    let error = exec_at(&mut deps, at(NOW), ALICE, &[], lease(ALICE, UNTIL)).unwrap_err();
+
+    assert!(matches!(error, ContractError::Unauthorized { .. }));
+    assert_eq!(effective_owner(&deps, NOW), address(ISSUER));
+}
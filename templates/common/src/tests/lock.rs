@@ -0,0 +1,119 @@
+use cosmwasm_std::testing::mock_env;
+use serde_json::json;
+
+use super::{exec, query_as, setup, TestDeps, ALICE, BOB, ISSUER};
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, OwnerResponse, QueryMsg};
+use crate::testing::address;
+
+fn locked() -> TestDeps {
+    let mut deps = setup(json!({}));
+    exec(&mut deps, ISSUER, ExecuteMsg::Lock {}).unwrap();
+    deps
+}
+
+fn is_locked(deps: &TestDeps) -> bool {
+    query_as(deps, QueryMsg::IsLocked {})
+}
+
+#[test]
+fn lock_is_reflected_by_the_query() {
+    let deps = locked();
+
+    assert!(is_locked(&deps));
+}
+
+#[test]
+fn owner_actions_are_rejected_while_locked() {
+    let mut deps = locked();
+    let until = mock_env().block.time.plus_seconds(3_600);
+    let actions = [
+        ExecuteMsg::Transfer {
+            recipient: address(ALICE).to_string(),
+            memo: None,
+            sale_price: None,
+        },
+        ExecuteMsg::TransferAndApprove {
+            recipient: address(ALICE).to_string(),
+            spender: address(BOB).to_string(),
+        },
+        ExecuteMsg::Burn {},
+        ExecuteMsg::Approve { spender: address(BOB).to_string() },
+        ExecuteMsg::Lease { to: address(BOB).to_string(), until },
+        ExecuteMsg::UpdateMetadata {
+            description: Some("while locked".to_string()),
+            external_url: None,
+            image: None,
+            animation_url: None,
+        },
+    ];
+
+    for msg in actions {
+        let error = exec(&mut deps, ISSUER, msg.clone()).unwrap_err();
+        assert!(matches!(error, ContractError::Locked {}), "{:?} was not rejected: {:?}", msg, error);
+    }
+    let owner: OwnerResponse = query_as(&deps, QueryMsg::GetOwner {});
+    assert_eq!(owner.owner, address(ISSUER));
+    assert!(is_locked(&deps));
+}
+
+#[test]
+fn locking_twice_is_rejected() {
+    let mut deps = locked();
+
+    let error = exec(&mut deps, ISSUER, ExecuteMsg::Lock {}).unwrap_err();
+
+    assert!(matches!(error, ContractError::Locked {}));
+}
+
+#[test]
+fn owner_unlocks_an_owner_lock() {
+    let mut deps = locked();
+
+    exec(&mut deps, ISSUER, ExecuteMsg::Unlock {}).unwrap();
+
+    assert!(!is_locked(&deps));
+    exec(&mut deps, ISSUER, ExecuteMsg::Transfer {
+        recipient: address(ALICE).to_string(),
+        memo: None,
+        sale_price: None,
+    })
+    .unwrap();
+}
+
+#[test]
+fn only_the_owner_unlocks() {
+    let mut deps = locked();
+
+    let error = exec(&mut deps, ALICE, ExecuteMsg::Unlock {}).unwrap_err();
+
+    assert!(matches!(error, ContractError::Unauthorized { .. }));
+    assert!(is_locked(&deps));
+}
+
+#[test]
+fn unlock_of_an_unlocked_ownable_is_rejected() {
+    let mut deps = setup(json!({}));
+
+    let error = exec(&mut deps, ISSUER, ExecuteMsg::Unlock {}).unwrap_err();
+
+    assert!(matches!(error, ContractError::LockError { val } if val == "Not locked"));
+}
+
+#[test]
+fn bridge_lock_is_not_unlocked_by_the_owner() {
+    let mut deps = setup(json!({
+        "nft": { "network": "eip155:1", "address": "0xabc", "id": "1" },
+    }));
+    exec(&mut deps, ISSUER, ExecuteMsg::LockFor {
+        network: "eip155:1".to_string(),
+        contract: "0xabc".to_string(),
+        token_id: "1".to_string(),
+    })
+    .unwrap();
+
+    let error = exec(&mut deps, ISSUER, ExecuteMsg::Unlock {}).unwrap_err();
+
+    assert!(matches!(error, ContractError::LockError { .. }));
+    assert!(is_locked(&deps));
+}
@@ -0,0 +1,221 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::Addr;
+use ownable_std::ExternalEventMsg;
+use serde_json::json;
+
+use super::{attr, exec, exec_as, setup, Signer, TestDeps, ISSUER};
+use crate::contract::{burn_message, register_external_event};
+use crate::error::ContractError;
+use crate::msg::ExecuteMsg;
+use crate::state::MERGED_FROM;
+use crate::testing::address;
+
+const OTHER: &str = "3NBdQnJsMsv1Gh7LzUmG7XVgTTRZQrZmVjY";
+
+impl Signer {
+    fn merge(&self, other: &str) -> ExecuteMsg {
+        let message = format!("merge:{}:{}", other, mock_env().contract.address);
+        ExecuteMsg::Merge { other: other.to_string(), proof: self.sign(&message) }
+    }
+
+    /// The signature of the burn event on `burned`'s chain, in base64
+    fn burn_signature(&self, burned: &str) -> String {
+        self.sign(&burn_message(&Addr::unchecked(burned), &self.address)).to_base64()
+    }
+}
+
+/// An ownable handed to a signing owner
+fn signer_ownable() -> (TestDeps, Signer) {
+    let mut deps = setup(json!({}));
+    let signer = Signer::new(9);
+    exec(&mut deps, ISSUER, ExecuteMsg::Transfer {
+        recipient: signer.address.to_string(),
+        memo: None,
+        sale_price: None,
+    })
+    .unwrap();
+    (deps, signer)
+}
+
+fn register_burn(deps: &mut TestDeps, signer: &Signer, event_id: &str) -> Result<(), ContractError> {
+    register_burn_of(deps, signer, event_id, OTHER)
+}
+
+fn register_burn_of(
+    deps: &mut TestDeps,
+    signer: &Signer,
+    event_id: &str,
+    burned: &str,
+) -> Result<(), ContractError> {
+    let proof = (signer.public_key.as_str(), signer.burn_signature(burned));
+    register_burn_with(deps, signer, event_id, burned, Some(proof))
+}
+
+/// Relay a burn of `burned` by `signer`, proven by a public key and signature
+fn register_burn_with(
+    deps: &mut TestDeps,
+    signer: &Signer,
+    event_id: &str,
+    burned: &str,
+    proof: Option<(&str, String)>,
+) -> Result<(), ContractError> {
+    let mut attributes = json!({
+        "event_id": event_id,
+        "ownable_id": "simulate",
+        "owner": signer.address,
+        "burned_ownable": burned,
+    });
+    if let Some((public_key, signature)) = proof {
+        attributes["public_key"] = json!(public_key);
+        attributes["signature"] = json!(signature);
+    }
+    let event: ExternalEventMsg = serde_json::from_value(json!({
+        "network": "lto:T",
+        "event_type": "ownable_burn",
+        "attributes": attributes,
+    }))
+    .unwrap();
+    register_external_event(
+        mock_info(&signer.public_key, &[]),
+        deps.as_mut(),
+        mock_env(),
+        event,
+        "simulate".to_string(),
+    )
+    .map(|_| ())
+}
+
+#[test]
+fn burned_ownable_is_merged() {
+    let (mut deps, signer) = signer_ownable();
+    register_burn(&mut deps, &signer, "1").unwrap();
+
+    let response = exec_as(&mut deps, &signer.public_key, signer.merge(OTHER)).unwrap();
+
+    assert_eq!(attr(&response, "merged_from"), Some(OTHER));
+}
+
+#[test]
+fn merge_without_a_registered_burn_is_rejected() {
+    let (mut deps, signer) = signer_ownable();
+
+    let error = exec_as(&mut deps, &signer.public_key, signer.merge(OTHER)).unwrap_err();
+
+    assert!(matches!(error, ContractError::Unauthorized { val } if val.contains("No burn")));
+}
+
+#[test]
+fn replayed_burn_event_is_rejected() {
+    let (mut deps, signer) = signer_ownable();
+    register_burn(&mut deps, &signer, "1").unwrap();
+
+    let error = register_burn(&mut deps, &signer, "1").unwrap_err();
+
+    assert!(matches!(error, ContractError::DuplicateEvent { .. }));
+}
+
+#[test]
+fn burn_is_merged_only_once() {
+    let (mut deps, signer) = signer_ownable();
+    register_burn(&mut deps, &signer, "1").unwrap();
+    exec_as(&mut deps, &signer.public_key, signer.merge(OTHER)).unwrap();
+    register_burn(&mut deps, &signer, "2").unwrap();
+
+    let error = exec_as(&mut deps, &signer.public_key, signer.merge(OTHER)).unwrap_err();
+
+    assert!(matches!(error, ContractError::AlreadyMerged { .. }));
+}
+
+#[test]
+fn proof_must_be_signed_by_the_owner() {
+    let (mut deps, signer) = signer_ownable();
+    register_burn(&mut deps, &signer, "1").unwrap();
+    let forged = Signer::new(10).merge(OTHER);
+
+    let error = exec_as(&mut deps, &signer.public_key, forged).unwrap_err();
+
+    assert!(matches!(error, ContractError::Unauthorized { val } if val == "Invalid merge proof"));
+}
+
+#[test]
+fn burn_without_a_signed_proof_is_rejected() {
+    let (mut deps, signer) = signer_ownable();
+
+    let error = register_burn_with(&mut deps, &signer, "1", OTHER, None).unwrap_err();
+
+    assert!(matches!(error, ContractError::InvalidExternalEventArgs { key } if key == "signature"));
+}
+
+#[test]
+fn self_asserted_burn_proof_is_rejected() {
+    let (mut deps, signer) = signer_ownable();
+    let stranger = Signer::new(10);
+    let proofs = vec![
+        // signed for another ownable than the one registered
+        (signer.public_key.as_str(), signer.burn_signature(address(ISSUER).as_str())),
+        // signed by a key that doesn't hold this ownable
+        (stranger.public_key.as_str(), stranger.burn_signature(OTHER)),
+        // the key of the holder with a signature made by another key
+        (signer.public_key.as_str(), stranger.burn_signature(OTHER)),
+        (signer.public_key.as_str(), "not base64!".to_string()),
+    ];
+
+    for (event_id, proof) in proofs.into_iter().enumerate() {
+        let error = register_burn_with(&mut deps, &signer, &event_id.to_string(), OTHER, Some(proof))
+            .unwrap_err();
+        assert!(
+            matches!(&error, ContractError::Unauthorized { val } if val == "Invalid burn proof"),
+            "{:?}",
+            error
+        );
+    }
+    let error = exec_as(&mut deps, &signer.public_key, signer.merge(OTHER)).unwrap_err();
+    assert!(matches!(error, ContractError::Unauthorized { val } if val.contains("No burn")));
+}
+
+#[test]
+fn only_the_owner_registers_a_burn() {
+    let (mut deps, _) = signer_ownable();
+    let stranger = Signer::new(10);
+
+    let error = register_burn(&mut deps, &stranger, "1").unwrap_err();
+
+    assert!(matches!(error, ContractError::Unauthorized { .. }));
+}
+
+#[test]
+fn oldest_processed_events_are_forgotten() {
+    let (mut deps, signer) = signer_ownable();
+    for event_id in 0..=1_000 {
+        register_burn(&mut deps, &signer, &event_id.to_string()).unwrap();
+    }
+
+    // only the latest 1000 event ids are remembered
+    register_burn(&mut deps, &signer, "0").unwrap();
+    let error = register_burn(&mut deps, &signer, "2").unwrap_err();
+    assert!(matches!(error, ContractError::DuplicateEvent { .. }));
+}
+
+#[test]
+fn merged_ownables_are_capped() {
+    let (mut deps, signer) = signer_ownable();
+    let merged: Vec<_> = (100..150).map(address).collect();
+    MERGED_FROM.save(&mut deps.storage, &merged).unwrap();
+    register_burn(&mut deps, &signer, "1").unwrap();
+
+    let error = exec_as(&mut deps, &signer.public_key, signer.merge(OTHER)).unwrap_err();
+
+    assert!(matches!(error, ContractError::Validation { field, .. } if field == "other"));
+}
+
+#[test]
+fn pending_burns_are_capped() {
+    let (mut deps, signer) = signer_ownable();
+    for seed in 100..150 {
+        register_burn_of(&mut deps, &signer, &seed.to_string(), address(seed).as_str()).unwrap();
+    }
+
+    let error = register_burn(&mut deps, &signer, "1").unwrap_err();
+
+    assert!(matches!(error, ContractError::Validation { field, .. } if field == "burned_ownable"));
+}
@@ -0,0 +1,30 @@
+use serde_json::json;
+
+use crate::msg::{ExecuteMsg, QueryMsg};
+use crate::{message_tag, message_variants};
+
+#[test]
+fn message_variants_cover_declared_and_macro_variants() {
+    let execute = message_variants::<ExecuteMsg>();
+    for variant in ["transfer", "lock", "release", "unlock", "transfer_from"] {
+        assert!(execute.contains(&variant.to_string()), "missing {}", variant);
+    }
+    let query = message_variants::<QueryMsg>();
+    for variant in ["get_info", "get_metadata", "get_shares", "is_owner"] {
+        assert!(query.contains(&variant.to_string()), "missing {}", variant);
+    }
+}
+
+#[test]
+fn message_tag_reads_the_variant_name() {
+    assert_eq!(message_tag(&json!({ "transfer": { "recipient": "x" } })), Some("transfer".to_string()));
+    assert_eq!(message_tag(&json!("unlock")), Some("unlock".to_string()));
+    assert_eq!(message_tag(&json!({ "a": {}, "b": {} })), None);
+    assert_eq!(message_tag(&json!(1)), None);
+}
+
+#[test]
+fn unknown_tag_is_not_a_variant() {
+    let tag = message_tag(&json!({ "teleport": {} })).unwrap();
+    assert!(!message_variants::<ExecuteMsg>().contains(&tag));
+}
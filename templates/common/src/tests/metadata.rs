@@ -0,0 +1,131 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use ownable_std::{InfoResponse, Metadata};
+use serde_json::json;
+
+use super::{attr, exec, query_as, setup, TestDeps, ALICE, ISSUER};
+use crate::contract::{default_metadata, instantiate};
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, QueryMsg};
+use crate::state::OwnableType;
+use crate::testing::{address, instantiate_msg, mock_lto_deps, public_key};
+
+/// The type this template was compiled as
+fn ownable_type(deps: &TestDeps) -> OwnableType {
+    let info: InfoResponse = query_as(deps, QueryMsg::GetInfo {});
+    serde_json::from_value(json!(info.ownable_type)).unwrap()
+}
+
+fn update_description(description: &str) -> ExecuteMsg {
+    ExecuteMsg::UpdateMetadata {
+        description: Some(description.to_string()),
+        external_url: None,
+        image: None,
+        animation_url: None,
+    }
+}
+
+#[test]
+fn issuer_updates_only_the_given_fields() {
+    let mut deps = setup(json!({}));
+    let before: Metadata = query_as(&deps, QueryMsg::GetMetadata {});
+
+    let response = exec(&mut deps, ISSUER, update_description("fixed typo")).unwrap();
+
+    assert_eq!(attr(&response, "method"), Some("update_metadata"));
+    let after: Metadata = query_as(&deps, QueryMsg::GetMetadata {});
+    assert_eq!(after.description.as_deref(), Some("fixed typo"));
+    assert_eq!(after.name, before.name);
+    assert_eq!(after.image, before.image);
+}
+
+#[test]
+fn owner_who_is_not_the_issuer_is_rejected() {
+    let mut deps = setup(json!({}));
+    exec(&mut deps, ISSUER, ExecuteMsg::Transfer {
+        recipient: address(ALICE).to_string(),
+        memo: None,
+        sale_price: None,
+    })
+    .unwrap();
+
+    let error = exec(&mut deps, ALICE, update_description("mine now")).unwrap_err();
+
+    assert!(matches!(error, ContractError::Unauthorized { .. }));
+}
+
+#[test]
+fn locked_ownable_is_not_updated() {
+    let mut deps = setup(json!({}));
+    exec(&mut deps, ISSUER, ExecuteMsg::Lock {}).unwrap();
+    let before: Metadata = query_as(&deps, QueryMsg::GetMetadata {});
+
+    let error = exec(&mut deps, ISSUER, update_description("while locked")).unwrap_err();
+
+    assert!(matches!(error, ContractError::Locked {}));
+    let after: Metadata = query_as(&deps, QueryMsg::GetMetadata {});
+    assert_eq!(after.description, before.description);
+}
+
+#[test]
+fn type_defaults_are_applied() {
+    let deps = setup(json!({}));
+    let ownable_type = ownable_type(&deps);
+
+    let metadata: Metadata = query_as(&deps, QueryMsg::GetMetadata {});
+
+    assert_eq!(metadata, default_metadata(ownable_type));
+    assert_eq!(metadata.image.as_deref(), Some("thumbnail.webp"));
+    let (background_color, animation_url) = match ownable_type {
+        OwnableType::Music => (Some("000000"), Some("index.html")),
+        OwnableType::Document => (None, Some("index.html")),
+        _ => (None, None),
+    };
+    assert_eq!(metadata.background_color.as_deref(), background_color);
+    assert_eq!(metadata.animation_url.as_deref(), animation_url);
+}
+
+#[test]
+fn provided_fields_override_only_their_default() {
+    let deps = setup(json!({ "metadata": { "name": "Sunrise", "background_color": "ffaa00" } }));
+    let defaults = default_metadata(ownable_type(&deps));
+
+    let metadata: Metadata = query_as(&deps, QueryMsg::GetMetadata {});
+
+    assert_eq!(metadata.name.as_deref(), Some("Sunrise"));
+    assert_eq!(metadata.background_color.as_deref(), Some("ffaa00"));
+    assert_eq!(metadata.image, defaults.image);
+    assert_eq!(metadata.animation_url, defaults.animation_url);
+}
+
+#[test]
+fn image_types_require_an_image() {
+    let deps = setup(json!({}));
+    let needs_image = matches!(ownable_type(&deps), OwnableType::Image | OwnableType::Artwork);
+    let mut deps = mock_lto_deps();
+
+    let result = instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(public_key(ISSUER).as_str(), &[]),
+        instantiate_msg(json!({ "metadata": { "image": "" } })),
+    );
+
+    match result {
+        Err(ContractError::Validation { field, .. }) => {
+            assert!(needs_image);
+            assert_eq!(field, "metadata.image");
+        }
+        other => assert!(!needs_image && other.is_ok(), "{:?}", other),
+    }
+}
+
+#[test]
+fn embedded_image_data_is_returned() {
+    // a 1x1 transparent png, base64 encoded as `--image` embeds it
+    let image_data = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAQAAAC1HAwCAAAAC0lEQVR42mNkYAAAAAYAAjCB0C8AAAAASUVORK5CYII=";
+    let deps = setup(json!({ "metadata": { "image_data": image_data } }));
+
+    let metadata: Metadata = query_as(&deps, QueryMsg::GetMetadata {});
+
+    assert_eq!(metadata.image_data.as_deref(), Some(image_data));
+}
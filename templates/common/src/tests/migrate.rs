@@ -0,0 +1,144 @@
+use cosmwasm_std::testing::mock_env;
+use cosmwasm_std::{Order, Storage};
+use cw2::{get_contract_version, set_contract_version};
+use ownable_std::{InfoResponse, Metadata, NFT};
+use serde_json::{json, Map, Value};
+
+use super::{attr, exec, query_as, setup, TestDeps, ALICE, ISSUER};
+use crate::contract::{migrate, CONTRACT_NAME};
+use crate::error::ContractError;
+use crate::msg::{CreatorResponse, ExecuteMsg, MigrateMsg, OwnerResponse, QueryMsg, WidgetStateResponse};
+use crate::pagination::Page;
+use crate::testing::{address, mock_lto_deps};
+
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Storage of the first released template: a single `nft` item and no
+/// `ownable_id`, creator or native flag
+const LAYOUT_0_1: &str = include_str!("fixtures/layout_0_1.json");
+
+/// Deps holding `layout`, a JSON object of raw storage keys and the JSON
+/// stored under them, as written by contract version `version`
+fn legacy_deps(layout: &str, version: &str) -> TestDeps {
+    let mut deps = mock_lto_deps();
+    let items: Map<String, Value> = serde_json::from_str(layout).expect("parse layout fixture");
+    for (key, value) in items {
+        deps.storage.set(key.as_bytes(), &serde_json::to_vec(&value).unwrap());
+    }
+    set_contract_version(&mut deps.storage, CONTRACT_NAME, version).unwrap();
+    deps
+}
+
+/// Every query reads back the migrated storage
+fn assert_layout_loads(deps: &TestDeps) {
+    query_as::<InfoResponse>(deps, QueryMsg::GetInfo {});
+    query_as::<Metadata>(deps, QueryMsg::GetMetadata {});
+    query_as::<WidgetStateResponse>(deps, QueryMsg::GetWidgetState {});
+    query_as::<CreatorResponse>(deps, QueryMsg::GetCreator {});
+    query_as::<Page<NFT>>(deps, QueryMsg::GetNfts { start_after: None, limit: None });
+    query_as::<Value>(deps, QueryMsg::GetConfig {});
+}
+
+#[test]
+fn downgrade_is_rejected() {
+    let mut deps = setup(json!({}));
+    let name = get_contract_version(&deps.storage).unwrap().contract;
+    set_contract_version(&mut deps.storage, &name, "999.0.0").unwrap();
+
+    let error = migrate(deps.as_mut(), mock_env(), MigrateMsg { ownable_id: None }).unwrap_err();
+
+    assert!(matches!(
+        error,
+        ContractError::InvalidMigration { from, to } if from == "999.0.0" && to == CONTRACT_VERSION
+    ));
+    assert_eq!(get_contract_version(&deps.storage).unwrap().version, "999.0.0");
+}
+
+#[test]
+fn other_contract_is_rejected() {
+    let mut deps = setup(json!({}));
+    set_contract_version(&mut deps.storage, "some-other-contract", "0.0.1").unwrap();
+
+    let error = migrate(deps.as_mut(), mock_env(), MigrateMsg { ownable_id: None }).unwrap_err();
+
+    assert!(matches!(error, ContractError::MigrationError { reason } if reason.contains("some-other-contract")));
+}
+
+#[test]
+fn older_version_is_upgraded() {
+    let mut deps = setup(json!({}));
+    let name = get_contract_version(&deps.storage).unwrap().contract;
+    set_contract_version(&mut deps.storage, &name, "0.0.0").unwrap();
+
+    let response = migrate(deps.as_mut(), mock_env(), MigrateMsg { ownable_id: None }).unwrap();
+
+    assert_eq!(attr(&response, "from_version"), Some("0.0.0"));
+    assert_eq!(attr(&response, "migrated"), Some("true"));
+    assert_eq!(get_contract_version(&deps.storage).unwrap().version, CONTRACT_VERSION);
+}
+
+#[test]
+fn same_version_is_a_no_op() {
+    let mut deps = setup(json!({}));
+    let before = deps.storage.range(None, None, Order::Ascending).collect::<Vec<_>>();
+
+    let response = migrate(deps.as_mut(), mock_env(), MigrateMsg { ownable_id: None }).unwrap();
+
+    assert_eq!(attr(&response, "migrated"), Some("false"));
+    let after = deps.storage.range(None, None, Order::Ascending).collect::<Vec<_>>();
+    assert_eq!(before, after);
+}
+
+#[test]
+fn first_layout_loads_after_migrate() {
+    let mut deps = legacy_deps(LAYOUT_0_1, "0.0.0");
+
+    migrate(deps.as_mut(), mock_env(), MigrateMsg { ownable_id: Some("simulate".to_string()) }).unwrap();
+
+    assert_layout_loads(&deps);
+    let nfts: Page<NFT> = query_as(&deps, QueryMsg::GetNfts { start_after: None, limit: None });
+    assert_eq!(nfts.items.len(), 1);
+    assert_eq!(nfts.items[0].address, "0xabc");
+    let creator: CreatorResponse = query_as(&deps, QueryMsg::GetCreator {});
+    assert_eq!(creator.creator, None);
+    assert_eq!(get_contract_version(&deps.storage).unwrap().version, CONTRACT_VERSION);
+}
+
+#[test]
+fn migrated_first_layout_transfers() {
+    let mut deps = legacy_deps(LAYOUT_0_1, "0.0.0");
+    migrate(deps.as_mut(), mock_env(), MigrateMsg { ownable_id: Some("simulate".to_string()) }).unwrap();
+
+    exec(&mut deps, ISSUER, ExecuteMsg::Transfer {
+        recipient: address(ALICE).to_string(),
+        memo: None,
+        sale_price: None,
+    })
+    .unwrap();
+
+    let owner: OwnerResponse = query_as(&deps, QueryMsg::GetOwner {});
+    assert_eq!(owner.owner, address(ALICE));
+    assert_eq!(owner.issuer, address(ISSUER));
+}
+
+#[test]
+fn first_layout_needs_an_ownable_id() {
+    let mut deps = legacy_deps(LAYOUT_0_1, "0.0.0");
+
+    let error = migrate(deps.as_mut(), mock_env(), MigrateMsg { ownable_id: None }).unwrap_err();
+
+    assert!(matches!(error, ContractError::MigrationError { reason } if reason.contains("ownable_id")));
+    assert_eq!(get_contract_version(&deps.storage).unwrap().version, "0.0.0");
+}
+
+#[test]
+fn current_layout_keeps_its_ownable_id() {
+    let mut deps = setup(json!({}));
+    set_contract_version(&mut deps.storage, CONTRACT_NAME, "0.0.0").unwrap();
+
+    migrate(deps.as_mut(), mock_env(), MigrateMsg { ownable_id: Some("other".to_string()) }).unwrap();
+
+    assert_layout_loads(&deps);
+    let stored = deps.storage.get(b"ownable_id").unwrap();
+    assert_eq!(serde_json::from_slice::<String>(&stored).unwrap(), "simulate");
+}
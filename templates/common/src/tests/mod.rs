@@ -0,0 +1,165 @@
+//! Unit tests against mocked deps; run with `cargo unit-test`
+
+use cosmwasm_std::testing::{mock_env, mock_info, MockQuerier, MockStorage};
+use cosmwasm_std::{from_json, Addr, Binary, Coin, Env, OwnedDeps, Response, Timestamp};
+use ed25519_zebra::{SigningKey, VerificationKey};
+use ownable_std::{address_lto, ExternalEventMsg};
+use secp256k1::{PublicKey, Secp256k1, SecretKey};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+
+use crate::contract::{execute, instantiate, query, register_external_event};
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, QueryMsg};
+use crate::testing::{instantiate_msg, mock_lto_deps, public_key, LtoApi, NETWORK_ID};
+
+mod approval;
+#[cfg(feature = "artwork")]
+mod artwork;
+mod attributes;
+mod auth;
+mod bridge;
+mod burn;
+mod caip;
+mod config;
+#[cfg(feature = "consumable")]
+mod consumable;
+mod counters;
+mod eip155;
+mod external_event;
+mod history;
+mod instantiate;
+mod lease;
+mod lock;
+mod merge;
+mod messages;
+mod metadata;
+mod migrate;
+mod network;
+mod owner_sig;
+mod notify;
+mod pagination;
+mod queries;
+mod release;
+mod royalty;
+mod shares;
+mod state_machine;
+#[cfg(feature = "subscription")]
+mod subscription;
+mod transfer_lock;
+mod wire;
+
+pub type TestDeps = OwnedDeps<MockStorage, LtoApi, MockQuerier>;
+
+/// Key seeds of the accounts used across the tests
+pub const ISSUER: u8 = 1;
+pub const ALICE: u8 = 2;
+pub const BOB: u8 = 3;
+
+/// An account with a real ed25519 key, for messages the contract verifies
+/// a signature on
+pub struct Signer {
+    key: SigningKey,
+    pub public_key: String,
+    pub address: Addr,
+}
+
+impl Signer {
+    pub fn new(seed: u8) -> Self {
+        let key = SigningKey::from([seed; 32]);
+        let public_key = bs58::encode(VerificationKey::from(&key).as_ref()).into_string();
+        let address = address_lto(NETWORK_ID as char, public_key.clone()).unwrap();
+        Signer { key, public_key, address }
+    }
+
+    pub fn sign(&self, message: &str) -> Binary {
+        let signature: [u8; 64] = self.key.sign(message.as_bytes()).into();
+        Binary::from(signature.to_vec())
+    }
+}
+
+/// An ownable instantiated by `ISSUER`, with `fields` set on the
+/// `InstantiateMsg`
+pub fn setup(fields: Value) -> TestDeps {
+    let mut deps = mock_lto_deps();
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(public_key(ISSUER).as_str(), &[]),
+        instantiate_msg(fields),
+    )
+    .expect("instantiate");
+    deps
+}
+
+/// The mock env with the block time set to `seconds`
+pub fn at(seconds: u64) -> Env {
+    let mut env = mock_env();
+    env.block.time = Timestamp::from_seconds(seconds);
+    env
+}
+
+pub fn exec(deps: &mut TestDeps, sender: u8, msg: ExecuteMsg) -> Result<Response, ContractError> {
+    exec_at(deps, mock_env(), sender, &[], msg)
+}
+
+/// Execute as the account with public key `sender`, for keys the tests sign with
+pub fn exec_as(deps: &mut TestDeps, sender: &str, msg: ExecuteMsg) -> Result<Response, ContractError> {
+    execute(deps.as_mut(), mock_env(), mock_info(sender, &[]), msg)
+}
+
+pub fn exec_at(
+    deps: &mut TestDeps,
+    env: Env,
+    sender: u8,
+    funds: &[Coin],
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    execute(deps.as_mut(), env, mock_info(public_key(sender).as_str(), funds), msg)
+}
+
+pub fn query_as<T: DeserializeOwned>(deps: &TestDeps, msg: QueryMsg) -> T {
+    query_at(deps, mock_env(), msg)
+}
+
+pub fn query_at<T: DeserializeOwned>(deps: &TestDeps, env: Env, msg: QueryMsg) -> T {
+    let binary = query(deps.as_ref(), env, msg).expect("query");
+    from_json(&binary).expect("parse query response")
+}
+
+/// Base58 compressed secp256k1 public key, as an eip155 wallet signs with
+pub fn secp256k1_key(seed: u8) -> String {
+    let secret = SecretKey::from_slice(&[seed; 32]).unwrap();
+    let public = PublicKey::from_secret_key(&Secp256k1::new(), &secret);
+    bs58::encode(public.serialize()).into_string()
+}
+
+/// Relay a bridge event of `event_type` from `network`, sent by the key
+/// `sender`; `attributes` are set on top of the ownable id
+pub fn relay(
+    deps: &mut TestDeps,
+    sender: &str,
+    network: &str,
+    event_type: &str,
+    attributes: Value,
+) -> Result<Response, ContractError> {
+    let mut event = json!({
+        "network": network,
+        "event_type": event_type,
+        "attributes": { "ownable_id": "simulate" },
+    });
+    if let Value::Object(attributes) = attributes {
+        for (key, value) in attributes {
+            event["attributes"][key] = value;
+        }
+    }
+    let event: ExternalEventMsg = serde_json::from_value(event).expect("build ExternalEventMsg");
+    register_external_event(mock_info(sender, &[]), deps.as_mut(), mock_env(), event, "simulate".to_string())
+}
+
+/// Value of the response attribute `key`
+pub fn attr<'a>(response: &'a Response, key: &str) -> Option<&'a str> {
+    response.attributes.iter()
+        .find(|attribute| attribute.key == key)
+        .map(|attribute| attribute.value.as_str())
+}
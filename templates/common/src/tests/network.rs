@@ -0,0 +1,68 @@
+use cosmwasm_std::testing::mock_env;
+use cosmwasm_std::{Addr, StdError};
+use ownable_std::address_lto;
+use serde_json::json;
+
+use super::{exec, query_as, setup, TestDeps, ALICE, ISSUER};
+use crate::contract::{address_for_network, query};
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, NetworkAddressResponse, QueryMsg};
+use crate::testing::{address, public_key};
+
+fn address_on(deps: &TestDeps, network_id: u8) -> NetworkAddressResponse {
+    query_as(deps, QueryMsg::GetAddressForNetwork { network_id })
+}
+
+#[test]
+fn owner_is_derived_for_each_lto_network() {
+    let deps = setup(json!({}));
+
+    let mainnet = address_on(&deps, b'L');
+    let testnet = address_on(&deps, b'T');
+
+    assert_eq!(mainnet.network_id, "L");
+    assert_eq!(mainnet.address, address_lto('L', public_key(ISSUER).to_string()).unwrap().as_str());
+    // the stored network is testnet, so its address is the owner itself
+    assert_eq!(testnet.network_id, "T");
+    assert_eq!(testnet.address, address(ISSUER).as_str());
+    assert_ne!(mainnet.address, testnet.address);
+}
+
+#[test]
+fn derived_address_follows_the_owner() {
+    let mut deps = setup(json!({}));
+    exec(&mut deps, ISSUER, ExecuteMsg::Transfer {
+        recipient: address(ALICE).to_string(),
+        memo: None,
+        sale_price: None,
+    })
+    .unwrap();
+
+    let mainnet = address_on(&deps, b'L');
+
+    assert_eq!(mainnet.address, address_lto('L', public_key(ALICE).to_string()).unwrap().as_str());
+}
+
+#[test]
+fn unknown_network_is_rejected() {
+    let deps = setup(json!({}));
+
+    for network_id in [b'E', b'l', 0] {
+        let error = query(deps.as_ref(), mock_env(), QueryMsg::GetAddressForNetwork { network_id })
+            .unwrap_err();
+        assert!(
+            matches!(&error, StdError::GenericErr { msg, .. } if msg.starts_with("Invalid LTO network")),
+            "{:?}",
+            error
+        );
+    }
+}
+
+#[test]
+fn eip155_address_is_not_re_derived() {
+    let eip155 = Addr::unchecked("0x71c7656ec7ab88b098defb751b7401b5f6d8976f");
+
+    let error = address_for_network(&eip155, b'L').unwrap_err();
+
+    assert!(matches!(error, ContractError::CustomError { val } if val.starts_with("Invalid LTO address")));
+}
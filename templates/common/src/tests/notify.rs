@@ -0,0 +1,43 @@
+use cosmwasm_std::{from_json, CosmosMsg, WasmMsg};
+use serde_json::json;
+
+use super::{exec, setup, ALICE, ISSUER};
+use crate::msg::{ExecuteMsg, NotifyMsg};
+use crate::testing::address;
+
+const REGISTRY: &str = "registry";
+
+fn transfer_to_alice() -> ExecuteMsg {
+    ExecuteMsg::Transfer {
+        recipient: address(ALICE).to_string(),
+        memo: None,
+        sale_price: None,
+    }
+}
+
+#[test]
+fn configured_contract_is_notified_of_the_new_owner() {
+    let mut deps = setup(json!({ "notify_contract": REGISTRY }));
+
+    let response = exec(&mut deps, ISSUER, transfer_to_alice()).unwrap();
+
+    assert_eq!(response.messages.len(), 1);
+    match &response.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, msg, funds }) => {
+            assert_eq!(contract_addr, REGISTRY);
+            assert!(funds.is_empty());
+            let NotifyMsg::OwnerChanged { owner, .. } = from_json(msg).unwrap();
+            assert_eq!(owner, address(ALICE));
+        }
+        other => panic!("unexpected message {:?}", other),
+    }
+}
+
+#[test]
+fn nothing_is_sent_without_a_notify_contract() {
+    let mut deps = setup(json!({}));
+
+    let response = exec(&mut deps, ISSUER, transfer_to_alice()).unwrap();
+
+    assert!(response.messages.is_empty());
+}
@@ -0,0 +1,67 @@
+use cosmwasm_std::Binary;
+use serde_json::json;
+
+use super::{exec, exec_as, query_as, setup, Signer, TestDeps, ALICE, ISSUER};
+use crate::msg::{ExecuteMsg, QueryMsg};
+use crate::testing::address;
+
+const CHALLENGE: &str = "sign in to the gallery: 8f3a";
+
+/// An ownable handed to a signing owner
+fn signer_ownable() -> (TestDeps, Signer) {
+    let mut deps = setup(json!({}));
+    let signer = Signer::new(9);
+    exec(&mut deps, ISSUER, ExecuteMsg::Transfer {
+        recipient: signer.address.to_string(),
+        memo: None,
+        sale_price: None,
+    })
+    .unwrap();
+    (deps, signer)
+}
+
+fn verify(deps: &TestDeps, message: &str, signature: Binary, signer: &Signer) -> bool {
+    let pubkey = bs58::decode(&signer.public_key).into_vec().unwrap();
+    query_as(deps, QueryMsg::VerifyOwnerSig {
+        message: Binary::from(message.as_bytes()),
+        signature,
+        pubkey: Binary::from(pubkey),
+    })
+}
+
+#[test]
+fn owner_signature_is_verified() {
+    let (deps, signer) = signer_ownable();
+
+    assert!(verify(&deps, CHALLENGE, signer.sign(CHALLENGE), &signer));
+}
+
+#[test]
+fn wrong_signature_is_not_the_owners() {
+    let (deps, signer) = signer_ownable();
+
+    // signed by another key, or over another message
+    assert!(!verify(&deps, CHALLENGE, Signer::new(10).sign(CHALLENGE), &signer));
+    assert!(!verify(&deps, CHALLENGE, signer.sign("another challenge"), &signer));
+}
+
+#[test]
+fn signature_of_someone_else_is_not_the_owners() {
+    let (deps, _) = signer_ownable();
+    let stranger = Signer::new(10);
+
+    assert!(!verify(&deps, CHALLENGE, stranger.sign(CHALLENGE), &stranger));
+}
+
+#[test]
+fn former_owner_no_longer_verifies() {
+    let (mut deps, signer) = signer_ownable();
+    exec_as(&mut deps, &signer.public_key, ExecuteMsg::Transfer {
+        recipient: address(ALICE).to_string(),
+        memo: None,
+        sale_price: None,
+    })
+    .unwrap();
+
+    assert!(!verify(&deps, CHALLENGE, signer.sign(CHALLENGE), &signer));
+}
@@ -0,0 +1,73 @@
+use serde_json::json;
+
+use super::{query_as, setup};
+use crate::msg::QueryMsg;
+use crate::pagination::{paginate, paginate_list, Page, DEFAULT_LIMIT, MAX_LIMIT};
+use crate::state::Trait;
+
+fn numbers(count: u64) -> Vec<u64> {
+    (0..count).collect()
+}
+
+#[test]
+fn empty_input_is_a_single_empty_page() {
+    let page = paginate_list(Vec::<u64>::new(), None, None, MAX_LIMIT);
+
+    assert_eq!(page, Page { items: vec![], next_start_after: None });
+}
+
+#[test]
+fn limit_defaults_to_ten() {
+    let page = paginate_list(numbers(25), None, None, MAX_LIMIT);
+
+    assert_eq!(page.items.len(), DEFAULT_LIMIT as usize);
+    assert_eq!(page.next_start_after, Some(9));
+}
+
+#[test]
+fn exactly_one_page_has_no_cursor() {
+    let page = paginate_list(numbers(10), None, Some(10), MAX_LIMIT);
+
+    assert_eq!(page.items, numbers(10));
+    assert_eq!(page.next_start_after, None);
+}
+
+#[test]
+fn one_past_a_page_has_a_cursor() {
+    let first = paginate_list(numbers(11), None, Some(10), MAX_LIMIT);
+    assert_eq!(first.next_start_after, Some(9));
+
+    let last = paginate_list(numbers(11), first.next_start_after, Some(10), MAX_LIMIT);
+    assert_eq!(last.items, vec![10]);
+    assert_eq!(last.next_start_after, None);
+}
+
+#[test]
+fn limit_is_capped() {
+    let page = paginate_list(numbers(100), None, Some(1_000), MAX_LIMIT);
+
+    assert_eq!(page.items.len(), MAX_LIMIT as usize);
+    assert_eq!(page.next_start_after, Some(u64::from(MAX_LIMIT) - 1));
+}
+
+#[test]
+fn cursor_skips_gaps_in_the_keys() {
+    let keyed = [(2, "a"), (5, "b"), (9, "c")];
+
+    let page = paginate(keyed, Some(2), Some(1), MAX_LIMIT);
+
+    assert_eq!(page, Page { items: vec!["b"], next_start_after: Some(5) });
+}
+
+#[test]
+fn list_query_enforces_the_cap() {
+    let attributes: Vec<_> = (0..35)
+        .map(|i| json!({ "trait_type": format!("trait {}", i), "value": "x" }))
+        .collect();
+    let deps = setup(json!({ "attributes": attributes }));
+
+    let page: Page<Trait> = query_as(&deps, QueryMsg::GetAttributes { start_after: None, limit: Some(100) });
+
+    assert_eq!(page.items.len(), MAX_LIMIT as usize);
+    assert_eq!(page.next_start_after, Some(29));
+}
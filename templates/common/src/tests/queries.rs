@@ -0,0 +1,127 @@
+use cosmwasm_std::testing::mock_env;
+use cosmwasm_std::{from_json, Binary};
+use ownable_std::InfoResponse;
+use serde_json::{json, Value};
+
+use super::{exec, query_as, setup, ALICE, ISSUER};
+use crate::contract::query;
+use crate::msg::{CapabilitiesResponse, ExecuteMsg, PackageCidResponse, QueryMsg, WidgetStateResponse};
+use crate::state::PACKAGE_CID;
+use crate::testing::address;
+
+#[test]
+fn default_build_reports_only_its_template_feature() {
+    let deps = setup(json!({}));
+
+    let capabilities: CapabilitiesResponse = query_as(&deps, QueryMsg::GetCapabilities {});
+
+    let has = |feature: &str| capabilities.features.iter().any(|f| f == feature);
+    assert_eq!(has("subscription"), cfg!(feature = "subscription"));
+    assert_eq!(has("consumable"), cfg!(feature = "consumable"));
+    assert_eq!(has("artwork"), cfg!(feature = "artwork"));
+    // debugging features are never on in a default build
+    assert!(!has("backtraces") && !has("debug-trace"), "{:?}", capabilities.features);
+}
+
+#[test]
+fn multi_returns_each_result_in_order() {
+    let deps = setup(json!({}));
+
+    let results: Vec<Binary> = query_as(&deps, QueryMsg::Multi {
+        queries: vec![QueryMsg::GetInfo {}, QueryMsg::IsLocked {}],
+    });
+
+    assert_eq!(results.len(), 2);
+    let info: InfoResponse = from_json(&results[0]).unwrap();
+    assert_eq!(info.owner, address(ISSUER));
+    assert!(!from_json::<bool>(&results[1]).unwrap());
+}
+
+#[test]
+fn nested_multi_is_rejected() {
+    let deps = setup(json!({}));
+    let nested = QueryMsg::Multi {
+        queries: vec![QueryMsg::IsLocked {}, QueryMsg::Multi { queries: vec![] }],
+    };
+
+    let error = query(deps.as_ref(), mock_env(), nested).unwrap_err();
+
+    assert!(error.to_string().contains("Nested multi queries"), "{}", error);
+}
+
+#[test]
+fn is_owner_follows_transfers() {
+    let mut deps = setup(json!({}));
+    let is_owner = |deps: &_, seed| -> bool {
+        query_as(deps, QueryMsg::IsOwner { address: address(seed).to_string() })
+    };
+    assert!(is_owner(&deps, ISSUER));
+    assert!(!is_owner(&deps, ALICE));
+
+    exec(&mut deps, ISSUER, ExecuteMsg::Transfer {
+        recipient: address(ALICE).to_string(),
+        memo: None,
+        sale_price: None,
+    })
+    .unwrap();
+
+    assert!(!is_owner(&deps, ISSUER));
+    assert!(is_owner(&deps, ALICE));
+}
+
+#[test]
+fn is_owner_rejects_an_empty_address() {
+    let deps = setup(json!({}));
+
+    let result = query(deps.as_ref(), mock_env(), QueryMsg::IsOwner { address: String::new() });
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn package_cid_is_the_instantiated_package() {
+    let package = "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi";
+    let deps = setup(json!({ "package": package }));
+
+    let response: PackageCidResponse = query_as(&deps, QueryMsg::GetPackageCid {});
+
+    assert_eq!(response.cid, package);
+}
+
+#[test]
+fn empty_package_cid_is_returned_as_is() {
+    let mut deps = setup(json!({}));
+    // instantiate rejects an empty package, but older layouts may hold one
+    PACKAGE_CID.save(&mut deps.storage, &String::new()).unwrap();
+
+    let response: PackageCidResponse = query_as(&deps, QueryMsg::GetPackageCid {});
+
+    assert_eq!(response.cid, "");
+}
+
+#[test]
+fn widget_reports_the_template_type() {
+    // the wallet's requested type doesn't override the compiled one
+    let deps = setup(json!({ "ownable_type": "not-a-type" }));
+    let info: InfoResponse = query_as(&deps, QueryMsg::GetInfo {});
+
+    let widget: WidgetStateResponse = query_as(&deps, QueryMsg::GetWidgetState {});
+    let raw: Value = query_as(&deps, QueryMsg::GetWidgetState {});
+
+    let expected = if cfg!(feature = "subscription") {
+        Some("subscription")
+    } else if cfg!(feature = "consumable") {
+        Some("consumable")
+    } else if cfg!(feature = "artwork") {
+        Some("artwork")
+    } else {
+        // image, music and document share the default features
+        None
+    };
+    let ownable_type = widget.ownable_type.to_string();
+    if let Some(expected) = expected {
+        assert_eq!(ownable_type, expected);
+    }
+    assert_eq!(info.ownable_type.as_deref(), Some(ownable_type.as_str()));
+    assert_eq!(raw["ownable_type"], json!(ownable_type));
+}
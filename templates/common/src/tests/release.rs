@@ -0,0 +1,114 @@
+use cosmwasm_std::testing::mock_env;
+use serde_json::json;
+
+use super::{attr, exec, query_as, setup, TestDeps, ALICE, BOB, ISSUER};
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, OwnerResponse, QueryMsg};
+use crate::testing::address;
+
+fn release(to: &str) -> ExecuteMsg {
+    ExecuteMsg::Release { to: to.to_string() }
+}
+
+fn nft_backed() -> TestDeps {
+    setup(json!({
+        "nft": { "network": "eip155:1", "address": "0xabc", "id": "1" },
+    }))
+}
+
+/// Lock `deps` as its owner and check that releasing it to `ALICE` is
+/// rejected, leaving it locked with its owner
+fn assert_release_to_alice_rejected(mut deps: TestDeps) {
+    exec(&mut deps, ISSUER, ExecuteMsg::Lock {}).unwrap();
+
+    let error = exec(&mut deps, ISSUER, release(address(ALICE).as_str())).unwrap_err();
+
+    assert!(matches!(&error, ContractError::Validation { field, .. } if field == "to"), "{:?}", error);
+    let owner: OwnerResponse = query_as(&deps, QueryMsg::GetOwner {});
+    assert_eq!(owner.owner, address(ISSUER));
+    assert!(query_as::<bool>(&deps, QueryMsg::IsLocked {}));
+}
+
+#[test]
+fn owner_releases_an_owner_lock() {
+    let mut deps = setup(json!({}));
+    exec(&mut deps, ISSUER, ExecuteMsg::Lock {}).unwrap();
+
+    let response = exec(&mut deps, ISSUER, release(address(ISSUER).as_str())).unwrap();
+
+    assert_eq!(attr(&response, "owner"), Some(address(ISSUER).as_str()));
+    let owner: OwnerResponse = query_as(&deps, QueryMsg::GetOwner {});
+    assert_eq!(owner.owner, address(ISSUER));
+    assert!(!query_as::<bool>(&deps, QueryMsg::IsLocked {}));
+}
+
+#[test]
+fn release_to_another_address_is_rejected() {
+    assert_release_to_alice_rejected(setup(json!({})));
+}
+
+#[test]
+fn release_does_not_bypass_a_lease() {
+    let mut deps = setup(json!({}));
+    let until = mock_env().block.time.plus_seconds(3_600);
+    exec(&mut deps, ISSUER, ExecuteMsg::Lease { to: address(BOB).to_string(), until }).unwrap();
+
+    assert_release_to_alice_rejected(deps);
+}
+
+#[test]
+fn release_does_not_bypass_a_transfer_lock() {
+    let until = mock_env().block.time.plus_seconds(3_600);
+
+    assert_release_to_alice_rejected(setup(json!({ "transfer_locked_until": until })));
+}
+
+#[test]
+fn release_does_not_bypass_the_royalty() {
+    assert_release_to_alice_rejected(setup(json!({ "royalty_bps": 500 })));
+}
+
+#[test]
+fn bridge_lock_is_not_released_by_the_owner() {
+    let mut deps = nft_backed();
+    exec(&mut deps, ISSUER, ExecuteMsg::LockFor {
+        network: "eip155:1".to_string(),
+        contract: "0xabc".to_string(),
+        token_id: "1".to_string(),
+    })
+    .unwrap();
+
+    let error = exec(&mut deps, ISSUER, release(address(ALICE).as_str())).unwrap_err();
+
+    assert!(matches!(error, ContractError::LockError { .. }));
+    assert!(query_as::<bool>(&deps, QueryMsg::IsLocked {}));
+}
+
+#[test]
+fn release_of_an_unlocked_ownable_is_rejected() {
+    let mut deps = setup(json!({}));
+
+    let error = exec(&mut deps, ISSUER, release(address(ALICE).as_str())).unwrap_err();
+
+    assert!(matches!(error, ContractError::LockError { val } if val == "Not locked"));
+}
+
+#[test]
+fn only_the_owner_releases() {
+    let mut deps = setup(json!({}));
+    exec(&mut deps, ISSUER, ExecuteMsg::Lock {}).unwrap();
+
+    let error = exec(&mut deps, BOB, release(address(ISSUER).as_str())).unwrap_err();
+
+    assert!(matches!(error, ContractError::Unauthorized { .. }));
+}
+
+#[test]
+fn release_validates_the_recipient() {
+    let mut deps = setup(json!({}));
+    exec(&mut deps, ISSUER, ExecuteMsg::Lock {}).unwrap();
+
+    let error = exec(&mut deps, ISSUER, release("")).unwrap_err();
+
+    assert!(matches!(error, ContractError::Std(_)));
+}
@@ -0,0 +1,170 @@
+use cosmwasm_std::testing::mock_env;
+use cosmwasm_std::{coin, BankMsg, Coin, CosmosMsg, Response};
+use serde_json::json;
+
+use super::{attr, exec, exec_at, setup, TestDeps, ALICE, BOB, ISSUER};
+use crate::error::ContractError;
+use crate::msg::ExecuteMsg;
+use crate::testing::address;
+
+fn transfer(recipient: u8, sale_price: Option<Coin>) -> ExecuteMsg {
+    ExecuteMsg::Transfer {
+        recipient: address(recipient).to_string(),
+        memo: None,
+        sale_price,
+    }
+}
+
+fn bank_sends(response: &Response) -> Vec<(String, Vec<Coin>)> {
+    response.messages.iter()
+        .filter_map(|sub| match &sub.msg {
+            CosmosMsg::Bank(BankMsg::Send { to_address, amount }) =>
+                Some((to_address.clone(), amount.clone())),
+            _ => None,
+        })
+        .collect()
+}
+
+/// An ownable with a 5% royalty, handed to `ALICE` as a gift
+fn royalty_ownable() -> TestDeps {
+    let mut deps = setup(json!({ "royalty_bps": 500 }));
+    exec(&mut deps, ISSUER, transfer(ALICE, Some(coin(0, "lto")))).unwrap();
+    deps
+}
+
+#[test]
+fn priced_transfer_splits_the_sent_funds() {
+    let mut deps = royalty_ownable();
+    let price = coin(1_000, "lto");
+    let funds = [price.clone()];
+
+    let response = exec_at(&mut deps, mock_env(), ALICE, &funds, transfer(BOB, Some(price)))
+        .unwrap();
+
+    assert_eq!(attr(&response, "royalty"), Some("50lto"));
+    assert_eq!(bank_sends(&response), vec![
+        (address(ISSUER).to_string(), vec![coin(50, "lto")]),
+        (address(ALICE).to_string(), vec![coin(950, "lto")]),
+    ]);
+    let event = response.events.iter().find(|event| event.ty == "royalty").unwrap();
+    assert!(event.attributes.iter().any(|a| a.key == "recipient" && a.value == address(ISSUER).as_str()));
+}
+
+#[test]
+fn gift_pays_nothing() {
+    let mut deps = setup(json!({ "royalty_bps": 500 }));
+
+    let response = exec(&mut deps, ISSUER, transfer(ALICE, Some(coin(0, "lto")))).unwrap();
+
+    assert!(bank_sends(&response).is_empty());
+    assert_eq!(attr(&response, "royalty"), Some("0lto"));
+}
+
+#[test]
+fn unpriced_transfer_pays_no_royalty() {
+    let mut deps = royalty_ownable();
+
+    let response = exec(&mut deps, ALICE, transfer(BOB, None)).unwrap();
+
+    assert!(bank_sends(&response).is_empty());
+    assert_eq!(attr(&response, "royalty"), None);
+}
+
+#[test]
+fn unpriced_transfer_without_royalty_behaves_as_before() {
+    let mut deps = setup(json!({}));
+
+    let response = exec(&mut deps, ISSUER, transfer(ALICE, None)).unwrap();
+
+    assert!(response.messages.is_empty());
+    assert_eq!(attr(&response, "royalty"), None);
+}
+
+#[test]
+fn underfunded_transfer_is_rejected() {
+    let mut deps = royalty_ownable();
+
+    let error = exec_at(
+        &mut deps,
+        mock_env(),
+        ALICE,
+        &[coin(900, "lto")],
+        transfer(BOB, Some(coin(1_000, "lto"))),
+    )
+    .unwrap_err();
+
+    assert!(matches!(error, ContractError::Validation { field, .. } if field == "sale_price"));
+}
+
+#[test]
+fn price_in_another_denom_is_rejected() {
+    let mut deps = royalty_ownable();
+    let price = coin(1_000, "uatom");
+    let funds = [price.clone()];
+
+    let error = exec_at(&mut deps, mock_env(), ALICE, &funds, transfer(BOB, Some(price)))
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        ContractError::InvalidDenom { expected, got } if expected == "lto" && got == "uatom"
+    ));
+}
+
+#[test]
+fn accepted_denom_is_configurable() {
+    let mut deps = setup(json!({ "royalty_bps": 1_000, "accepted_denom": "uatom" }));
+    let price = coin(100, "uatom");
+    let funds = [price.clone()];
+
+    let response = exec_at(&mut deps, mock_env(), ISSUER, &funds, transfer(ALICE, Some(price)))
+        .unwrap();
+
+    assert_eq!(attr(&response, "royalty"), Some("10uatom"));
+}
+
+fn update_denom(denom: &str) -> ExecuteMsg {
+    ExecuteMsg::UpdateConfig {
+        notify_contract: None,
+        royalty_bps: None,
+        accepted_denom: Some(denom.to_string()),
+        transfer_locked_until: None,
+        admin: None,
+    }
+}
+
+#[test]
+fn admin_changes_the_accepted_denom() {
+    let mut deps = setup(json!({ "royalty_bps": 1_000, "admin": address(ISSUER) }));
+    exec(&mut deps, ISSUER, update_denom("uatom")).unwrap();
+    let price = coin(100, "uatom");
+    let funds = [price.clone()];
+
+    let response = exec_at(&mut deps, mock_env(), ISSUER, &funds, transfer(ALICE, Some(price))).unwrap();
+
+    assert_eq!(attr(&response, "royalty"), Some("10uatom"));
+}
+
+#[test]
+fn price_in_the_replaced_denom_is_rejected() {
+    let mut deps = setup(json!({ "royalty_bps": 1_000, "admin": address(ISSUER) }));
+    exec(&mut deps, ISSUER, update_denom("uatom")).unwrap();
+    let price = coin(100, "lto");
+    let funds = [price.clone()];
+
+    let error = exec_at(&mut deps, mock_env(), ISSUER, &funds, transfer(ALICE, Some(price))).unwrap_err();
+
+    assert!(matches!(
+        error,
+        ContractError::InvalidDenom { expected, got } if expected == "uatom" && got == "lto"
+    ));
+}
+
+#[test]
+fn empty_accepted_denom_is_rejected() {
+    let mut deps = setup(json!({ "admin": address(ISSUER) }));
+
+    let error = exec(&mut deps, ISSUER, update_denom(" ")).unwrap_err();
+
+    assert!(matches!(error, ContractError::Validation { field, .. } if field == "accepted_denom"));
+}
@@ -0,0 +1,120 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use serde_json::json;
+
+use super::{exec, query_as, setup, TestDeps, ALICE, BOB, ISSUER};
+use crate::contract::instantiate;
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, QueryMsg};
+use crate::state::{LockInfo, LockKind, LOCKED, LOCK_INFO};
+use crate::testing::{address, instantiate_msg, mock_lto_deps, public_key};
+
+fn shared(alice: u64, bob: u64) -> TestDeps {
+    setup(json!({
+        "total_shares": alice + bob,
+        "shares": [
+            { "holder": address(ALICE), "amount": alice },
+            { "holder": address(BOB), "amount": bob },
+        ],
+    }))
+}
+
+fn shares_of(deps: &TestDeps, seed: u8) -> u64 {
+    query_as(deps, QueryMsg::GetShares { address: address(seed).to_string() })
+}
+
+fn transfer_shares(recipient: u8, amount: u64) -> ExecuteMsg {
+    ExecuteMsg::TransferShares { recipient: address(recipient).to_string(), amount }
+}
+
+#[test]
+fn shares_move_between_holders() {
+    let mut deps = shared(60, 40);
+
+    exec(&mut deps, ALICE, transfer_shares(BOB, 25)).unwrap();
+
+    assert_eq!(shares_of(&deps, ALICE), 35);
+    assert_eq!(shares_of(&deps, BOB), 65);
+}
+
+#[test]
+fn transferring_every_share_removes_the_holder() {
+    let mut deps = shared(60, 40);
+
+    exec(&mut deps, ALICE, transfer_shares(BOB, 60)).unwrap();
+
+    assert_eq!(shares_of(&deps, ALICE), 0);
+    assert_eq!(shares_of(&deps, BOB), 100);
+}
+
+#[test]
+fn transfer_above_the_balance_is_rejected() {
+    let mut deps = shared(60, 40);
+
+    let error = exec(&mut deps, ALICE, transfer_shares(BOB, 61)).unwrap_err();
+
+    assert!(matches!(error, ContractError::Validation { field, .. } if field == "amount"));
+    assert_eq!(shares_of(&deps, ALICE), 60);
+}
+
+#[test]
+fn overflowing_allocations_are_rejected() {
+    let mut deps = mock_lto_deps();
+    let msg = instantiate_msg(json!({
+        "total_shares": u64::MAX,
+        "shares": [
+            { "holder": address(ALICE), "amount": u64::MAX },
+            { "holder": address(BOB), "amount": 1 },
+        ],
+    }));
+
+    let error = instantiate(deps.as_mut(), mock_env(), mock_info(public_key(ISSUER).as_str(), &[]), msg)
+        .unwrap_err();
+
+    assert!(matches!(error, ContractError::Validation { field, .. } if field == "shares"));
+}
+
+#[test]
+fn single_owner_actions_are_rejected() {
+    let mut deps = shared(60, 40);
+    let until = mock_env().block.time.plus_seconds(3_600);
+    let actions = [
+        ExecuteMsg::Transfer {
+            recipient: address(ALICE).to_string(),
+            memo: None,
+            sale_price: None,
+        },
+        ExecuteMsg::TransferAndApprove {
+            recipient: address(ALICE).to_string(),
+            spender: address(BOB).to_string(),
+        },
+        ExecuteMsg::Lock {},
+        ExecuteMsg::Lease { to: address(ALICE).to_string(), until },
+        ExecuteMsg::Approve { spender: address(ALICE).to_string() },
+        ExecuteMsg::Burn {},
+    ];
+
+    for msg in actions {
+        let error = exec(&mut deps, ISSUER, msg.clone()).unwrap_err();
+        assert!(matches!(error, ContractError::SharedOwnership {}), "{:?} gave {:?}", msg, error);
+    }
+    assert!(!query_as::<bool>(&deps, QueryMsg::IsLocked {}));
+}
+
+#[test]
+fn old_owner_lock_is_not_released_in_shares_mode() {
+    let mut deps = shared(60, 40);
+    // an owner lock can't be made in shares mode any more, but may be stored
+    LOCKED.save(&mut deps.storage, &true).unwrap();
+    LOCK_INFO.save(&mut deps.storage, &Some(LockInfo {
+        locked_by: address(ISSUER),
+        at_height: mock_env().block.height,
+        event_network: mock_env().block.chain_id,
+        kind: LockKind::Owner,
+    }))
+    .unwrap();
+
+    let error = exec(&mut deps, ISSUER, ExecuteMsg::Release { to: address(ISSUER).to_string() })
+        .unwrap_err();
+
+    assert!(matches!(error, ContractError::SharedOwnership {}));
+}
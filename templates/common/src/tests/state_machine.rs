@@ -0,0 +1,100 @@
+//! Random sequences of transfers, locks, unlocks and releases, checked
+//! against the ownership invariants after every step
+
+use proptest::collection::vec;
+use proptest::prelude::*;
+use serde_json::json;
+
+use super::{exec, query_as, setup, TestDeps, ALICE, BOB, ISSUER};
+use crate::msg::{ExecuteMsg, OwnerResponse, QueryMsg};
+use crate::testing::address;
+
+#[derive(Clone, Debug)]
+enum Action {
+    Transfer { sender: u8, recipient: u8 },
+    Lock { sender: u8 },
+    Unlock { sender: u8 },
+    Release { sender: u8, to: u8 },
+}
+
+impl Action {
+    fn sender(&self) -> u8 {
+        match *self {
+            Action::Transfer { sender, .. }
+            | Action::Lock { sender }
+            | Action::Unlock { sender }
+            | Action::Release { sender, .. } => sender,
+        }
+    }
+
+    fn msg(&self) -> ExecuteMsg {
+        match *self {
+            Action::Transfer { recipient, .. } => ExecuteMsg::Transfer {
+                recipient: address(recipient).to_string(),
+                memo: None,
+                sale_price: None,
+            },
+            Action::Lock { .. } => ExecuteMsg::Lock {},
+            Action::Unlock { .. } => ExecuteMsg::Unlock {},
+            Action::Release { to, .. } => ExecuteMsg::Release { to: address(to).to_string() },
+        }
+    }
+}
+
+fn account() -> impl Strategy<Value = u8> {
+    prop_oneof![Just(ISSUER), Just(ALICE), Just(BOB)]
+}
+
+fn action() -> impl Strategy<Value = Action> {
+    prop_oneof![
+        (account(), account()).prop_map(|(sender, recipient)| Action::Transfer { sender, recipient }),
+        account().prop_map(|sender| Action::Lock { sender }),
+        account().prop_map(|sender| Action::Unlock { sender }),
+        (account(), account()).prop_map(|(sender, to)| Action::Release { sender, to }),
+    ]
+}
+
+fn state(deps: &TestDeps) -> (OwnerResponse, bool) {
+    (query_as(deps, QueryMsg::GetOwner {}), query_as(deps, QueryMsg::IsLocked {}))
+}
+
+proptest! {
+    #[test]
+    fn ownership_invariants_hold(actions in vec(action(), 1..24)) {
+        let mut deps = setup(json!({}));
+
+        for action in actions {
+            let (before, was_locked) = state(&deps);
+            let result = exec(&mut deps, action.sender(), action.msg());
+            let (after, locked) = state(&deps);
+
+            prop_assert_eq!(&after.issuer, &address(ISSUER));
+            if was_locked {
+                prop_assert!(!matches!(action, Action::Transfer { .. }) || result.is_err(), "transferred while locked");
+            }
+            if after.owner != before.owner {
+                prop_assert!(result.is_ok(), "owner changed by a failed {:?}", action);
+                prop_assert!(matches!(action, Action::Transfer { .. }), "owner changed by {:?}", action);
+            }
+
+            match (&action, result.is_ok()) {
+                (_, false) => {
+                    prop_assert_eq!(&after, &before);
+                    prop_assert_eq!(locked, was_locked);
+                }
+                (Action::Transfer { recipient, .. }, true) => {
+                    prop_assert_eq!(&after.owner, &address(*recipient));
+                    prop_assert!(!locked);
+                }
+                // a release only hands the ownable back to its owner
+                (Action::Release { to, .. }, true) => {
+                    prop_assert_eq!(&after.owner, &address(*to));
+                    prop_assert_eq!(&after.owner, &before.owner);
+                    prop_assert!(!locked);
+                }
+                (Action::Lock { .. }, true) => prop_assert!(locked),
+                (Action::Unlock { .. }, true) => prop_assert!(!locked),
+            }
+        }
+    }
+}
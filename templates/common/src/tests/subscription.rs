@@ -0,0 +1,71 @@
+use serde_json::json;
+
+use super::{at, attr, exec_at, query_at, setup, TestDeps, ALICE, ISSUER};
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, OwnerResponse, QueryMsg};
+use crate::testing::address;
+
+const EXPIRES_AT: u64 = 1_800_000_000;
+
+fn subscription() -> TestDeps {
+    setup(json!({ "expires_at": EXPIRES_AT }))
+}
+
+fn is_active(deps: &TestDeps, seconds: u64) -> bool {
+    query_at(deps, at(seconds), QueryMsg::IsActive {})
+}
+
+fn renew(additional_secs: u64) -> ExecuteMsg {
+    ExecuteMsg::Renew { additional_secs }
+}
+
+#[test]
+fn active_subscription_is_extended_from_its_expiry() {
+    let mut deps = subscription();
+
+    let response = exec_at(&mut deps, at(EXPIRES_AT - 100), ISSUER, &[], renew(1_000)).unwrap();
+
+    assert_eq!(attr(&response, "expires_at"), Some((EXPIRES_AT + 1_000).to_string().as_str()));
+    assert!(is_active(&deps, EXPIRES_AT + 999));
+    assert!(!is_active(&deps, EXPIRES_AT + 1_000));
+}
+
+#[test]
+fn expired_subscription_is_renewed_from_now() {
+    let mut deps = subscription();
+    let now = EXPIRES_AT + 5_000;
+    assert!(!is_active(&deps, now));
+
+    exec_at(&mut deps, at(now), ISSUER, &[], renew(1_000)).unwrap();
+
+    assert!(is_active(&deps, now + 999));
+    assert!(!is_active(&deps, now + 1_000));
+}
+
+#[test]
+fn only_the_owner_renews() {
+    let mut deps = subscription();
+
+    let error = exec_at(&mut deps, at(EXPIRES_AT), ALICE, &[], renew(1_000)).unwrap_err();
+
+    assert!(matches!(error, ContractError::Unauthorized { .. }));
+    assert!(!is_active(&deps, EXPIRES_AT));
+}
+
+#[test]
+fn expired_subscription_is_still_transferred() {
+    let mut deps = subscription();
+
+    exec_at(&mut deps, at(EXPIRES_AT + 1), ISSUER, &[], ExecuteMsg::Transfer {
+        recipient: address(ALICE).to_string(),
+        memo: None,
+        sale_price: None,
+    })
+    .unwrap();
+
+    let owner: OwnerResponse = query_at(&deps, at(EXPIRES_AT + 1), QueryMsg::GetOwner {});
+    assert_eq!(owner.owner, address(ALICE));
+    assert!(!is_active(&deps, EXPIRES_AT + 1));
+    exec_at(&mut deps, at(EXPIRES_AT + 1), ALICE, &[], renew(60)).unwrap();
+    assert!(is_active(&deps, EXPIRES_AT + 1));
+}
@@ -0,0 +1,63 @@
+use cosmwasm_std::Timestamp;
+use serde_json::json;
+
+use super::{at, exec_at, query_at, setup, TestDeps, ALICE, ISSUER};
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, QueryMsg};
+use crate::testing::address;
+
+const LOCKED_UNTIL: u64 = 1_700_000_000;
+
+fn transfer_locked() -> TestDeps {
+    setup(json!({ "transfer_locked_until": Timestamp::from_seconds(LOCKED_UNTIL) }))
+}
+
+fn transfer() -> ExecuteMsg {
+    ExecuteMsg::Transfer { recipient: address(ALICE).to_string(), memo: None, sale_price: None }
+}
+
+#[test]
+fn not_transferable_before_the_lock_ends() {
+    let mut deps = transfer_locked();
+
+    assert!(!query_at::<bool>(&deps, at(LOCKED_UNTIL - 1), QueryMsg::IsActive {}));
+    let error = exec_at(&mut deps, at(LOCKED_UNTIL - 1), ISSUER, &[], transfer()).unwrap_err();
+    assert!(matches!(error, ContractError::CustomError { val } if val.starts_with("Transfers are locked")));
+}
+
+#[test]
+fn transferable_once_the_lock_ends() {
+    let mut deps = transfer_locked();
+
+    assert!(query_at::<bool>(&deps, at(LOCKED_UNTIL), QueryMsg::IsActive {}));
+    exec_at(&mut deps, at(LOCKED_UNTIL), ISSUER, &[], transfer()).unwrap();
+}
+
+#[test]
+fn transferable_without_a_lock() {
+    let deps = setup(json!({}));
+
+    assert!(query_at::<bool>(&deps, at(LOCKED_UNTIL), QueryMsg::IsActive {}));
+}
+
+#[cfg(feature = "subscription")]
+#[test]
+fn subscription_is_active_until_it_expires() {
+    let expires_at = 1_800_000_000;
+    let deps = setup(json!({ "expires_at": expires_at }));
+
+    assert!(query_at::<bool>(&deps, at(expires_at - 1), QueryMsg::IsActive {}));
+    assert!(!query_at::<bool>(&deps, at(expires_at), QueryMsg::IsActive {}));
+}
+
+#[cfg(feature = "subscription")]
+#[test]
+fn transfer_locked_subscription_is_inactive() {
+    let deps = setup(json!({
+        "expires_at": 1_800_000_000,
+        "transfer_locked_until": Timestamp::from_seconds(LOCKED_UNTIL),
+    }));
+
+    assert!(!query_at::<bool>(&deps, at(LOCKED_UNTIL - 1), QueryMsg::IsActive {}));
+    assert!(query_at::<bool>(&deps, at(LOCKED_UNTIL), QueryMsg::IsActive {}));
+}
@@ -0,0 +1,240 @@
+//! JSON wire format of the query responses, as read by the JS clients
+
+use cosmwasm_std::testing::mock_env;
+use cosmwasm_std::{Addr, Timestamp};
+use ownable_std::{InfoResponse, Metadata};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use super::{exec, setup, TestDeps, ALICE, ISSUER};
+use crate::contract::query;
+use crate::msg::{
+    CapabilitiesResponse, CreatorResponse, Eip155Response, ExecuteMsg, LockProofResponse,
+    NetworkAddressResponse, NotifyMsg, OwnerResponse, PackageCidResponse, QueryMsg, WidgetStateResponse,
+};
+use crate::pagination::Page;
+#[cfg(feature = "artwork")]
+use crate::state::ArtworkInfo;
+use crate::state::{AuthPolicy, Config, LockInfo, LockKind, OwnableType, OwnershipRecord, Thumbnail};
+use crate::testing::address;
+
+/// Raw JSON a query answers with
+fn query_json(deps: &TestDeps, msg: QueryMsg) -> Value {
+    let binary = query(deps.as_ref(), mock_env(), msg).expect("query");
+    serde_json::from_slice(binary.as_slice()).expect("query response is JSON")
+}
+
+/// Parse `json` as `T` and check it serializes back to the same JSON
+fn round_trip<T: Serialize + DeserializeOwned>(json: &Value) -> T {
+    let value: T = serde_json::from_value(json.clone()).expect("parse");
+    assert_eq!(&serde_json::to_value(&value).unwrap(), json);
+    value
+}
+
+/// Serialize `value` and check it parses back to an equal value
+fn to_json<T: Serialize + DeserializeOwned + PartialEq + std::fmt::Debug>(value: &T) -> Value {
+    let json = serde_json::to_value(value).unwrap();
+    assert_eq!(&serde_json::from_value::<T>(json.clone()).unwrap(), value);
+    json
+}
+
+fn keys(json: &Value) -> Vec<&str> {
+    let mut keys: Vec<&str> = json.as_object().expect("JSON object").keys().map(String::as_str).collect();
+    keys.sort_unstable();
+    keys
+}
+
+/// Every object key below `json` is snake_case
+fn assert_snake_case(json: &Value) {
+    match json {
+        Value::Object(map) => {
+            for (key, value) in map {
+                assert!(
+                    !key.is_empty() && key.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_'),
+                    "{} is not snake_case",
+                    key
+                );
+                assert_snake_case(value);
+            }
+        }
+        Value::Array(items) => items.iter().for_each(assert_snake_case),
+        _ => {}
+    }
+}
+
+#[test]
+fn info_response() {
+    let deps = setup(json!({}));
+
+    let json = query_json(&deps, QueryMsg::GetInfo {});
+
+    assert_eq!(keys(&json), ["issuer", "nft", "ownable_type", "owner"]);
+    assert_snake_case(&json);
+    let info: InfoResponse = round_trip(&json);
+    assert_eq!(info.owner, address(ISSUER));
+}
+
+#[test]
+fn metadata_response() {
+    let deps = setup(json!({}));
+
+    let json = query_json(&deps, QueryMsg::GetMetadata {});
+
+    assert_eq!(keys(&json), [
+        "animation_url",
+        "background_color",
+        "description",
+        "external_url",
+        "image",
+        "image_data",
+        "name",
+        "youtube_url",
+    ]);
+    assert_snake_case(&json);
+    round_trip::<Metadata>(&json);
+}
+
+#[test]
+fn widget_state_response() {
+    let deps = setup(json!({ "royalty_bps": 500 }));
+
+    let json = query_json(&deps, QueryMsg::GetWidgetState {});
+
+    assert_eq!(keys(&json), ["config", "contract_address", "locked", "ownable_type", "thumbnail"]);
+    assert_eq!(keys(&json["config"]), [
+        "accepted_denom",
+        "admin",
+        "bridgeable",
+        "lock_policy",
+        "notify_contract",
+        "royalty_bps",
+        "transfer_locked_until",
+        "transfer_policy",
+    ]);
+    assert_eq!(keys(&json["thumbnail"]), ["data", "url"]);
+    assert_eq!(json["config"]["transfer_policy"], json!("owner_only"));
+    assert_snake_case(&json);
+    round_trip::<WidgetStateResponse>(&json);
+}
+
+#[test]
+fn lock_info_response() {
+    let mut deps = setup(json!({}));
+    exec(&mut deps, ISSUER, ExecuteMsg::Lock {}).unwrap();
+
+    let json = query_json(&deps, QueryMsg::GetLockInfo {});
+
+    assert_eq!(keys(&json), ["at_height", "event_network", "kind", "locked_by"]);
+    assert_eq!(json["kind"], json!("owner"));
+    round_trip::<Option<LockInfo>>(&json);
+}
+
+#[test]
+fn ownership_history_page() {
+    let mut deps = setup(json!({}));
+    exec(&mut deps, ISSUER, ExecuteMsg::Transfer {
+        recipient: address(ALICE).to_string(),
+        memo: None,
+        sale_price: None,
+    })
+    .unwrap();
+
+    let json = query_json(&deps, QueryMsg::GetOwnershipHistory { start_after: None, limit: None });
+
+    assert_eq!(keys(&json), ["items", "next_start_after"]);
+    assert_eq!(keys(&json["items"][0]), ["height", "owner", "timestamp"]);
+    assert_snake_case(&json);
+    round_trip::<Page<OwnershipRecord>>(&json);
+}
+
+#[test]
+fn small_responses() {
+    let owner = to_json(&OwnerResponse { owner: Addr::unchecked("owner"), issuer: Addr::unchecked("issuer") });
+    assert_eq!(keys(&owner), ["issuer", "owner"]);
+
+    let creator = to_json(&CreatorResponse { creator: None, issuer: Addr::unchecked("issuer") });
+    assert_eq!(keys(&creator), ["creator", "issuer"]);
+
+    let capabilities = to_json(&CapabilitiesResponse { features: vec!["subscription".to_string()] });
+    assert_eq!(keys(&capabilities), ["features"]);
+
+    let cid = to_json(&PackageCidResponse { cid: "bafy".to_string() });
+    assert_eq!(keys(&cid), ["cid"]);
+
+    let eip155 = to_json(&Eip155Response { address: "0xabc".to_string() });
+    assert_eq!(keys(&eip155), ["address"]);
+
+    let network_address = to_json(&NetworkAddressResponse {
+        network_id: "T".to_string(),
+        address: "3N".to_string(),
+    });
+    assert_eq!(keys(&network_address), ["address", "network_id"]);
+}
+
+#[test]
+fn lock_proof_response() {
+    let json = to_json(&LockProofResponse {
+        ownable_id: "simulate".to_string(),
+        contract_address: Addr::unchecked("contract"),
+        network: "eip155:1".to_string(),
+        contract: "0xabc".to_string(),
+        token_id: "1".to_string(),
+        owner: Addr::unchecked("owner"),
+        commitment: "00".to_string(),
+    });
+
+    assert_eq!(keys(&json), [
+        "commitment",
+        "contract",
+        "contract_address",
+        "network",
+        "ownable_id",
+        "owner",
+        "token_id",
+    ]);
+}
+
+#[test]
+fn notify_msg() {
+    let json = to_json(&NotifyMsg::OwnerChanged {
+        ownable_id: "simulate".to_string(),
+        owner: Addr::unchecked("owner"),
+    });
+
+    assert_eq!(json, json!({ "owner_changed": { "ownable_id": "simulate", "owner": "owner" } }));
+}
+
+#[test]
+fn enums_are_snake_case() {
+    assert_eq!(to_json(&OwnableType::Subscription), json!("subscription"));
+    assert_eq!(to_json(&AuthPolicy::OwnerOrApproved), json!("owner_or_approved"));
+    assert_eq!(to_json(&AuthPolicy::OwnerOrIssuer), json!("owner_or_issuer"));
+    assert_eq!(to_json(&LockKind::Bridge), json!("bridge"));
+    assert_eq!(to_json(&QueryMsg::GetWidgetState {}), json!({ "get_widget_state": {} }));
+}
+
+#[test]
+fn config_defaults_fields_missing_from_older_json() {
+    let config: Config = serde_json::from_value(json!({ "notify_contract": null })).unwrap();
+
+    assert!(config.bridgeable);
+    assert_eq!(config.accepted_denom, "lto");
+    assert_eq!(config.transfer_policy, AuthPolicy::OwnerOnly);
+    assert_eq!(config.transfer_locked_until, None::<Timestamp>);
+    assert_eq!(to_json(&Thumbnail::default()), json!({ "url": null, "data": null }));
+}
+
+#[cfg(feature = "artwork")]
+#[test]
+fn artwork_info() {
+    let json = to_json(&ArtworkInfo {
+        medium: "oil on canvas".to_string(),
+        dimensions: None,
+        year: 2020,
+        edition: Some(1),
+        edition_total: Some(10),
+    });
+
+    assert_eq!(keys(&json), ["dimensions", "edition", "edition_total", "medium", "year"]);
+}
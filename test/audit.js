@@ -0,0 +1,73 @@
+const assert = require("assert");
+const fs = require("fs-extra");
+const os = require("os");
+const path = require("path");
+const { audit, auditSource } = require("../lib/commands/audit");
+const { scaffold } = require("../lib/commands/create");
+const { TEMPLATES } = require("../lib/utils/templates");
+
+const METADATA = {
+  name: "audit-check",
+  description: "Audit check",
+  version: "1.0.0",
+  authors: "ownables",
+  keywords: ["audit"],
+};
+
+const UNSAFE_CONTRACT = `pub fn execute(info: MessageInfo, deps: DepsMut, msg: ExecuteMsg) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Transfer { to } => try_transfer(info, deps, to),
+    }
+}
+
+pub fn try_transfer(info: MessageInfo, deps: DepsMut, to: Addr) -> Result<Response, ContractError> {
+    let mut ownership = OWNABLE_INFO.load(deps.storage).unwrap();
+    ownership.owner = to;
+    OWNABLE_INFO.save(deps.storage, &ownership)?;
+    Ok(Response::new().add_attribute("info", format!("{{\\"owner\\": \\"{}\\"}}", ownership.owner)))
+}
+`;
+
+async function testAudit() {
+  const findings = auditSource(UNSAFE_CONTRACT, "contract.rs");
+  assert.deepStrictEqual(
+    findings.map(({ rule, severity, line }) => `${severity} ${rule} ${line}`),
+    [
+      "high missing-sender-check 7",
+      "high unwrap-in-handler 8",
+      "medium missing-lock-check 7",
+      "medium formatted-json 11",
+    ]
+  );
+  console.log("✓ Audit reports findings by severity then line");
+
+  const allowed = auditSource(UNSAFE_CONTRACT, "contract.rs", ["unwrap-in-handler", "formatted-json"]);
+  assert.deepStrictEqual(
+    allowed.map((finding) => finding.rule),
+    ["missing-sender-check", "missing-lock-check"]
+  );
+  console.log("✓ Audit skips allowed rules");
+
+  const tmpDir = await fs.mkdtemp(path.join(os.tmpdir(), "ownable-audit-"));
+  try {
+    const unsafeDir = path.join(tmpDir, "unsafe");
+    await fs.outputFile(path.join(unsafeDir, "src", "contract.rs"), UNSAFE_CONTRACT);
+    await assert.rejects(audit(unsafeDir), /2 high severity findings/);
+    const remaining = await audit(unsafeDir, { allow: ["missing-sender-check", "unwrap-in-handler"] });
+    assert.deepStrictEqual(remaining.map((finding) => finding.severity), ["medium", "medium"]);
+    await assert.rejects(audit(unsafeDir, { allow: ["no-such-rule"] }), /Unknown rule no-such-rule/);
+    console.log("✓ Audit fails only on high severity findings");
+
+    for (const template of TEMPLATES) {
+      const projectDir = path.join(tmpDir, template.name);
+      await scaffold(template.name, METADATA, projectDir);
+      const templateFindings = await audit(projectDir);
+      assert.deepStrictEqual(templateFindings, [], `${template.name} should audit clean`);
+    }
+    console.log("✓ Shipped templates audit clean");
+  } finally {
+    await fs.remove(tmpDir);
+  }
+}
+
+module.exports = { testAudit };
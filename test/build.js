@@ -0,0 +1,134 @@
+const assert = require("assert");
+const fs = require("fs-extra");
+const os = require("os");
+const path = require("path");
+const { buildWasm, checkCrateVersion, reproducibleBuildEnv, wasmOptArgs } = require("../lib/commands/build");
+
+// Stand-ins for cargo and wasm-bindgen: cargo logs its arguments and build
+// environment and writes a module into CARGO_TARGET_DIR, wasm-bindgen copies
+// it to --out-dir
+const FAKE_CARGO = `#!/bin/sh
+echo "$* | RUSTFLAGS=$RUSTFLAGS | SOURCE_DATE_EPOCH=$SOURCE_DATE_EPOCH" >> "$FAKE_TOOL_LOG"
+mkdir -p "$CARGO_TARGET_DIR/wasm32-unknown-unknown/release"
+printf 'asm' > "$CARGO_TARGET_DIR/wasm32-unknown-unknown/release/demo.wasm"
+`;
+const FAKE_WASM_BINDGEN = `#!/bin/sh
+input="$1"
+while [ $# -gt 0 ]; do
+  if [ "$1" = "--out-dir" ]; then out="$2"; fi
+  shift
+done
+name=$(basename "$input" .wasm)
+cp "$input" "$out/\${name}_bg.wasm"
+echo "export default {};" > "$out/$name.js"
+`;
+
+/**
+ * Build a minimal project with the fake toolchain on PATH and return the
+ * build result with the logged cargo invocations
+ */
+async function fakeBuild(dir, options) {
+  const bin = path.join(dir, "bin");
+  for (const [tool, script] of [["cargo", FAKE_CARGO], ["wasm-bindgen", FAKE_WASM_BINDGEN]]) {
+    await fs.outputFile(path.join(bin, tool), script);
+    await fs.chmod(path.join(bin, tool), 0o755);
+  }
+  const project = path.join(dir, "project");
+  await fs.outputFile(path.join(project, "Cargo.toml"), '[package]\nname = "demo"\nversion = "1.0.0"\n');
+  // an existing schema skips `cargo run --example schema`
+  await fs.ensureDir(path.join(project, "build", "schema"));
+  const log = path.join(dir, "cargo.log");
+
+  const saved = ["PATH", "RUSTFLAGS", "CARGO_TARGET_DIR", "SOURCE_DATE_EPOCH", "FAKE_TOOL_LOG"]
+    .map((key) => [key, process.env[key]]);
+  process.env.PATH = `${bin}${path.delimiter}${process.env.PATH}`;
+  process.env.FAKE_TOOL_LOG = log;
+  try {
+    const result = await buildWasm(project, { text: "" }, { optimize: false, ...options });
+    return { ...result, project, log: await fs.readFile(log, "utf8") };
+  } finally {
+    for (const [key, value] of saved) {
+      if (value === undefined) delete process.env[key];
+      else process.env[key] = value;
+    }
+  }
+}
+
+// Warnings checkCrateVersion prints for a Cargo.toml with `version`
+async function versionWarnings(version) {
+  const dir = await fs.mkdtemp(path.join(os.tmpdir(), "ownable-build-"));
+  const cargoPath = path.join(dir, "Cargo.toml");
+  await fs.writeFile(cargoPath, `[package]\nname = "demo"\nversion = ${version}\n`);
+
+  const warnings = [];
+  const warn = console.warn;
+  console.warn = (message) => warnings.push(message);
+  try {
+    await checkCrateVersion(cargoPath);
+  } finally {
+    console.warn = warn;
+    await fs.remove(dir);
+  }
+  return warnings;
+}
+
+async function testBuild() {
+  const args = wasmOptArgs("/tmp/demo_bg.wasm");
+  for (const flag of ["--enable-threads", "--enable-bulk-memory", "--enable-mutable-globals"]) {
+    assert(args.includes(flag), `wasm-opt should get ${flag}`);
+  }
+  assert.deepStrictEqual(args.slice(-3), ['"/tmp/demo_bg.wasm"', "-o", '"/tmp/demo_bg.wasm"']);
+  console.log("✓ wasm-opt enables the features the module is built with");
+
+  assert.strictEqual((await versionWarnings("PLACEHOLDER1_VERSION")).length, 1);
+  assert.strictEqual((await versionWarnings('"0.0.0"')).length, 1);
+  assert.deepStrictEqual(await versionWarnings('"1.2.0"'), []);
+  console.log("✓ Unset crate versions are reported, including unrendered placeholders");
+
+  const dir = await fs.mkdtemp(path.join(os.tmpdir(), "ownable-build-"));
+  try {
+    const targetDir = path.join(dir, "shared-target");
+    const custom = await fakeBuild(dir, { targetDir });
+    assert(
+      await fs.pathExists(path.join(targetDir, "wasm32-unknown-unknown", "release", "demo.wasm")),
+      "cargo should build into --target-dir"
+    );
+    assert(!(await fs.pathExists(path.join(custom.project, "target"))));
+    assert.strictEqual(custom.wasmPath, path.join(custom.project, "build", "demo_bg.wasm"));
+    assert(await fs.pathExists(custom.wasmPath), "the module should be found in --target-dir");
+
+    const local = await fakeBuild(dir, {});
+    assert(await fs.pathExists(path.join(local.project, "target", "wasm32-unknown-unknown", "release", "demo.wasm")));
+    console.log("✓ --target-dir is passed to cargo and the module is found there");
+
+    const project = path.join(dir, "project");
+    await fs.remove(path.join(project, "Cargo.lock"));
+    await assert.rejects(
+      () => reproducibleBuildEnv(project, targetDir),
+      /need a Cargo\.lock/
+    );
+    await assert.rejects(() => fakeBuild(dir, { reproducible: true }), /need a Cargo\.lock/);
+
+    await fs.writeFile(path.join(project, "Cargo.lock"), "version = 3\n");
+    await fs.writeFile(path.join(project, "rust-toolchain.toml"), '[toolchain]\nchannel = "1.81.0"\n');
+    const env = await reproducibleBuildEnv(project, targetDir);
+    assert(env.rustflags.includes(`--remap-path-prefix=${project}=/ownable`));
+    assert(env.rustflags.includes(`--remap-path-prefix=${targetDir}=/target`));
+    assert(env.rustflags.includes("-C codegen-units=1"));
+    assert.strictEqual(env.cargoArgs, "--locked");
+
+    await fs.remove(path.join(dir, "cargo.log"));
+    const first = await fakeBuild(dir, { reproducible: true, targetDir });
+    const second = await fakeBuild(dir, { reproducible: true, targetDir });
+    assert.strictEqual(first.sha256, second.sha256);
+    const invocation = second.log.trim().split("\n").pop();
+    assert(invocation.includes("--locked"), invocation);
+    assert(invocation.includes("--remap-path-prefix"), invocation);
+    assert(invocation.includes("SOURCE_DATE_EPOCH=0"), invocation);
+    console.log("✓ --reproducible requires a Cargo.lock and pins paths, lockfile and timestamps");
+  } finally {
+    await fs.remove(dir);
+  }
+}
+
+module.exports = { testBuild };
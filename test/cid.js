@@ -0,0 +1,30 @@
+const assert = require("assert");
+const { computeCid, CHUNK_SIZE, MAX_LINKS } = require("../lib/utils/cid");
+
+// CIDs `ipfs add --cid-version=1` reports for these contents
+const KNOWN_CIDS = [
+  ["", "bafkreihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku"],
+  ["hello world", "bafkreifzjut3te2nhyekklss27nh3k72ysco7y32koao5eei66wof36n5e"],
+];
+
+async function testCid() {
+  for (const [content, cid] of KNOWN_CIDS) {
+    assert.strictEqual(computeCid(Buffer.from(content)), cid);
+  }
+  console.log("✓ CIDs match the known vectors");
+
+  // one chunk is a raw block, more is a dag-pb root over the chunks
+  assert(computeCid(Buffer.alloc(CHUNK_SIZE)).startsWith("bafkrei"));
+  const chunked = Buffer.alloc(CHUNK_SIZE + 1);
+  assert(computeCid(chunked).startsWith("bafybei"));
+  assert.strictEqual(computeCid(chunked), computeCid(Buffer.alloc(CHUNK_SIZE + 1)));
+
+  // a change in any chunk, including past the first level of links, shows in the root
+  const deep = Buffer.alloc(CHUNK_SIZE * (MAX_LINKS + 1));
+  const root = computeCid(deep);
+  deep[deep.length - 1] = 1;
+  assert.notStrictEqual(computeCid(deep), root);
+  console.log("✓ Large files are chunked into a UnixFS DAG");
+}
+
+module.exports = { testCid };
@@ -0,0 +1,86 @@
+const assert = require("assert");
+const { codegenTs, schemaToTypeScript } = require("../lib/commands/codegenTs");
+
+const EXECUTE_SCHEMA = require("./fixtures/execute_msg.json");
+
+const EXPECTED_EXECUTE = `// Generated by \`ownables codegen-ts\` from the contract's JSON schema. Do not edit.
+
+export type ExecuteMsg =
+  | "renounce"
+  | { transfer: ExecuteMsg.Transfer }
+  | { consume: ExecuteMsg.Consume }
+  | { set_bridgeable: ExecuteMsg.SetBridgeable };
+
+export namespace ExecuteMsg {
+  /** Hand the ownable to \`recipient\` */
+  export type Transfer = {
+    recipient: string;
+    memo?: string | null;
+    sale_price?: Coin | null;
+  };
+  export type Consume = {
+    amount: number;
+  };
+  export type SetBridgeable = {
+    bridgeable: boolean;
+  };
+}
+
+export interface Coin {
+  amount: Uint128;
+  denom: string;
+}
+
+/** A string containing a 128-bit integer */
+export type Uint128 = string;
+`;
+
+const PAGE_SCHEMA = {
+  title: "Page_for_Trait",
+  description: "One page of a list query.\nPass `next_start_after` back as `start_after`.",
+  type: "object",
+  required: ["items"],
+  properties: {
+    items: { type: "array", items: { $ref: "#/definitions/Trait" } },
+    next_start_after: { type: ["integer", "null"] },
+    "display-type": { anyOf: [{ type: "string" }, { type: "null" }] },
+    range: { type: "array", items: [{ type: "integer" }, { type: "integer" }] },
+    labels: { type: "object", additionalProperties: { type: "string" } },
+  },
+  definitions: {
+    Trait: {
+      type: "object",
+      required: ["trait_type", "value"],
+      properties: {
+        trait_type: { type: "string" },
+        value: { type: "string" },
+      },
+    },
+    Coin: { type: "object", properties: { denom: { type: "string" } } },
+  },
+};
+
+async function testCodegenTs() {
+  assert.strictEqual(schemaToTypeScript([EXECUTE_SCHEMA]), EXPECTED_EXECUTE);
+  console.log("✓ Codegen-ts declares execute variants as a tagged union");
+
+  const output = schemaToTypeScript([EXECUTE_SCHEMA, PAGE_SCHEMA]);
+  assert(output.includes(
+    "/**\n * One page of a list query.\n * Pass `next_start_after` back as `start_after`.\n */\n" +
+      "export interface PageForTrait {\n" +
+      "  items: Trait[];\n" +
+      "  next_start_after?: number | null;\n" +
+      '  "display-type"?: string | null;\n' +
+      "  range?: [number, number];\n" +
+      "  labels?: Record<string, string>;\n" +
+      "}\n"
+  ), output);
+  assert.strictEqual(output.match(/export interface Coin/g).length, 1);
+  assert(output.indexOf("amount: Uint128") < output.indexOf("export interface PageForTrait"));
+  console.log("✓ Codegen-ts declares shared definitions once");
+
+  await assert.rejects(codegenTs("."), /Missing output file: pass --out <file>/);
+  console.log("✓ Codegen-ts requires an output file");
+}
+
+module.exports = { testCodegenTs };
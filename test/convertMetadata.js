@@ -0,0 +1,41 @@
+const assert = require("assert");
+const { convert } = require("../lib/commands/convertMetadata");
+
+// Representative OpenSea-style metadata
+const ERC721 = {
+  name: "Dave Starbelly",
+  description: "Friendly OpenSea Creature that enjoys long swims in the ocean.",
+  image: "ipfs://bafkreifzjut3te2nhyekklss27nh3k72ysco7y32koao5eei66wof36n5e",
+  external_url: "https://openseacreatures.io/3",
+  attributes: [
+    { trait_type: "Base", value: "Starfish" },
+    { trait_type: "Level", value: 5, display_type: "number" },
+    { trait_type: "Stamina", value: 1.4, display_type: "boost_number" },
+  ],
+};
+
+async function testConvertMetadata() {
+  const warnings = [];
+  const ownable = convert(ERC721, "erc721", "ownable", (message) => warnings.push(message));
+  assert.deepStrictEqual(ownable.attributes, [
+    { trait_type: "Base", value: "Starfish" },
+    { trait_type: "Level", value: "5", display_type: "number" },
+    { trait_type: "Stamina", value: "1.4", display_type: "boost_number" },
+  ]);
+  assert.deepStrictEqual(warnings, []);
+  assert.deepStrictEqual(convert(ownable, "ownable", "erc721"), ERC721);
+  console.log("✓ ERC-721 metadata round-trips, attributes included");
+
+  const dropped = [];
+  const partial = convert(
+    { name: "x", attributes: [{ value: "no type" }], unknown: 1 },
+    "erc721",
+    "ownable",
+    (message) => dropped.push(message)
+  );
+  assert.deepStrictEqual(partial, { name: "x", attributes: [] });
+  assert.strictEqual(dropped.length, 2);
+  console.log("✓ Unmapped metadata is reported");
+}
+
+module.exports = { testConvertMetadata };
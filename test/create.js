@@ -0,0 +1,97 @@
+const assert = require("assert");
+const fs = require("fs-extra");
+const os = require("os");
+const path = require("path");
+const toml = require("@iarna/toml");
+const { readImageData, replacePlaceholders, MAX_IMAGE_SIZE } = require("../lib/commands/create");
+const { TEMPLATES } = require("../lib/utils/templates");
+const { fromCargoGenerate, toCargoGenerate } = require("../lib/utils/cargoGenerate");
+
+const METADATA = {
+  name: "demo",
+  description: 'Says "hi" \\ and\nmore',
+  version: "1.0.0",
+  authors: "Ann \"A\" Example",
+  keywords: ["art", "quote\"d"],
+};
+
+// a 1x1 transparent png
+const PNG = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAQAAAC1HAwCAAAAC0lEQVR42mNkYAAAAAYAAjCB0C8AAAAASUVORK5CYII=";
+
+async function render(content, overrides = {}, template = "static-ownable") {
+  const dir = await fs.mkdtemp(path.join(os.tmpdir(), "ownable-create-"));
+  const file = path.join(dir, "file");
+  try {
+    await fs.writeFile(file, content);
+    await replacePlaceholders(file, { ...METADATA, ...overrides }, template);
+    return await fs.readFile(file, "utf8");
+  } finally {
+    await fs.remove(dir);
+  }
+}
+
+async function testCreate() {
+  const cargo = toml.parse(await render(
+    "[package]\ndescription = PLACEHOLDER1_DESCRIPTION\nauthors = [PLACEHOLDER1_AUTHORS]\n"
+  ));
+  assert.strictEqual(cargo.package.description, METADATA.description);
+  assert.deepStrictEqual(cargo.package.authors, [METADATA.authors]);
+  console.log("✓ Cargo.toml values are escaped");
+
+  assert.strictEqual(
+    await render("const DESCRIPTION: &str = PLACEHOLDER4_DESCRIPTION;"),
+    'const DESCRIPTION: &str = "Says \\"hi\\" \\\\ and\\nmore";'
+  );
+  assert.strictEqual(
+    await render("PLACEHOLDER4_BELL", { extra: { bell: "\u0007\b" } }),
+    '"\\u{0007}\\u{8}"'
+  );
+  console.log("✓ Rust string values are escaped");
+
+  // build fills in the asset paths once it knows the files
+  assert.strictEqual(await render('<img src="PLACEHOLDER2_COVER">'), '<img src="PLACEHOLDER2_COVER">');
+  console.log("✓ Asset placeholders are left for build");
+
+  assert.strictEqual(
+    await render("use {{crate_name}}::msg; // {{ project-name }} {{version}} {{unknown}}", { name: "my-song" }),
+    "use my_song::msg; // my-song 1.0.0 {{unknown}}"
+  );
+  const cargoGenerate = toCargoGenerate("name = PLACEHOLDER1_NAME\nuse PLACEHOLDER3_MSG::msg;");
+  assert.strictEqual(cargoGenerate, 'name = "{{project-name}}"\nuse {{crate_name}}::msg;');
+  assert.strictEqual(
+    fromCargoGenerate(cargoGenerate, { ...METADATA, name: "my-song" }),
+    'name = "my-song"\nuse my_song::msg;'
+  );
+  console.log("✓ cargo-generate variables are substituted");
+
+  // the widget reports the compiled type, serialized in snake_case
+  for (const template of TEMPLATES) {
+    assert.strictEqual(
+      await render("const OWNABLE_TYPE: OwnableType = PLACEHOLDER4_OWNABLE_TYPE;", {}, template.name),
+      `const OWNABLE_TYPE: OwnableType = OwnableType::${template.variant};`
+    );
+    assert.strictEqual(template.variant.toLowerCase(), template.type);
+  }
+  console.log("✓ Each template compiles to its own ownable type");
+
+  const dir = await fs.mkdtemp(path.join(os.tmpdir(), "ownable-image-"));
+  try {
+    const png = Buffer.from(PNG, "base64");
+    await fs.writeFile(path.join(dir, "pixel.png"), png);
+    const imageData = await readImageData(path.join(dir, "pixel.png"));
+    assert.strictEqual(imageData, PNG);
+    assert.strictEqual(
+      await render("image_data: PLACEHOLDER4_IMAGE_DATA,", { imageData }),
+      `image_data: Some("${PNG}".to_string()),`
+    );
+    assert.strictEqual(await render("image_data: PLACEHOLDER4_IMAGE_DATA,"), "image_data: None,");
+
+    await fs.writeFile(path.join(dir, "large.png"), Buffer.alloc(MAX_IMAGE_SIZE + 1));
+    await assert.rejects(readImageData(path.join(dir, "large.png")), /limited to 256 KB/);
+  } finally {
+    await fs.remove(dir);
+  }
+  console.log("✓ --image is embedded as base64 image_data, up to 256 KB");
+}
+
+module.exports = { testCreate };
@@ -0,0 +1,24 @@
+const assert = require("assert");
+const fs = require("fs-extra");
+const os = require("os");
+const path = require("path");
+const shell = require("shelljs");
+const { init } = require("../lib/commands/init");
+
+// Runs init with its cargo checks, so it needs cargo and the crates.io index
+async function testInit() {
+  if (!shell.which("cargo")) {
+    console.log("- Skipping init check: cargo is not installed");
+    return;
+  }
+  const tmpDir = await fs.mkdtemp(path.join(os.tmpdir(), "ownable-init-"));
+  try {
+    const projectDir = await init("init-check", { dir: path.join(tmpDir, "init-check") });
+    assert(await fs.pathExists(path.join(projectDir, "src", "error.rs")));
+    console.log("✓ init generates a workspace that cargo checks, with and without entry points");
+  } finally {
+    await fs.remove(tmpDir);
+  }
+}
+
+module.exports = { testInit };
@@ -0,0 +1,39 @@
+const assert = require("assert");
+const fs = require("fs-extra");
+const os = require("os");
+const path = require("path");
+const { lint, lintSource } = require("../lib/commands/lint");
+const { scaffold } = require("../lib/commands/create");
+const { TEMPLATES } = require("../lib/utils/templates");
+
+const METADATA = {
+  name: "lint-check",
+  description: "Lint check",
+  version: "1.0.0",
+  authors: "ownables",
+  keywords: ["lint"],
+};
+
+async function testLint() {
+  const findings = lintSource(
+    "fn grant(_info: MessageInfo, deps: DepsMut) {\n    APPROVAL.save(deps.storage, &None);\n}\n",
+    "contract.rs"
+  );
+  assert.deepStrictEqual(findings.map((finding) => finding.rule), ["ignored-info"]);
+  console.log("✓ Lint flags state writes that ignore the sender");
+
+  const tmpDir = await fs.mkdtemp(path.join(os.tmpdir(), "ownable-lint-"));
+  try {
+    for (const template of TEMPLATES) {
+      const projectDir = path.join(tmpDir, template.name);
+      await scaffold(template.name, METADATA, projectDir);
+      const templateFindings = await lint(projectDir);
+      assert.deepStrictEqual(templateFindings, [], `${template.name} should lint clean`);
+    }
+    console.log("✓ Shipped templates lint clean");
+  } finally {
+    await fs.remove(tmpDir);
+  }
+}
+
+module.exports = { testLint };
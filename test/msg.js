@@ -0,0 +1,52 @@
+const assert = require("assert");
+const fs = require("fs-extra");
+const os = require("os");
+const path = require("path");
+const { msg, buildMessage } = require("../lib/commands/msg");
+
+const SCHEMA = require("./fixtures/execute_msg.json");
+
+async function testMsg() {
+  assert.strictEqual(
+    buildMessage(SCHEMA, "transfer", [
+      "--recipient", "3N3GFfVatjZQj2prQMGkGgxaCi36mv62JWM",
+      "--sale-price", '{"amount":"100","denom":"ulto"}',
+    ]),
+    '{"transfer":{"recipient":"3N3GFfVatjZQj2prQMGkGgxaCi36mv62JWM","sale_price":{"amount":"100","denom":"ulto"}}}'
+  );
+  assert.strictEqual(buildMessage(SCHEMA, "consume", ["--amount", "2"]), '{"consume":{"amount":2}}');
+  assert.strictEqual(
+    buildMessage(SCHEMA, "set-bridgeable", ["--bridgeable", "false"]),
+    '{"set_bridgeable":{"bridgeable":false}}'
+  );
+  assert.strictEqual(buildMessage(SCHEMA, "renounce"), '"renounce"');
+  console.log("✓ Msg types fields from the execute schema");
+
+  assert.throws(
+    () => buildMessage(SCHEMA, "burn"),
+    /Unknown message 'burn'. Known messages: renounce, transfer, consume, set-bridgeable/
+  );
+  assert.throws(() => buildMessage(SCHEMA, "transfer", ["--memo", "hi"]), /Missing required --recipient/);
+  assert.throws(
+    () => buildMessage(SCHEMA, "transfer", ["--recipient", "x", "--price", "1"]),
+    /Usage: ownables msg transfer --recipient <recipient> \[--memo <memo>\] \[--sale-price <sale_price>\]\nUnknown field '--price'/
+  );
+  assert.throws(() => buildMessage(SCHEMA, "transfer", ["--recipient"]), /Missing value for --recipient/);
+  assert.throws(() => buildMessage(SCHEMA, "consume", ["--amount", "two"]), /--amount must be a number/);
+  assert.throws(() => buildMessage(SCHEMA, "set-bridgeable", ["--bridgeable", "yes"]), /must be true or false/);
+  assert.throws(() => buildMessage(SCHEMA, "transfer", ["--recipient", "x", "--sale-price", "{"]), /--sale-price must be JSON/);
+  assert.throws(() => buildMessage(SCHEMA, "renounce", ["--now", "1"]), /'renounce' takes no fields/);
+  console.log("✓ Msg rejects unknown messages and bad fields");
+
+  const tmpDir = await fs.mkdtemp(path.join(os.tmpdir(), "ownable-msg-"));
+  try {
+    await assert.rejects(msg("consume", ["--amount", "1"], { dir: tmpDir }), /Run 'ownables schema' first/);
+    await fs.outputJson(path.join(tmpDir, "schema", "execute_msg.json"), SCHEMA);
+    assert.strictEqual(await msg("consume", ["--amount", "1"], { dir: tmpDir }), '{"consume":{"amount":1}}');
+    console.log("✓ Msg reads the schema written by 'ownables schema'");
+  } finally {
+    await fs.remove(tmpDir);
+  }
+}
+
+module.exports = { testMsg };
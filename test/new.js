@@ -0,0 +1,192 @@
+const assert = require("assert");
+const fs = require("fs-extra");
+const os = require("os");
+const path = require("path");
+const { execAsync } = require("../lib/utils/execAsync");
+const {
+  dryRunScaffold,
+  newOwnable,
+  readManifest,
+  replacePlaceholders,
+} = require("../lib/commands/create");
+const {
+  getTemplate,
+  resolveTemplate,
+  resolveTemplateVersion,
+  TEMPLATES,
+  templateDirs,
+  TEMPLATE_VERSION,
+} = require("../lib/utils/templates");
+
+const OPTIONS = { crateVersion: "1.0.0", authors: "tester" };
+
+/**
+ * Run `new`, keeping its next steps out of the test output
+ */
+async function quietly(fn) {
+  const log = console.log;
+  console.log = () => {};
+  try {
+    return await fn();
+  } finally {
+    console.log = log;
+  }
+}
+
+/**
+ * Every file under `dir` with its contents
+ */
+async function snapshot(dir, base = dir) {
+  const files = {};
+  for (const entry of await fs.readdir(dir, { withFileTypes: true })) {
+    const fullPath = path.join(dir, entry.name);
+    if (entry.isDirectory()) {
+      Object.assign(files, await snapshot(fullPath, base));
+    } else {
+      files[path.relative(base, fullPath)] = await fs.readFile(fullPath, "utf8");
+    }
+  }
+  return files;
+}
+
+async function testNew() {
+  assert.strictEqual(resolveTemplate("music").name, "music-ownable");
+  assert.throws(() => resolveTemplate("video"), /No template for ownable type 'video'/);
+
+  TEMPLATES.push({ name: "other-music-ownable", type: "music", variant: "Music" });
+  try {
+    assert.throws(
+      () => resolveTemplate("music"),
+      /Multiple templates claim ownable type 'music': music-ownable, other-music-ownable/
+    );
+  } finally {
+    TEMPLATES.pop();
+  }
+
+  const tmpDir = await fs.mkdtemp(path.join(os.tmpdir(), "ownable-new-"));
+  try {
+    const projectDir = path.join(tmpDir, "mysong");
+    await quietly(() => newOwnable("mysong", { ...OPTIONS, type: "music", dir: projectDir }));
+    assert.strictEqual((await readManifest(projectDir)).template, "music-ownable");
+    assert.strictEqual(await fs.readFile(path.join(projectDir, "type.txt"), "utf8"), "music-ownable");
+    console.log("✓ --type music resolves to music-ownable");
+
+    assert.strictEqual(resolveTemplateVersion(undefined), TEMPLATE_VERSION);
+    assert.strictEqual(resolveTemplateVersion(TEMPLATE_VERSION), TEMPLATE_VERSION);
+    const pinnedDir = path.join(tmpDir, "pinned");
+    await quietly(() =>
+      newOwnable("pinned", { ...OPTIONS, type: "music", dir: pinnedDir, templateVersion: TEMPLATE_VERSION })
+    );
+    assert.strictEqual((await readManifest(pinnedDir)).templateVersion, TEMPLATE_VERSION);
+
+    const unavailableDir = path.join(tmpDir, "unavailable");
+    await assert.rejects(
+      newOwnable("unavailable", { ...OPTIONS, type: "music", dir: unavailableDir, templateVersion: "0.0.1" }),
+      /Template version 0\.0\.1 is not available/
+    );
+    assert(!(await fs.pathExists(unavailableDir)));
+    console.log("✓ --template-version pins the template and is recorded in the manifest");
+
+    const ownableToml = path.join(tmpDir, "ownable.toml");
+    await fs.writeFile(
+      ownableToml,
+      [
+        'name = "from-manifest"',
+        'description = "Described in ownable.toml"',
+        'ownable_type = "music"',
+        'network = "lto:T"',
+        'greeting = "hello"',
+      ].join("\n")
+    );
+    const manifestDir = path.join(tmpDir, "from-manifest");
+    await quietly(() => newOwnable(undefined, { ...OPTIONS, manifest: ownableToml, dir: manifestDir }));
+    const generated = await readManifest(manifestDir);
+    assert.strictEqual(generated.template, "music-ownable");
+    assert.deepStrictEqual(generated.metadata.extra, { network: "lto:T", greeting: "hello" });
+    const cargoToml = await fs.readFile(path.join(manifestDir, "Cargo.toml"), "utf8");
+    assert(cargoToml.includes('name = "from-manifest"'), cargoToml);
+    assert(cargoToml.includes('description = "Described in ownable.toml"'), cargoToml);
+    const contract = await fs.readFile(path.join(manifestDir, "src", "contract.rs"), "utf8");
+    assert(contract.includes('Some("Described in ownable.toml".to_string())'));
+    await execAsync("cargo check", { cwd: manifestDir });
+
+    const extraFile = path.join(tmpDir, "extra.rs");
+    await fs.writeFile(extraFile, "const NETWORK: &str = PLACEHOLDER4_NETWORK;");
+    await replacePlaceholders(extraFile, generated.metadata, generated.template);
+    assert.strictEqual(await fs.readFile(extraFile, "utf8"), 'const NETWORK: &str = "lto:T";');
+
+    const overriddenDir = path.join(tmpDir, "overridden");
+    await quietly(() =>
+      newOwnable("overridden", {
+        ...OPTIONS,
+        manifest: ownableToml,
+        dir: overriddenDir,
+        description: "From the flag",
+      })
+    );
+    assert.strictEqual((await readManifest(overriddenDir)).metadata.description, "From the flag");
+
+    await fs.writeFile(ownableToml, 'name = "untyped"');
+    await assert.rejects(
+      newOwnable(undefined, { ...OPTIONS, manifest: ownableToml, dir: path.join(tmpDir, "untyped") }),
+      /Missing ownable type: pass --type or set `ownable_type` in ownable.toml/
+    );
+    console.log("✓ ownable.toml fills the template values, and flags override them");
+
+    const contractPath = path.join(projectDir, "src", "contract.rs");
+    await fs.appendFile(contractPath, "// edited\n");
+    await fs.remove(path.join(projectDir, "README.md"));
+    const before = await snapshot(projectDir);
+    const { metadata } = await readManifest(projectDir);
+
+    const changes = await quietly(() => dryRunScaffold("music-ownable", metadata, projectDir));
+    const status = (file) => changes.find((change) => change.file === file).status;
+    assert.strictEqual(status(path.join("src", "contract.rs")), "modified");
+    assert(changes.find((change) => change.file === path.join("src", "contract.rs")).diff.includes("-// edited"));
+    assert.strictEqual(status("README.md"), "created");
+    assert.strictEqual(status("Cargo.toml"), "unchanged");
+
+    await quietly(() => newOwnable("mysong", { ...OPTIONS, type: "music", dir: projectDir, dryRun: true }));
+    assert.deepStrictEqual(await snapshot(projectDir), before);
+    console.log("✓ --dry-run reports changes to an existing project without writing");
+
+    // a private template: the built-in music crate with a marker file
+    const templateRoot = path.join(tmpDir, "private-templates");
+    const customDir = path.join(templateRoot, "templates", "music-ownable");
+    for (const dir of templateDirs(getTemplate("music-ownable"))) {
+      await fs.copy(dir, customDir);
+    }
+    await fs.writeFile(path.join(customDir, "CUSTOM"), "private template");
+
+    const customProject = path.join(tmpDir, "custom");
+    await quietly(() =>
+      newOwnable("custom", { ...OPTIONS, type: "music", dir: customProject, templatePath: templateRoot })
+    );
+    assert.strictEqual(await fs.readFile(path.join(customProject, "CUSTOM"), "utf8"), "private template");
+    assert.strictEqual(await fs.readFile(path.join(customProject, "type.txt"), "utf8"), "music-ownable");
+    const customCargo = await fs.readFile(path.join(customProject, "Cargo.toml"), "utf8");
+    assert(customCargo.includes('name = "custom"'), customCargo);
+    // the built-in template is back once the custom source is closed
+    assert(!getTemplate("music-ownable").dir);
+
+    await assert.rejects(
+      newOwnable("missing", {
+        ...OPTIONS,
+        type: "music",
+        dir: path.join(tmpDir, "missing"),
+        templatePath: templateRoot,
+        template: "video-ownable",
+      }),
+      /Template 'video-ownable' not found in .*private-templates/
+    );
+    await assert.rejects(
+      newOwnable("nowhere", { ...OPTIONS, type: "music", templatePath: path.join(tmpDir, "nowhere") }),
+      /Template path .*nowhere does not exist/
+    );
+    console.log("✓ --template-path scaffolds from a local custom template");
+  } finally {
+    await fs.remove(tmpDir);
+  }
+}
+
+module.exports = { testNew };
@@ -0,0 +1,145 @@
+const assert = require("assert");
+const fs = require("fs-extra");
+const http = require("http");
+const JSZip = require("jszip");
+const os = require("os");
+const path = require("path");
+const { packageOwnable, collectPackageFiles } = require("../lib/commands/package");
+const { computeCid } = require("../lib/utils/cid");
+
+/**
+ * Stand-in for an IPFS node answering `add --only-hash`. It hashes the
+ * uploaded file like kubo, or with a different importer when `mismatch` is
+ * set.
+ */
+async function mockIpfsNode({ mismatch = false } = {}) {
+  const requests = [];
+  const server = http.createServer((req, res) => {
+    const chunks = [];
+    req.on("data", (chunk) => chunks.push(chunk));
+    req.on("end", () => {
+      const url = new URL(req.url, "http://localhost");
+      requests.push(Object.fromEntries(url.searchParams));
+      const body = Buffer.concat(chunks);
+      const boundary = body.subarray(0, body.indexOf("\r\n"));
+      const start = body.indexOf("\r\n\r\n") + 4;
+      const content = body.subarray(start, body.lastIndexOf(Buffer.concat([Buffer.from("\r\n"), boundary])));
+      const hash = computeCid(mismatch ? Buffer.concat([content, Buffer.from([0])]) : content);
+      res.writeHead(200, { "Content-Type": "application/x-ndjson" });
+      res.end(JSON.stringify({ Name: "package.zip", Hash: hash }) + "\n");
+    });
+  });
+  await new Promise((resolve) => server.listen(0, "127.0.0.1", resolve));
+
+  return {
+    api: `http://127.0.0.1:${server.address().port}`,
+    requests,
+    close: () => new Promise((resolve) => server.close(resolve)),
+  };
+}
+
+/**
+ * A project as `build` leaves it: the module and bindings in build/, the
+ * rendered package in build/package and the template assets untouched
+ */
+async function builtProject() {
+  const dir = await fs.mkdtemp(path.join(os.tmpdir(), "ownable-package-"));
+  await fs.outputFile(path.join(dir, "build", "demo_bg.wasm"), Buffer.from([0, 97, 115, 109]));
+  await fs.outputFile(path.join(dir, "build", "demo.js"), "export default {};\n");
+  await fs.outputJson(path.join(dir, "build", "package", "chain.json"), { id: "chain" });
+  await fs.outputFile(path.join(dir, "build", "package", "index.html"), '<img src="images/a.png">');
+  await fs.outputFile(path.join(dir, "assets", "index.html"), '<img src="PLACEHOLDER2_IMG">');
+  return dir;
+}
+
+// Stand-in for cargo: `cargo run --example schema` writes two schema files
+const FAKE_CARGO = `#!/bin/sh
+mkdir -p schema
+echo '{"title":"ExecuteMsg"}' > schema/execute_msg.json
+echo '{"title":"QueryMsg"}' > schema/query_msg.json
+`;
+
+/**
+ * Package with the schema, generated by the fake cargo
+ */
+async function packageWithSchema(dir, options) {
+  const bin = path.join(dir, "bin");
+  await fs.outputFile(path.join(bin, "cargo"), FAKE_CARGO);
+  await fs.chmod(path.join(bin, "cargo"), 0o755);
+  await fs.outputFile(path.join(dir, "Cargo.toml"), '[package]\nname = "demo"\n');
+  await fs.outputFile(path.join(dir, "examples", "schema.rs"), "fn main() {}\n");
+
+  const saved = process.env.PATH;
+  process.env.PATH = `${bin}${path.delimiter}${saved}`;
+  try {
+    return await packageOwnable(dir, { ...options, includeSchema: true, verify: false });
+  } finally {
+    process.env.PATH = saved;
+  }
+}
+
+async function testPackage() {
+  const dir = await builtProject();
+  try {
+    const first = await packageOwnable(dir, { output: path.join(dir, "first.zip"), verify: false });
+    const second = await packageOwnable(dir, { output: path.join(dir, "second.zip"), verify: false });
+    assert.strictEqual(first.cid, second.cid);
+    assert(
+      (await fs.readFile(first.output)).equals(await fs.readFile(second.output)),
+      "Archives of the same content should be byte-identical"
+    );
+    console.log("✓ Packaging is deterministic");
+
+    const files = await collectPackageFiles(dir);
+    const sources = files.map((file) => path.relative(dir, file.source));
+    assert(sources.includes(path.join("build", "package", "chain.json")));
+    assert(!sources.some((source) => source.startsWith("assets")));
+    console.log("✓ Packages the rendered build output");
+
+    const node = await mockIpfsNode();
+    const mismatching = await mockIpfsNode({ mismatch: true });
+    try {
+      const verified = await packageOwnable(dir, { output: path.join(dir, "verified.zip"), api: node.api });
+      assert.strictEqual(verified.cid, first.cid);
+      assert.deepStrictEqual(node.requests, [{ "only-hash": "true", "cid-version": "1", pin: "false" }]);
+
+      const rejected = path.join(dir, "rejected.zip");
+      await assert.rejects(
+        packageOwnable(dir, { output: rejected, api: mismatching.api }),
+        new RegExp(`Package CID ${first.cid} does not match b\\w+ computed by the IPFS node`)
+      );
+      assert(!(await fs.pathExists(rejected)), "A package failing verification should not be written");
+    } finally {
+      await node.close();
+      await mismatching.close();
+    }
+    await assert.rejects(
+      packageOwnable(dir, { output: path.join(dir, "offline.zip"), api: "http://127.0.0.1:1" }),
+      /Unable to reach IPFS node.*Use --no-verify to package offline/
+    );
+    console.log("✓ The package CID is verified against the IPFS node unless --no-verify");
+
+    await fs.outputJson(path.join(dir, "build", "package", "package.json"), { name: "demo" });
+    const withoutSchema = await packageOwnable(dir, { output: path.join(dir, "plain.zip"), verify: false });
+    const withSchema = await packageWithSchema(dir, { output: path.join(dir, "schema.zip") });
+    assert.notStrictEqual(withSchema.cid, withoutSchema.cid);
+    const zip = await JSZip.loadAsync(await fs.readFile(withSchema.output));
+    assert.deepStrictEqual(JSON.parse(await zip.file("schema.json").async("string")), {
+      execute_msg: { title: "ExecuteMsg" },
+      query_msg: { title: "QueryMsg" },
+    });
+    assert.deepStrictEqual(JSON.parse(await zip.file("package.json").async("string")), {
+      name: "demo",
+      schema: "schema.json",
+    });
+    console.log("✓ --include-schema bundles schema.json and changes the CID");
+
+    await fs.remove(path.join(dir, "build", "package"));
+    await assert.rejects(collectPackageFiles(dir), /Run 'ownables-cli build' first/);
+    console.log("✓ Packaging needs a build");
+  } finally {
+    await fs.remove(dir);
+  }
+}
+
+module.exports = { testPackage, builtProject };
@@ -0,0 +1,74 @@
+const assert = require("assert");
+const fs = require("fs-extra");
+const http = require("http");
+const os = require("os");
+const path = require("path");
+const { pinMetadata } = require("../lib/commands/pinMetadata");
+
+/**
+ * Minimal stand-in for the IPFS HTTP API `add` endpoint. It answers with one
+ * JSON line per uploaded file and the wrapping directory last, as kubo does.
+ */
+async function mockIpfsNode() {
+  const requests = [];
+  const server = http.createServer((req, res) => {
+    const chunks = [];
+    req.on("data", (chunk) => chunks.push(chunk));
+    req.on("end", () => {
+      const url = new URL(req.url, "http://localhost");
+      const body = Buffer.concat(chunks).toString("utf8");
+      const names = [...body.matchAll(/filename="([^"]*)"/g)].map((match) =>
+        decodeURIComponent(match[1])
+      );
+      requests.push({ path: url.pathname, query: Object.fromEntries(url.searchParams), names });
+
+      const lines = names.map((name, index) => ({ Name: name, Hash: `bafyfile${index}` }));
+      lines.push({ Name: "", Hash: "bafyroot" });
+      res.writeHead(200, { "Content-Type": "application/x-ndjson" });
+      res.end(lines.map((line) => JSON.stringify(line)).join("\n") + "\n");
+    });
+  });
+  await new Promise((resolve) => server.listen(0, "127.0.0.1", resolve));
+
+  return {
+    api: `http://127.0.0.1:${server.address().port}`,
+    requests,
+    close: () => new Promise((resolve) => server.close(resolve)),
+  };
+}
+
+async function testPinMetadata() {
+  const node = await mockIpfsNode();
+  const dir = await fs.mkdtemp(path.join(os.tmpdir(), "ownables-pin-"));
+  try {
+    await fs.outputJson(path.join(dir, "2.json"), { name: "Second", image: null });
+    await fs.outputJson(path.join(dir, "1.json"), { name: "First", description: "one" });
+    await fs.outputFile(path.join(dir, "notes.txt"), "not metadata");
+
+    const result = await pinMetadata(dir, { api: node.api });
+
+    assert.strictEqual(node.requests.length, 1);
+    assert.strictEqual(node.requests[0].path, "/api/v0/add");
+    assert.strictEqual(node.requests[0].query["wrap-with-directory"], "true");
+    assert.strictEqual(node.requests[0].query["cid-version"], "1");
+    assert.deepStrictEqual(node.requests[0].names, ["1.json", "2.json"]);
+    assert.deepStrictEqual(result, {
+      root: "bafyroot",
+      files: [
+        { path: "1.json", cid: "bafyfile0" },
+        { path: "2.json", cid: "bafyfile1" },
+      ],
+    });
+    console.log("✓ Metadata directory is pinned under one root CID");
+
+    await fs.outputJson(path.join(dir, "3.json"), { name: "Third", traits: [] });
+    await assert.rejects(pinMetadata(dir, { api: node.api }), /unknown metadata field 'traits'/);
+    assert.strictEqual(node.requests.length, 1);
+    console.log("✓ Invalid metadata is rejected before anything is uploaded");
+  } finally {
+    await node.close();
+    await fs.remove(dir);
+  }
+}
+
+module.exports = { testPinMetadata };
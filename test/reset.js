@@ -0,0 +1,102 @@
+const assert = require("assert");
+const fs = require("fs-extra");
+const os = require("os");
+const path = require("path");
+const { newOwnable, scaffold } = require("../lib/commands/create");
+const { reset } = require("../lib/commands/reset");
+const { getTemplate, templateDirs } = require("../lib/utils/templates");
+
+const METADATA = {
+  name: "reset-check",
+  description: "Regenerated by reset",
+  version: "1.0.0",
+  authors: "tester",
+  keywords: ["music"],
+};
+
+async function testReset() {
+  const tmpDir = await fs.mkdtemp(path.join(os.tmpdir(), "ownable-reset-"));
+  const projectDir = path.join(tmpDir, "reset-check");
+  try {
+    await scaffold("music-ownable", METADATA, projectDir);
+    const contract = path.join(projectDir, "src", "contract.rs");
+    const cargoToml = path.join(projectDir, "Cargo.toml");
+    const generated = {
+      contract: await fs.readFile(contract, "utf8"),
+      cargoToml: await fs.readFile(cargoToml, "utf8"),
+    };
+
+    await fs.writeFile(contract, "corrupted");
+    await fs.remove(cargoToml);
+    await fs.outputFile(path.join(projectDir, "assets", "images", "cover.png"), "user image");
+    await fs.outputFile(path.join(projectDir, "assets", "index.html"), "<p>edited</p>");
+
+    await reset(projectDir);
+
+    assert.strictEqual(await fs.readFile(contract, "utf8"), generated.contract);
+    assert.strictEqual(await fs.readFile(cargoToml, "utf8"), generated.cargoToml);
+    assert.strictEqual(
+      await fs.readFile(path.join(projectDir, "assets", "images", "cover.png"), "utf8"),
+      "user image"
+    );
+    assert.strictEqual(
+      await fs.readFile(path.join(projectDir, "assets", "index.html"), "utf8"),
+      "<p>edited</p>"
+    );
+    console.log("✓ Reset regenerates sources from the manifest and keeps assets");
+
+    await fs.remove(path.join(projectDir, ".ownables"));
+    await assert.rejects(reset(projectDir), /No \.ownables[\\/]manifest\.json found/);
+    console.log("✓ Reset requires the scaffold manifest");
+
+    // a project generated from a custom template named apart from the built-ins
+    const templateRoot = path.join(tmpDir, "private-templates");
+    const customTemplate = path.join(templateRoot, "private-music");
+    for (const dir of templateDirs(getTemplate("music-ownable"))) {
+      await fs.copy(dir, customTemplate);
+    }
+    await fs.writeFile(path.join(customTemplate, "CUSTOM"), "private template");
+    const customDir = path.join(tmpDir, "custom");
+    const log = console.log;
+    console.log = () => {};
+    try {
+      await newOwnable("custom", {
+        crateVersion: "1.0.0",
+        authors: "tester",
+        type: "music",
+        dir: customDir,
+        templatePath: templateRoot,
+        template: "private-music",
+      });
+    } finally {
+      console.log = log;
+    }
+    const customContract = path.join(customDir, "src", "contract.rs");
+    const customGenerated = await fs.readFile(customContract, "utf8");
+    await fs.remove(customContract);
+    await fs.remove(path.join(customDir, "CUSTOM"));
+
+    await reset(customDir);
+
+    assert.strictEqual(await fs.readFile(customContract, "utf8"), customGenerated);
+    assert.strictEqual(await fs.readFile(path.join(customDir, "CUSTOM"), "utf8"), "private template");
+    assert.throws(() => getTemplate("private-music"), /Unknown template 'private-music'/);
+    console.log("✓ Reset regenerates from the custom template the project was created from");
+
+    await fs.move(templateRoot, path.join(tmpDir, "moved-templates"));
+    await assert.rejects(reset(customDir), /Template path .*private-templates does not exist/);
+    const manifestPath = path.join(customDir, ".ownables", "manifest.json");
+    const manifest = JSON.parse(await fs.readFile(manifestPath, "utf8"));
+    delete manifest.templateSource;
+    await fs.writeFile(manifestPath, JSON.stringify(manifest));
+    await assert.rejects(
+      reset(customDir),
+      /Template 'private-music' is not built in and the manifest doesn't record its source/
+    );
+    console.log("✓ Reset explains a custom template it can't find");
+  } finally {
+    await fs.remove(tmpDir);
+  }
+}
+
+module.exports = { testReset };
@@ -0,0 +1,39 @@
+const assert = require("assert");
+const fs = require("fs-extra");
+const os = require("os");
+const path = require("path");
+const { scaffold } = require("../lib/commands/create");
+const { schema } = require("../lib/commands/schema");
+
+const METADATA = {
+  name: "schema-check",
+  description: "Schema generated by the CLI",
+  version: "1.0.0",
+  authors: "tester",
+  keywords: ["music"],
+};
+
+async function testSchema() {
+  const tmpDir = await fs.mkdtemp(path.join(os.tmpdir(), "ownable-schema-"));
+  const projectDir = path.join(tmpDir, "schema-check");
+  try {
+    await scaffold("music-ownable", METADATA, projectDir);
+
+    const files = await schema(projectDir);
+
+    for (const name of ["execute_msg.json", "query_msg.json"]) {
+      const file = path.join(projectDir, "schema", name);
+      assert(files.includes(file), `${name} should be reported`);
+      JSON.parse(await fs.readFile(file, "utf8"));
+    }
+    console.log("✓ Schema files are generated for the music template");
+
+    await fs.remove(path.join(projectDir, "examples", "schema.rs"));
+    await assert.rejects(schema(projectDir), /No schema entrypoint found/);
+    console.log("✓ Schema requires the template's schema entrypoint");
+  } finally {
+    await fs.remove(tmpDir);
+  }
+}
+
+module.exports = { testSchema };
@@ -0,0 +1,89 @@
+const assert = require("assert");
+const fs = require("fs-extra");
+const os = require("os");
+const path = require("path");
+const { scaffold } = require("../lib/commands/create");
+const { simulate, simulateQuery } = require("../lib/commands/simulate");
+
+// Stand-in for `cargo run --example simulate_query`, answering with the name
+// in name.txt and the queries it was passed
+const FAKE_CARGO = `#!/bin/sh
+printf '{"get_metadata":{"name":"%s"},"queries":%s}' "$(cat name.txt)" "\${SIMULATE_QUERIES:-null}"
+`;
+
+/**
+ * Run simulate-query on a project named `name` with the fake cargo on PATH
+ */
+async function fakeSimulateQuery(dir, name, options) {
+  const bin = path.join(dir, "bin");
+  await fs.outputFile(path.join(bin, "cargo"), FAKE_CARGO);
+  await fs.chmod(path.join(bin, "cargo"), 0o755);
+  const project = path.join(dir, "project");
+  await fs.outputFile(path.join(project, "Cargo.toml"), '[package]\nname = "demo"\n');
+  await fs.outputFile(path.join(project, "examples", "simulate_query.rs"), "fn main() {}\n");
+  await fs.writeFile(path.join(project, "name.txt"), name);
+
+  const saved = process.env.PATH;
+  process.env.PATH = `${bin}${path.delimiter}${saved}`;
+  try {
+    return await simulateQuery(project, options);
+  } finally {
+    process.env.PATH = saved;
+  }
+}
+
+const METADATA = {
+  name: "simulate-check",
+  description: "Simulated by the CLI",
+  version: "1.0.0",
+  authors: "tester",
+  keywords: ["music"],
+};
+
+async function testSimulate() {
+  const tmpDir = await fs.mkdtemp(path.join(os.tmpdir(), "ownable-simulate-"));
+  const projectDir = path.join(tmpDir, "simulate-check");
+  try {
+    await scaffold("music-ownable", METADATA, projectDir);
+
+    const report = await simulate(projectDir);
+
+    assert(/^transfer:$/m.test(report), report);
+    assert(/^lock:$/m.test(report), report);
+    assert(/^is_locked:\n\s+true$/m.test(report), report);
+    console.log("✓ Simulating the music template locks the ownable");
+
+    const responses = JSON.parse(await simulateQuery(projectDir));
+    assert.strictEqual(responses.get_metadata.name, "simulate-check");
+    assert.strictEqual(responses.get_info.ownable_type, "music");
+    assert.deepStrictEqual(
+      Object.keys(JSON.parse(await simulateQuery(projectDir, { msg: '{"is_locked":{}}' }))),
+      ["is_locked"]
+    );
+    console.log("✓ Simulate-query prints the music template's query responses");
+
+    await fs.remove(path.join(projectDir, "examples", "simulate.rs"));
+    await assert.rejects(simulate(projectDir), /No simulation found/);
+    console.log("✓ Simulate requires the template's simulation");
+
+    const golden = path.join(tmpDir, "golden", "queries.json");
+    const written = await fakeSimulateQuery(tmpDir, "first", { golden });
+    assert.strictEqual(await fs.readFile(golden, "utf8"), written);
+    await fakeSimulateQuery(tmpDir, "first", { golden });
+    await assert.rejects(
+      fakeSimulateQuery(tmpDir, "renamed", { golden }),
+      /Output differs from golden file .*queries\.json at line 3:\n- {5}"name": "first"\n\+ {5}"name": "renamed"/
+    );
+    assert.strictEqual(await fs.readFile(golden, "utf8"), written);
+    await fakeSimulateQuery(tmpDir, "renamed", { golden, updateGolden: true });
+    assert.strictEqual(JSON.parse(await fs.readFile(golden, "utf8")).get_metadata.name, "renamed");
+
+    const queried = JSON.parse(await fakeSimulateQuery(tmpDir, "first", { msg: '{"get_info":{}}' }));
+    assert.deepStrictEqual(queried.queries, [{ get_info: {} }]);
+    console.log("✓ Simulate-query output is compared against a golden file");
+  } finally {
+    await fs.remove(tmpDir);
+  }
+}
+
+module.exports = { testSimulate };
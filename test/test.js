@@ -2,10 +2,68 @@ const { build } = require("../lib/commands/build");
 const assert = require("assert");
 const fs = require("fs-extra");
 const path = require("path");
+const { listRustFiles, lineOf } = require("../lib/utils/rustSource");
+const { testAudit } = require("./audit");
+const { testBuild } = require("./build");
+const { testCid } = require("./cid");
+const { testCodegenTs } = require("./codegenTs");
+const { testConvertMetadata } = require("./convertMetadata");
+const { testCreate } = require("./create");
+const { testInit } = require("./init");
+const { testLint } = require("./lint");
+const { testMsg } = require("./msg");
+const { testNew } = require("./new");
+const { testPackage } = require("./package");
+const { testPinMetadata } = require("./pinMetadata");
+const { testReset } = require("./reset");
+const { testSchema } = require("./schema");
+const { testSimulate } = require("./simulate");
+const { testVerify } = require("./verify");
+
+const TEMPLATES_DIR = path.join(__dirname, "../templates");
+
+// cosmwasm-std removed `to_binary` in favour of `to_json_binary`
+async function findRemovedSerializationHelpers() {
+  const offenders = [];
+  for (const file of await listRustFiles(TEMPLATES_DIR)) {
+    const source = await fs.readFile(file, "utf8");
+    for (const match of source.matchAll(/\bto_binary\s*\(/g)) {
+      offenders.push(
+        `${path.relative(TEMPLATES_DIR, file)}:${lineOf(source, match.index)}`
+      );
+    }
+  }
+  return offenders;
+}
 
 async function runTests() {
   console.log("Running tests...");
 
+  const offenders = await findRemovedSerializationHelpers();
+  if (offenders.length > 0) {
+    console.error("✗ Templates use the removed to_binary helper:");
+    offenders.forEach((offender) => console.error(`  ${offender}`));
+    process.exit(1);
+  }
+  console.log("✓ Templates use to_json_binary");
+
+  await testAudit();
+  await testBuild();
+  await testCid();
+  await testCodegenTs();
+  await testConvertMetadata();
+  await testCreate();
+  await testLint();
+  await testInit();
+  await testMsg();
+  await testNew();
+  await testPackage();
+  await testPinMetadata();
+  await testReset();
+  await testSchema();
+  await testSimulate();
+  await testVerify();
+
   try {
     await build();
     console.log("✓ Build process completed successfully");
@@ -24,4 +82,7 @@ async function runTests() {
   console.log("\nAll tests passed! 🎉");
 }
 
-runTests().catch(console.error);
+runTests().catch((error) => {
+  console.error(error);
+  process.exit(1);
+});
@@ -0,0 +1,37 @@
+const assert = require("assert");
+const fs = require("fs-extra");
+const path = require("path");
+const { verify } = require("../lib/commands/verify");
+const { packageOwnable } = require("../lib/commands/package");
+const { builtProject } = require("./package");
+
+async function testVerify() {
+  const dir = await builtProject();
+  try {
+    const { cid } = await packageOwnable(dir, { output: path.join(dir, "package.zip"), verify: false });
+
+    const matching = await verify({ package: dir, expectedCid: cid });
+    assert(matching.matches);
+    assert.deepStrictEqual(matching.placeholders, []);
+    console.log("✓ Verify accepts the packaged CID");
+
+    await fs.outputFile(path.join(dir, "build", "package", "index.html"), '<img src="images/b.png">');
+    const tampered = await verify({ package: dir, expectedCid: cid });
+    assert(!tampered.matches);
+    assert.notStrictEqual(tampered.cid, cid);
+    console.log("✓ Verify rejects a tampered package");
+
+    await fs.outputFile(path.join(dir, "build", "package", "index.html"), '<img src="PLACEHOLDER2_IMG">');
+    await fs.outputFile(path.join(dir, "src", "contract.rs"), "const NAME: &str = PLACEHOLDER4_CONTRACT_NAME;\n");
+    const unrendered = await verify({ package: dir, expectedCid: cid });
+    assert.deepStrictEqual(
+      unrendered.placeholders.map(({ file, token }) => `${file} ${token}`),
+      [`${path.join("src", "contract.rs")} PLACEHOLDER4_CONTRACT_NAME`, "index.html PLACEHOLDER2_IMG"]
+    );
+    console.log("✓ Verify reports leftover placeholders");
+  } finally {
+    await fs.remove(dir);
+  }
+}
+
+module.exports = { testVerify };